@@ -8,8 +8,12 @@ use std::time::Duration as StdDuration;
 use crate::astro::moon::{LunarPhase, LunarPhaseType, LunarPosition};
 use crate::astro::sun::SolarPosition;
 use crate::astro::{self, coordinates};
+use crate::cli::{AiProviderArg, DEFAULT_AI_MODEL, DEFAULT_AI_SERVER};
+use crate::events::AstroEventKind;
 use crate::time_sync::{self, TimeSyncInfo};
 
+pub use crate::config::AiProvider;
+
 const DEFAULT_TIMEOUT_SECS: u64 = 15;
 const USER_AGENT: &str = "Solunatus AI Insights";
 const ERROR_SUMMARY_LIMIT: usize = 120;
@@ -31,6 +35,8 @@ pub struct AiConfig {
     pub model: String,
     pub refresh: StdDuration,
     pub refresh_mode: crate::config::AiRefreshMode,
+    /// API spoken by `server`; decides request and response shapes
+    pub provider: AiProvider,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -136,6 +142,53 @@ struct OllamaRequest<'a> {
     stream: bool,
 }
 
+#[derive(Serialize)]
+struct ChatMessage<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Serialize)]
+struct ChatCompletionRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    stream: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponseMessage {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    message: ChatResponseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiModelEntry {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAiModelsResponse {
+    data: Vec<OpenAiModelEntry>,
+}
+
+/// Request body for the configured provider
+#[derive(Serialize)]
+#[serde(untagged)]
+enum InsightsRequest<'a> {
+    Ollama(OllamaRequest<'a>),
+    ChatCompletion(ChatCompletionRequest<'a>),
+}
+
 impl AiConfig {
     pub fn from_args(args: &crate::cli::Args) -> Result<Self> {
         let enabled = args.ai_insights;
@@ -147,22 +200,64 @@ impl AiConfig {
             ));
         }
 
+        let provider = match (args.ai_provider, &args.ai_api_key) {
+            (None | Some(AiProviderArg::Ollama), Some(_)) => {
+                return Err(anyhow!(
+                    "--ai-api-key requires --ai-provider openai-compatible"
+                ));
+            }
+            (None | Some(AiProviderArg::Ollama), None) => AiProvider::Ollama,
+            (Some(AiProviderArg::OpenaiCompatible), api_key) => AiProvider::OpenAiCompatible {
+                api_key: api_key.clone().unwrap_or_default(),
+            },
+        };
+
         Ok(Self {
             enabled,
-            server: Self::normalized_server(enabled, &args.ai_server),
-            model: args.ai_model.trim().to_string(),
+            server: Self::normalized_server(
+                enabled,
+                args.ai_server.as_deref().unwrap_or(DEFAULT_AI_SERVER),
+            ),
+            model: args
+                .ai_model
+                .as_deref()
+                .unwrap_or(DEFAULT_AI_MODEL)
+                .trim()
+                .to_string(),
             refresh: StdDuration::from_secs(refresh_minutes * 60),
             refresh_mode: crate::config::AiRefreshMode::AutoAndManual,
+            provider,
         })
     }
 
-    pub fn merge_with_saved(mut self, saved_settings: &crate::config::AiSettings) -> Self {
+    /// Fill in settings not given on the command line from the saved config.
+    ///
+    /// The saved server, model and provider are used only when `--ai-server`,
+    /// `--ai-model` and `--ai-provider` were omitted from `args`, so a gateway saved in
+    /// `~/.solunatus.json` keeps its own URL and model while explicit flags still win.
+    pub fn merge_with_saved(
+        mut self,
+        args: &crate::cli::Args,
+        saved_settings: &crate::config::AiSettings,
+    ) -> Self {
         self.refresh_mode = saved_settings.refresh_mode;
+        if args.ai_server.is_none() {
+            self.server = Self::normalized_server(self.enabled, &saved_settings.server);
+        }
+        if args.ai_model.is_none() && !saved_settings.model.trim().is_empty() {
+            self.model = saved_settings.model.trim().to_string();
+        }
+        if args.ai_provider.is_none() {
+            self.provider = saved_settings.provider.clone();
+        }
         self
     }
 
     pub fn endpoint(&self) -> String {
-        format!("{}/api/generate", self.server)
+        match self.provider {
+            AiProvider::Ollama => format!("{}/api/generate", self.server),
+            AiProvider::OpenAiCompatible { .. } => format!("{}/v1/chat/completions", self.server),
+        }
     }
 
     pub fn refresh_minutes(&self) -> u64 {
@@ -223,7 +318,7 @@ impl AiOutcome {
 }
 
 pub fn prepare_event_summaries(
-    events: &[(DateTime<Tz>, &'static str)],
+    events: &[(DateTime<Tz>, AstroEventKind)],
     reference: &DateTime<Tz>,
     next_index: Option<usize>,
 ) -> Vec<AiEventSummary> {
@@ -231,7 +326,7 @@ pub fn prepare_event_summaries(
         .iter()
         .enumerate()
         .map(|(idx, (time, name))| AiEventSummary {
-            name: (*name).to_string(),
+            name: name.label().to_string(),
             local_time: time.format("%Y-%m-%d %H:%M:%S %Z").to_string(),
             relative_time: astro::time_utils::format_duration_detailed(
                 astro::time_utils::time_until(reference, time),
@@ -341,53 +436,161 @@ pub fn fetch_insights(config: &AiConfig, data: &AiData) -> Result<AiOutcome> {
     }
 
     let prompt = build_prompt(data)?;
-    let desired_timeout = if config.refresh > StdDuration::from_secs(1) {
-        config.refresh - StdDuration::from_secs(1)
-    } else {
-        StdDuration::from_secs(DEFAULT_TIMEOUT_SECS)
-    };
-    let timeout = if desired_timeout >= StdDuration::from_secs(DEFAULT_TIMEOUT_SECS) {
-        desired_timeout
-    } else {
-        StdDuration::from_secs(DEFAULT_TIMEOUT_SECS)
-    };
+    let label = config.provider.label();
+    let client = build_secure_http_client(insights_timeout(config))
+        .with_context(|| format!("failed to construct HTTP client for {}", label))?;
 
-    let client = build_secure_http_client(timeout)
-        .context("failed to construct HTTP client for Ollama")?;
+    let mut request = client
+        .post(config.endpoint())
+        .json(&build_request(config, &prompt));
+    if let Some(api_key) = api_key(&config.provider) {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request
+        .send()
+        .with_context(|| format!("failed to reach {} server at {}", label, config.server))?;
 
-    let body = OllamaRequest {
-        model: &config.model,
-        prompt: &prompt,
-        stream: false,
-    };
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "{} server returned status {}",
+            label,
+            response.status()
+        ));
+    }
+
+    let body = response
+        .text()
+        .with_context(|| format!("failed to read {} response", label))?;
+    let content = parse_insights_response(&config.provider, &body)?;
 
-    let response = client
+    Ok(outcome_from_content(config, content))
+}
+
+/// Non-blocking counterpart of [`fetch_insights`] for async services.
+///
+/// Sends the same request with reqwest's async client, so it must be awaited inside a
+/// Tokio runtime. Available with the `async` feature; the blocking path stays the
+/// default.
+#[cfg(feature = "async")]
+pub async fn fetch_insights_async(config: &AiConfig, data: &AiData) -> Result<AiOutcome> {
+    if !config.enabled {
+        return Err(anyhow!("AI insights are disabled"));
+    }
+
+    let prompt = build_prompt(data)?;
+    let label = config.provider.label();
+    let client = reqwest::Client::builder()
+        .timeout(insights_timeout(config))
+        .user_agent(USER_AGENT)
+        .danger_accept_invalid_certs(false) // Explicitly enforce TLS verification
+        .build()
+        .with_context(|| format!("failed to construct HTTP client for {}", label))?;
+
+    let mut request = client
         .post(config.endpoint())
-        .json(&body)
+        .json(&build_request(config, &prompt));
+    if let Some(api_key) = api_key(&config.provider) {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request
         .send()
-        .with_context(|| format!("failed to reach Ollama server at {}", config.server))?;
+        .await
+        .with_context(|| format!("failed to reach {} server at {}", label, config.server))?;
 
     if !response.status().is_success() {
         return Err(anyhow!(
-            "Ollama server returned status {}",
+            "{} server returned status {}",
+            label,
             response.status()
         ));
     }
 
-    let payload: OllamaResponse = response
-        .json()
-        .context("failed to parse Ollama response payload")?;
+    let body = response
+        .text()
+        .await
+        .with_context(|| format!("failed to read {} response", label))?;
+    let content = parse_insights_response(&config.provider, &body)?;
+
+    Ok(outcome_from_content(config, content))
+}
+
+/// Generation request in the shape the configured provider expects.
+fn build_request<'a>(config: &'a AiConfig, prompt: &'a str) -> InsightsRequest<'a> {
+    match config.provider {
+        AiProvider::Ollama => InsightsRequest::Ollama(OllamaRequest {
+            model: &config.model,
+            prompt,
+            stream: false,
+        }),
+        AiProvider::OpenAiCompatible { .. } => {
+            InsightsRequest::ChatCompletion(ChatCompletionRequest {
+                model: &config.model,
+                messages: vec![ChatMessage {
+                    role: "user",
+                    content: prompt,
+                }],
+                stream: false,
+            })
+        }
+    }
+}
+
+/// Generated text from a provider's response body.
+fn parse_insights_response(provider: &AiProvider, body: &str) -> Result<String> {
+    let context = || format!("failed to parse {} response payload", provider.label());
+    match provider {
+        AiProvider::Ollama => {
+            let payload: OllamaResponse = serde_json::from_str(body).with_context(context)?;
+            Ok(payload.response)
+        }
+        AiProvider::OpenAiCompatible { .. } => {
+            let payload: ChatCompletionResponse =
+                serde_json::from_str(body).with_context(context)?;
+            Ok(payload
+                .choices
+                .into_iter()
+                .next()
+                .and_then(|choice| choice.message.content)
+                .unwrap_or_default())
+        }
+    }
+}
+
+/// Bearer token for providers that take one; `None` when unset.
+fn api_key(provider: &AiProvider) -> Option<&str> {
+    match provider {
+        AiProvider::Ollama => None,
+        AiProvider::OpenAiCompatible { api_key } => {
+            Some(api_key.trim()).filter(|key| !key.is_empty())
+        }
+    }
+}
+
+/// Request timeout: just under the refresh interval, but never below the default.
+fn insights_timeout(config: &AiConfig) -> StdDuration {
+    let desired_timeout = if config.refresh > StdDuration::from_secs(1) {
+        config.refresh - StdDuration::from_secs(1)
+    } else {
+        StdDuration::from_secs(DEFAULT_TIMEOUT_SECS)
+    };
+    if desired_timeout >= StdDuration::from_secs(DEFAULT_TIMEOUT_SECS) {
+        desired_timeout
+    } else {
+        StdDuration::from_secs(DEFAULT_TIMEOUT_SECS)
+    }
+}
 
-    let content = payload.response.trim().to_string();
+fn outcome_from_content(config: &AiConfig, content: String) -> AiOutcome {
+    let content = content.trim().to_string();
     if content.is_empty() {
-        Ok(AiOutcome {
+        AiOutcome {
             model: config.model.clone(),
             content: Some("No insights returned by model.".to_string()),
             error: None,
             updated_at: Utc::now(),
-        })
+        }
     } else {
-        Ok(AiOutcome::success(&config.model, content))
+        AiOutcome::success(&config.model, content)
     }
 }
 
@@ -416,34 +619,276 @@ fn summarize_error(message: &str) -> String {
     }
 }
 
-pub fn probe_server(server: &str) -> Result<Vec<String>> {
+/// List the models available on `server`, sorted and deduplicated.
+///
+/// Uses Ollama's `/api/tags` or the OpenAI-compatible `/v1/models`, depending on
+/// `provider`.
+pub fn probe_server(server: &str, provider: &AiProvider) -> Result<Vec<String>> {
+    let label = provider.label();
     let client = build_secure_http_client(StdDuration::from_secs(DEFAULT_TIMEOUT_SECS))
-        .context("failed to construct HTTP client for Ollama")?;
+        .with_context(|| format!("failed to construct HTTP client for {}", label))?;
 
-    let endpoint = format!("{}/api/tags", server.trim_end_matches('/'));
-    let response = client
-        .get(&endpoint)
+    let server = server.trim_end_matches('/');
+    let endpoint = match provider {
+        AiProvider::Ollama => format!("{}/api/tags", server),
+        AiProvider::OpenAiCompatible { .. } => format!("{}/v1/models", server),
+    };
+    let mut request = client.get(&endpoint);
+    if let Some(api_key) = api_key(provider) {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request
         .send()
-        .with_context(|| format!("failed to reach Ollama server at {}", server))?;
+        .with_context(|| format!("failed to reach {} server at {}", label, server))?;
 
     if !response.status().is_success() {
         return Err(anyhow!(
-            "Ollama server returned status {} while listing models",
+            "{} server returned status {} while listing models",
+            label,
             response.status()
         ));
     }
 
-    let tags: OllamaTagsResponse = response
-        .json()
-        .context("failed to parse Ollama model list")?;
-
-    let mut models: Vec<String> = tags.models.into_iter().map(|entry| entry.name).collect();
+    let mut models: Vec<String> = match provider {
+        AiProvider::Ollama => {
+            let tags: OllamaTagsResponse = response
+                .json()
+                .context("failed to parse Ollama model list")?;
+            tags.models.into_iter().map(|entry| entry.name).collect()
+        }
+        AiProvider::OpenAiCompatible { .. } => {
+            let list: OpenAiModelsResponse = response
+                .json()
+                .context("failed to parse OpenAI-compatible model list")?;
+            list.data.into_iter().map(|entry| entry.id).collect()
+        }
+    };
     models.sort();
     models.dedup();
 
     if models.is_empty() {
-        return Err(anyhow!("Ollama server reported no installed models"));
+        return Err(anyhow!("{} server reported no installed models", label));
     }
 
     Ok(models)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "async")]
+    fn sample_data() -> AiData {
+        use chrono::TimeZone;
+
+        let location = astro::Location::new(40.7128, -74.0060).unwrap();
+        let timezone: Tz = "America/New_York".parse().unwrap();
+        let dt = timezone.with_ymd_and_hms(2025, 6, 21, 21, 0, 0).unwrap();
+        build_ai_data(AiDataContext {
+            location: &location,
+            timezone: &timezone,
+            dt: &dt,
+            city_name: Some("New York"),
+            sun_pos: &astro::sun::solar_position(&location, &dt),
+            moon_pos: &astro::moon::lunar_position(&location, &dt),
+            events: Vec::new(),
+            time_sync_info: &TimeSyncInfo {
+                source: "test".to_string(),
+                delta: None,
+                error: None,
+            },
+            lunar_phases: &[],
+        })
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_fetch_reads_mocked_ollama_response() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some(value) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            let body = r#"{"response":"  The midsummer sun lingers.  "}"#;
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            (request_line, String::from_utf8(request_body).unwrap())
+        });
+
+        let config = AiConfig {
+            enabled: true,
+            server,
+            model: "llama3.2:latest".to_string(),
+            refresh: StdDuration::from_secs(120),
+            refresh_mode: crate::config::AiRefreshMode::AutoAndManual,
+            provider: AiProvider::Ollama,
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let outcome = runtime
+            .block_on(fetch_insights_async(&config, &sample_data()))
+            .unwrap();
+
+        assert_eq!(
+            outcome.content.as_deref(),
+            Some("The midsummer sun lingers.")
+        );
+        assert!(outcome.error.is_none());
+
+        let (request_line, request_body) = handle.join().unwrap();
+        assert!(request_line.starts_with("POST /api/generate "));
+        let request: serde_json::Value = serde_json::from_str(&request_body).unwrap();
+        assert_eq!(request["model"], "llama3.2:latest");
+        assert_eq!(request["stream"], false);
+    }
+
+    #[test]
+    fn openai_compatible_request_uses_chat_completions() {
+        let config = AiConfig {
+            enabled: true,
+            server: "https://gateway.example".to_string(),
+            model: "gpt-4o-mini".to_string(),
+            refresh: StdDuration::from_secs(120),
+            refresh_mode: crate::config::AiRefreshMode::AutoAndManual,
+            provider: AiProvider::OpenAiCompatible {
+                api_key: "sk-test".to_string(),
+            },
+        };
+        assert_eq!(
+            config.endpoint(),
+            "https://gateway.example/v1/chat/completions"
+        );
+        assert_eq!(api_key(&config.provider), Some("sk-test"));
+
+        let payload = serde_json::to_value(build_request(&config, "Describe the sky")).unwrap();
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "model": "gpt-4o-mini",
+                "messages": [{ "role": "user", "content": "Describe the sky" }],
+                "stream": false,
+            })
+        );
+
+        let body = r#"{"choices":[{"index":0,"message":{"role":"assistant","content":"Clear."}}]}"#;
+        let content = parse_insights_response(&config.provider, body).unwrap();
+        assert_eq!(content, "Clear.");
+    }
+
+    #[test]
+    fn saved_gateway_settings_fill_in_default_flags() {
+        use clap::Parser;
+
+        let saved: crate::config::AiSettings = serde_json::from_str(
+            r#"{
+                "server": "https://gateway.example.com/",
+                "model": "gpt-4o-mini",
+                "provider": {"kind": "openai_compatible", "api_key": "sk-test"}
+            }"#,
+        )
+        .unwrap();
+
+        let args = crate::cli::Args::parse_from(["solunatus", "--ai-insights"]);
+        let config = AiConfig::from_args(&args).unwrap().merge_with_saved(&args, &saved);
+        assert_eq!(config.server, "https://gateway.example.com");
+        assert_eq!(config.model, "gpt-4o-mini");
+        assert_eq!(config.provider, saved.provider);
+        assert_eq!(
+            config.endpoint(),
+            "https://gateway.example.com/v1/chat/completions"
+        );
+
+        // Flags given on the command line win over the saved settings
+        let args = crate::cli::Args::parse_from([
+            "solunatus",
+            "--ai-insights",
+            "--ai-server",
+            "https://other.example.com",
+            "--ai-model",
+            "mistral-small",
+            "--ai-provider",
+            "openai-compatible",
+            "--ai-api-key",
+            "sk-cli",
+        ]);
+        let config = AiConfig::from_args(&args).unwrap().merge_with_saved(&args, &saved);
+        assert_eq!(config.server, "https://other.example.com");
+        assert_eq!(config.model, "mistral-small");
+        assert_eq!(
+            config.provider,
+            AiProvider::OpenAiCompatible {
+                api_key: "sk-cli".to_string()
+            }
+        );
+
+        // An explicit flag wins even when it names the default value
+        let args = crate::cli::Args::parse_from([
+            "solunatus",
+            "--ai-insights",
+            "--ai-server",
+            "http://localhost:11434",
+            "--ai-model",
+            "llama3",
+            "--ai-provider",
+            "ollama",
+        ]);
+        let config = AiConfig::from_args(&args).unwrap().merge_with_saved(&args, &saved);
+        assert_eq!(config.server, "http://localhost:11434");
+        assert_eq!(config.model, "llama3");
+        assert_eq!(config.provider, AiProvider::Ollama);
+
+        let args = crate::cli::Args::parse_from([
+            "solunatus",
+            "--ai-provider",
+            "ollama",
+            "--ai-api-key",
+            "sk-cli",
+        ]);
+        assert!(AiConfig::from_args(&args).is_err());
+    }
+
+    #[test]
+    fn ollama_stays_the_default_provider() {
+        let settings: crate::config::AiSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings.provider, AiProvider::Ollama);
+
+        let saved: crate::config::AiSettings = serde_json::from_str(
+            r#"{"provider":{"kind":"openai_compatible","api_key":"sk-test"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            saved.provider,
+            AiProvider::OpenAiCompatible {
+                api_key: "sk-test".to_string()
+            }
+        );
+    }
+}
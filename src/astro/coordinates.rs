@@ -1,15 +1,48 @@
 // Coordinate transformation utilities
 
 /// Convert altitude-azimuth to compass bearing
+///
+/// Returns one of the 16 compass points; see [`azimuth_to_compass_16`].
 pub fn azimuth_to_compass(azimuth: f64) -> &'static str {
-    let idx = ((azimuth + 11.25) / 22.5) as usize % 16;
+    azimuth_to_compass_16(azimuth)
+}
+
+/// Convert an azimuth (degrees clockwise from north) to a 16-point compass direction.
+///
+/// Each 22.5° sector is centred on its bearing, so N covers 348.75°–11.25° and NNE
+/// covers 11.25°–33.75°. Azimuths outside 0–360° are wrapped.
+pub fn azimuth_to_compass_16(azimuth: f64) -> &'static str {
     const COMPASS: [&str; 16] = [
         "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW",
         "NW", "NNW",
     ];
+    let idx = ((azimuth.rem_euclid(360.0) + 11.25) / 22.5) as usize % 16;
     COMPASS[idx]
 }
 
+/// Name the zodiac sign occupying an ecliptic longitude (degrees).
+///
+/// Uses the tropical zodiac: twelve 30° sectors starting with Aries at the March
+/// equinox point. Longitudes outside 0–360° are wrapped.
+pub fn zodiac_sign(longitude: f64) -> &'static str {
+    const SIGNS: [&str; 12] = [
+        "Aries",
+        "Taurus",
+        "Gemini",
+        "Cancer",
+        "Leo",
+        "Virgo",
+        "Libra",
+        "Scorpio",
+        "Sagittarius",
+        "Capricorn",
+        "Aquarius",
+        "Pisces",
+    ];
+    let idx = (longitude.rem_euclid(360.0) / 30.0) as usize % 12;
+    SIGNS[idx]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -22,4 +55,24 @@ mod tests {
         assert_eq!(azimuth_to_compass(180.0), "S");
         assert_eq!(azimuth_to_compass(270.0), "W");
     }
+
+    #[test]
+    fn test_azimuth_to_compass_16() {
+        assert_eq!(azimuth_to_compass_16(22.5), "NNE");
+        assert_eq!(azimuth_to_compass_16(247.5), "WSW");
+        assert_eq!(azimuth_to_compass_16(11.0), "N");
+        assert_eq!(azimuth_to_compass_16(11.5), "NNE");
+        assert_eq!(azimuth_to_compass_16(350.0), "N");
+        assert_eq!(azimuth_to_compass_16(-22.5), "NNW");
+        assert_eq!(azimuth_to_compass_16(382.5), "NNE");
+    }
+
+    #[test]
+    fn test_zodiac_sign() {
+        assert_eq!(zodiac_sign(0.0), "Aries");
+        assert_eq!(zodiac_sign(29.9), "Aries");
+        assert_eq!(zodiac_sign(30.0), "Taurus");
+        assert_eq!(zodiac_sign(359.9), "Pisces");
+        assert_eq!(zodiac_sign(-15.0), "Pisces");
+    }
 }
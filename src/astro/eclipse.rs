@@ -0,0 +1,201 @@
+//! Lunar eclipse prediction.
+//!
+//! Implements the eclipse method from Jean Meeus, "Astronomical Algorithms"
+//! (Chapter 54): each full moon is screened by the moon's argument of latitude,
+//! then the surviving candidates get a corrected time of maximum, the distance
+//! of the moon's center from the shadow axis (γ), and umbral/penumbral magnitudes.
+//!
+//! Times of maximum are typically within a few minutes of published values.
+
+use super::time_utils::jde_to_utc;
+use super::*;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+/// Classification of a lunar eclipse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LunarEclipseType {
+    /// Moon passes only through Earth's penumbra
+    Penumbral,
+    /// Moon is partly inside the umbra
+    Partial,
+    /// Moon is entirely inside the umbra
+    Total,
+}
+
+/// A predicted lunar eclipse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LunarEclipse {
+    /// Time of greatest eclipse (UTC)
+    pub maximum: DateTime<Utc>,
+    pub eclipse_type: LunarEclipseType,
+    /// Umbral magnitude for partial/total eclipses, penumbral magnitude otherwise
+    pub magnitude: f64,
+}
+
+/// Find all lunar eclipses whose maximum falls in `start..=end` (UTC dates).
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::eclipse::{lunar_eclipses, LunarEclipseType};
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+/// for eclipse in lunar_eclipses(start, end) {
+///     println!("{:?} eclipse at {}", eclipse.eclipse_type, eclipse.maximum);
+/// }
+/// ```
+pub fn lunar_eclipses(start: NaiveDate, end: NaiveDate) -> Vec<LunarEclipse> {
+    if start > end {
+        return Vec::new();
+    }
+
+    let mut k = ((decimal_year(start) - 2000.0) * 12.3685).floor() - 1.0;
+    let mut eclipses = Vec::new();
+
+    loop {
+        // Full moons sit at half-integer k
+        let full_k = k + 0.5;
+        k += 1.0;
+
+        let Some(eclipse) = eclipse_at_full_moon(full_k) else {
+            if mean_full_moon_jde(full_k) > end_jde(end) {
+                break;
+            }
+            continue;
+        };
+
+        let date = eclipse.maximum.date_naive();
+        if date > end {
+            break;
+        }
+        if date >= start {
+            eclipses.push(eclipse);
+        }
+    }
+
+    eclipses
+}
+
+/// Decimal year of a date, good enough to seed the lunation count
+fn decimal_year(date: NaiveDate) -> f64 {
+    date.year() as f64 + date.ordinal0() as f64 / 365.25
+}
+
+fn end_jde(end: NaiveDate) -> f64 {
+    let midnight = end.and_hms_opt(23, 59, 59).unwrap().and_utc();
+    julian_day(&midnight) + 1.0
+}
+
+fn mean_full_moon_jde(k: f64) -> f64 {
+    let t = k / 1236.85;
+    2451550.09766 + 29.530588861 * k + 0.00015437 * t * t - 0.000000150 * t.powi(3)
+        + 0.00000000073 * t.powi(4)
+}
+
+/// Evaluate the full moon for lunation `k` (half-integer) and return the eclipse, if any
+fn eclipse_at_full_moon(k: f64) -> Option<LunarEclipse> {
+    let t = k / 1236.85;
+
+    let f = normalize_degrees(
+        160.7108 + 390.67050284 * k - 0.0016118 * t * t - 0.00000227 * t.powi(3)
+            + 0.000000011 * t.powi(4),
+    );
+
+    // No eclipse when the moon is too far from a node
+    if (f * DEG_TO_RAD).sin().abs() > 0.36 {
+        return None;
+    }
+
+    let m = normalize_degrees(
+        2.5534 + 29.10535670 * k - 0.0000014 * t * t - 0.00000011 * t.powi(3),
+    ) * DEG_TO_RAD;
+    let mp = normalize_degrees(
+        201.5643 + 385.81693528 * k + 0.0107582 * t * t + 0.00001238 * t.powi(3)
+            - 0.000000058 * t.powi(4),
+    ) * DEG_TO_RAD;
+    let omega = normalize_degrees(
+        124.7746 - 1.56375588 * k + 0.0020672 * t * t + 0.00000215 * t.powi(3),
+    ) * DEG_TO_RAD;
+    let e = 1.0 - 0.002516 * t - 0.0000074 * t * t;
+    let f1 = (f - 0.02665 * omega.sin()) * DEG_TO_RAD;
+    let a1 = (299.77 + 0.107408 * k - 0.009173 * t * t) * DEG_TO_RAD;
+
+    let jde = mean_full_moon_jde(k) - 0.4065 * mp.sin() + 0.1727 * e * m.sin()
+        + 0.0161 * (2.0 * mp).sin()
+        - 0.0097 * (2.0 * f1).sin()
+        + 0.0073 * e * (mp - m).sin()
+        - 0.0050 * e * (mp + m).sin()
+        - 0.0023 * (mp - 2.0 * f1).sin()
+        + 0.0021 * e * (2.0 * m).sin()
+        + 0.0012 * (mp + 2.0 * f1).sin()
+        + 0.0006 * e * (2.0 * mp + m).sin()
+        - 0.0004 * (3.0 * mp).sin()
+        - 0.0003 * e * (m + 2.0 * f1).sin()
+        + 0.0003 * a1.sin()
+        - 0.0002 * e * (m - 2.0 * f1).sin()
+        - 0.0002 * e * (2.0 * mp - m).sin()
+        - 0.0002 * omega.sin();
+
+    let p = 0.2070 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin()
+        + 0.0116 * (2.0 * mp).sin()
+        - 0.0073 * e * (mp + m).sin()
+        + 0.0067 * e * (mp - m).sin()
+        + 0.0118 * (2.0 * f1).sin();
+    let q = 5.2207 - 0.0048 * e * m.cos() + 0.0020 * e * (2.0 * m).cos() - 0.3299 * mp.cos()
+        - 0.0060 * e * (mp + m).cos()
+        + 0.0041 * e * (mp - m).cos();
+    let w = f1.cos().abs();
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+    let u = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos()
+        - 0.0005 * (m + mp).cos();
+
+    let penumbral_magnitude = (1.5573 + u - gamma.abs()) / 0.5450;
+    let umbral_magnitude = (1.0128 - u - gamma.abs()) / 0.5450;
+
+    if penumbral_magnitude <= 0.0 {
+        return None;
+    }
+
+    let (eclipse_type, magnitude) = if umbral_magnitude >= 1.0 {
+        (LunarEclipseType::Total, umbral_magnitude)
+    } else if umbral_magnitude > 0.0 {
+        (LunarEclipseType::Partial, umbral_magnitude)
+    } else {
+        (LunarEclipseType::Penumbral, penumbral_magnitude)
+    };
+
+    Some(LunarEclipse {
+        maximum: jde_to_utc(jde),
+        eclipse_type,
+        magnitude,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn detects_march_2025_total_eclipse() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        let eclipses = lunar_eclipses(start, end);
+
+        // 2025 has two total lunar eclipses: March 14 and September 7
+        assert_eq!(eclipses.len(), 2, "{:?}", eclipses);
+
+        let march = eclipses[0];
+        let known_max = Utc.with_ymd_and_hms(2025, 3, 14, 6, 58, 43).unwrap();
+        assert_eq!(march.eclipse_type, LunarEclipseType::Total);
+        let offset = (march.maximum - known_max).num_minutes().abs();
+        assert!(offset <= 5, "maximum at {}", march.maximum);
+        assert!((march.magnitude - 1.178).abs() < 0.05, "magnitude {}", march.magnitude);
+
+        assert_eq!(eclipses[1].eclipse_type, LunarEclipseType::Total);
+        let september = NaiveDate::from_ymd_opt(2025, 9, 7).unwrap();
+        assert_eq!(eclipses[1].maximum.date_naive(), september);
+    }
+}
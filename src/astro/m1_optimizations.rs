@@ -147,7 +147,7 @@ pub fn m1_batch_altitude(
 pub fn parallel_event_collection_m1(
     locations: &[crate::astro::Location],
     times: &[chrono::DateTime<chrono_tz::Tz>],
-) -> Vec<Vec<(chrono::DateTime<chrono_tz::Tz>, &'static str)>> {
+) -> Vec<Vec<(chrono::DateTime<chrono_tz::Tz>, crate::events::AstroEventKind)>> {
     // Placeholder for future rayon-based parallelization
     // When rayon is added as dependency, enable with --features parallel
     locations
@@ -186,6 +186,9 @@ impl M1L2OptimizedState {
             solar_pos: sun::SolarPosition {
                 altitude: 0.0,
                 azimuth: 0.0,
+                declination: 0.0,
+                right_ascension: 0.0,
+                distance_au: 0.0,
             },
             lunar_pos: moon::LunarPosition {
                 altitude: 0.0,
@@ -194,6 +197,9 @@ impl M1L2OptimizedState {
                 illumination: 0.0,
                 phase_angle: 0.0,
                 angular_diameter: 0.0,
+                parallax_correction_deg: 0.0,
+                horizontal_parallax: 0.0,
+                semi_diameter: 0.0,
             },
             trig_cache: [0.0; 16],
         }
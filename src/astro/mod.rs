@@ -12,6 +12,9 @@
 //!
 //! - [`sun`] - Solar position and event calculations
 //! - [`moon`] - Lunar position, phases, and event calculations
+//! - [`eclipse`] - Lunar eclipse prediction
+//! - [`seasons`] - Equinox and solstice times
+//! - [`sidereal`] - Greenwich and local sidereal time
 //! - [`units`] - Type-safe angle and coordinate units
 //! - [`coordinates`] - Coordinate system transformations
 //! - [`time_utils`] - Time and Julian Day utilities
@@ -20,42 +23,163 @@
 //! - [`moon_batch_optimized`] - Batch lunar calculations with parallelization
 
 pub mod coordinates;
+pub mod eclipse;
 pub mod m1_optimizations;
 pub mod moon;
 pub mod moon_batch_optimized;
+pub mod seasons;
+pub mod sidereal;
 pub mod simd_math;
 pub mod sun;
 pub mod time_utils;
 pub mod units;
 
 use chrono::{DateTime, Datelike, TimeZone, Timelike};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use units::{Latitude, Longitude};
 
 // Re-export commonly used types
 pub use units::{Altitude, Azimuth, Degrees, Radians, DEG_TO_RAD, RAD_TO_DEG};
 
+/// Reasons a [`Location`] can fail validation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LocationError {
+    /// Latitude outside -90° to 90°
+    LatitudeOutOfRange(f64),
+    /// Longitude outside -180° to 180°
+    LongitudeOutOfRange(f64),
+    /// Elevation is NaN or infinite
+    InvalidElevation(f64),
+    /// Temperature is NaN or infinite
+    InvalidTemperature(f64),
+    /// Pressure is not a positive, finite number
+    InvalidPressure(f64),
+    /// [`LocationBuilder::build`] was called without a latitude
+    MissingLatitude,
+    /// [`LocationBuilder::build`] was called without a longitude
+    MissingLongitude,
+    /// Text passed to [`Location::from_str`] does not hold two coordinates
+    MissingCoordinatePair(String),
+    /// A coordinate in text passed to [`Location::from_str`] is not decimal or DMS
+    InvalidCoordinate(String),
+    /// Both coordinates in text passed to [`Location::from_str`] carry N/S (or E/W)
+    AmbiguousHemisphere(String),
+}
+
+impl fmt::Display for LocationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LocationError::LatitudeOutOfRange(lat) => {
+                write!(f, "Invalid latitude: {} (must be -90 to 90)", lat)
+            }
+            LocationError::LongitudeOutOfRange(lon) => {
+                write!(f, "Invalid longitude: {} (must be -180 to 180)", lon)
+            }
+            LocationError::InvalidElevation(meters) => {
+                write!(f, "Elevation must be a finite number, got {}", meters)
+            }
+            LocationError::InvalidTemperature(celsius) => {
+                write!(f, "Temperature must be a finite number, got {}", celsius)
+            }
+            LocationError::InvalidPressure(hpa) => {
+                write!(f, "Pressure must be a positive number of hPa, got {}", hpa)
+            }
+            LocationError::MissingLatitude => write!(f, "Latitude is required"),
+            LocationError::MissingLongitude => write!(f, "Longitude is required"),
+            LocationError::MissingCoordinatePair(input) => {
+                write!(f, "Expected a latitude and longitude, got '{}'", input)
+            }
+            LocationError::InvalidCoordinate(text) => write!(f, "Invalid coordinate '{}'", text),
+            LocationError::AmbiguousHemisphere(input) => {
+                write!(f, "Ambiguous hemisphere letters in '{}'", input)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LocationError {}
+
 /// Location on Earth
-/// All calculations assume sea level (0m elevation) per USNO celestial navigation convention
+/// Defaults to sea level (0m elevation) per USNO celestial navigation convention;
+/// use [`Location::with_elevation`] to account for the dip of the horizon at altitude,
+/// or [`Location::builder`] to also record local atmospheric conditions
+///
+/// Equality and hashing compare quantized values so locations can key caches:
+/// coordinates to 1e-7° (about 1 cm), elevation to 1 mm, temperature to 0.001°C
+/// and pressure to 0.001 hPa. Serialized as plain numbers, validated on deserialize.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "LocationRecord", try_from = "LocationRecord")
+)]
 pub struct Location {
     pub latitude: Latitude,  // positive North
     pub longitude: Longitude, // positive East
+    pub elevation_m: f64,     // observer height above sea level in meters
+    pub temperature_c: f64,   // air temperature in degrees Celsius
+    pub pressure_hpa: f64,    // atmospheric pressure in hectopascals
 }
 
 impl Location {
     /// Create a new location with validation
-    pub fn new(lat: f64, lon: f64) -> Result<Self, String> {
+    pub fn new(lat: f64, lon: f64) -> Result<Self, LocationError> {
+        Self::with_elevation(lat, lon, 0.0)
+    }
+
+    /// Create a new location at the given elevation (meters above sea level)
+    pub fn with_elevation(lat: f64, lon: f64, meters: f64) -> Result<Self, LocationError> {
+        if !meters.is_finite() {
+            return Err(LocationError::InvalidElevation(meters));
+        }
+        let atmosphere = sun::RefractionParams::default();
         Ok(Self {
             latitude: Latitude::new(lat)?,
             longitude: Longitude::new(lon)?,
+            elevation_m: meters,
+            temperature_c: atmosphere.temperature_c,
+            pressure_hpa: atmosphere.pressure_hpa,
         })
     }
 
+    /// Start building a location with optional elevation and atmospheric conditions
+    pub fn builder() -> LocationBuilder {
+        LocationBuilder::default()
+    }
+
     /// Create a new location without validation (use only when values are known to be valid)
     pub fn new_unchecked(lat: f64, lon: f64) -> Self {
+        let atmosphere = sun::RefractionParams::default();
         Self {
             latitude: Latitude::new_unchecked(lat),
             longitude: Longitude::new_unchecked(lon),
+            elevation_m: 0.0,
+            temperature_c: atmosphere.temperature_c,
+            pressure_hpa: atmosphere.pressure_hpa,
+        }
+    }
+
+    /// Atmospheric conditions at this location, for
+    /// [`solar_event_time_with_refraction`](sun::solar_event_time_with_refraction)
+    pub fn refraction_params(&self) -> sun::RefractionParams {
+        sun::RefractionParams {
+            temperature_c: self.temperature_c,
+            pressure_hpa: self.pressure_hpa,
+        }
+    }
+
+    /// Geometric dip of the horizon in degrees for the observer's elevation.
+    ///
+    /// Approximately 1.76' × √h for h in meters; zero at or below sea level.
+    pub fn horizon_dip_degrees(&self) -> f64 {
+        if self.elevation_m > 0.0 {
+            1.76 * self.elevation_m.sqrt() / 60.0
+        } else {
+            0.0
         }
     }
 
@@ -70,6 +194,252 @@ impl Location {
     }
 }
 
+impl Location {
+    /// Field values rounded to the precision used by `PartialEq` and `Hash`
+    fn quantized(&self) -> [i64; 5] {
+        [
+            (self.latitude.value() * 1e7).round() as i64,
+            (self.longitude.value() * 1e7).round() as i64,
+            (self.elevation_m * 1e3).round() as i64,
+            (self.temperature_c * 1e3).round() as i64,
+            (self.pressure_hpa * 1e3).round() as i64,
+        ]
+    }
+}
+
+impl PartialEq for Location {
+    fn eq(&self, other: &Self) -> bool {
+        self.quantized() == other.quantized()
+    }
+}
+
+impl Eq for Location {}
+
+impl Hash for Location {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.quantized().hash(state);
+    }
+}
+
+/// Serialized form of a [`Location`]; atmosphere fields are optional on input.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct LocationRecord {
+    latitude: f64,
+    longitude: f64,
+    #[serde(default)]
+    elevation_m: f64,
+    #[serde(default = "default_temperature_c")]
+    temperature_c: f64,
+    #[serde(default = "default_pressure_hpa")]
+    pressure_hpa: f64,
+}
+
+#[cfg(feature = "serde")]
+fn default_temperature_c() -> f64 {
+    sun::RefractionParams::default().temperature_c
+}
+
+#[cfg(feature = "serde")]
+fn default_pressure_hpa() -> f64 {
+    sun::RefractionParams::default().pressure_hpa
+}
+
+#[cfg(feature = "serde")]
+impl From<Location> for LocationRecord {
+    fn from(location: Location) -> Self {
+        Self {
+            latitude: location.latitude.value(),
+            longitude: location.longitude.value(),
+            elevation_m: location.elevation_m,
+            temperature_c: location.temperature_c,
+            pressure_hpa: location.pressure_hpa,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LocationRecord> for Location {
+    type Error = LocationError;
+
+    fn try_from(record: LocationRecord) -> Result<Self, Self::Error> {
+        Location::builder()
+            .latitude(record.latitude)
+            .longitude(record.longitude)
+            .elevation_m(record.elevation_m)
+            .temperature_c(record.temperature_c)
+            .pressure_hpa(record.pressure_hpa)
+            .build()
+    }
+}
+
+/// Builder for a [`Location`] with elevation and atmospheric conditions.
+///
+/// Latitude and longitude are required; elevation defaults to sea level and the
+/// atmosphere to the standard 10°C and 1010 hPa, matching [`Location::new`].
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::Location;
+///
+/// let denver = Location::builder()
+///     .latitude(39.7392)
+///     .longitude(-104.9903)
+///     .elevation_m(1609.0)
+///     .pressure_hpa(835.0)
+///     .build()
+///     .unwrap();
+/// assert_eq!(denver.elevation_m, 1609.0);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocationBuilder {
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    elevation_m: Option<f64>,
+    temperature_c: Option<f64>,
+    pressure_hpa: Option<f64>,
+}
+
+impl LocationBuilder {
+    /// Latitude in degrees (positive North)
+    pub fn latitude(mut self, degrees: f64) -> Self {
+        self.latitude = Some(degrees);
+        self
+    }
+
+    /// Longitude in degrees (positive East)
+    pub fn longitude(mut self, degrees: f64) -> Self {
+        self.longitude = Some(degrees);
+        self
+    }
+
+    /// Observer height above sea level in meters
+    pub fn elevation_m(mut self, meters: f64) -> Self {
+        self.elevation_m = Some(meters);
+        self
+    }
+
+    /// Air temperature in degrees Celsius
+    pub fn temperature_c(mut self, celsius: f64) -> Self {
+        self.temperature_c = Some(celsius);
+        self
+    }
+
+    /// Atmospheric pressure in hectopascals
+    pub fn pressure_hpa(mut self, hpa: f64) -> Self {
+        self.pressure_hpa = Some(hpa);
+        self
+    }
+
+    /// Validate the coordinates, elevation and atmosphere and build the location
+    pub fn build(self) -> Result<Location, LocationError> {
+        let lat = self.latitude.ok_or(LocationError::MissingLatitude)?;
+        let lon = self.longitude.ok_or(LocationError::MissingLongitude)?;
+        let mut location = Location::with_elevation(lat, lon, self.elevation_m.unwrap_or(0.0))?;
+        if let Some(celsius) = self.temperature_c {
+            if !celsius.is_finite() {
+                return Err(LocationError::InvalidTemperature(celsius));
+            }
+            location.temperature_c = celsius;
+        }
+        if let Some(hpa) = self.pressure_hpa {
+            if !(hpa.is_finite() && hpa > 0.0) {
+                return Err(LocationError::InvalidPressure(hpa));
+            }
+            location.pressure_hpa = hpa;
+        }
+        Ok(location)
+    }
+}
+
+/// Parse `"lat,lon"` in decimal degrees (as copied from Google Maps) or
+/// degrees-minutes-seconds with hemisphere letters, e.g. `40°42'46"N 74°00'22"W`.
+///
+/// Out-of-range values produce the same errors as [`Location::new`].
+impl FromStr for Location {
+    type Err = LocationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        let (first, second) = split_coordinate_pair(input)
+            .ok_or_else(|| LocationError::MissingCoordinatePair(input.to_string()))?;
+        let (mut lat, lat_axis) = parse_coordinate(first)?;
+        let (mut lon, lon_axis) = parse_coordinate(second)?;
+
+        match (lat_axis, lon_axis) {
+            (Some('E' | 'W'), Some('N' | 'S')) => std::mem::swap(&mut lat, &mut lon),
+            (Some('E' | 'W'), _) | (_, Some('N' | 'S')) => {
+                return Err(LocationError::AmbiguousHemisphere(input.to_string()))
+            }
+            _ => {}
+        }
+
+        Location::new(lat, lon)
+    }
+}
+
+/// Split a coordinate pair on a comma, after a hemisphere letter, or on whitespace.
+fn split_coordinate_pair(input: &str) -> Option<(&str, &str)> {
+    if let Some((first, second)) = input.split_once(',') {
+        return Some((first.trim(), second.trim()));
+    }
+
+    // Letters either follow each value ("40N 74W") or lead it ("N40 W74")
+    let letters: Vec<usize> = input
+        .char_indices()
+        .filter(|(_, c)| matches!(c.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W'))
+        .map(|(idx, _)| idx)
+        .collect();
+    let split = match letters.as_slice() {
+        [0, second, ..] => Some(*second),
+        [first, ..] if *first + 1 < input.len() => Some(first + 1),
+        _ => None,
+    };
+    if let Some(split) = split {
+        return Some((input[..split].trim(), input[split..].trim()));
+    }
+
+    let mut parts = input.split_whitespace();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(first), Some(second), None) => Some((first, second)),
+        _ => None,
+    }
+}
+
+/// Parse one decimal or DMS coordinate, returning signed degrees and any hemisphere letter.
+fn parse_coordinate(text: &str) -> Result<(f64, Option<char>), LocationError> {
+    let mut body = text.trim();
+    let mut hemisphere = None;
+    for (idx, c) in [(0, body.chars().next()), (1, body.chars().last())] {
+        if let Some(c) = c.map(|c| c.to_ascii_uppercase()) {
+            if matches!(c, 'N' | 'S' | 'E' | 'W') && hemisphere.is_none() {
+                hemisphere = Some(c);
+                body = if idx == 0 { &body[1..] } else { &body[..body.len() - 1] };
+            }
+        }
+    }
+
+    let fields: Vec<f64> = body
+        .split(|c: char| c.is_whitespace() || "°º'′\"″".contains(c))
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.parse::<f64>()
+                .map_err(|_| LocationError::InvalidCoordinate(text.trim().to_string()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let magnitude = match fields.as_slice() {
+        [deg] => deg.abs(),
+        [deg, min] => deg.abs() + min / 60.0,
+        [deg, min, sec] => deg.abs() + min / 60.0 + sec / 3600.0,
+        _ => return Err(LocationError::InvalidCoordinate(text.trim().to_string())),
+    };
+    let negative = body.trim_start().starts_with('-') || matches!(hemisphere, Some('S' | 'W'));
+
+    Ok((if negative { -magnitude } else { magnitude }, hemisphere))
+}
+
 /// Calculate Julian Day from a given date and time.
 ///
 /// Julian Day is a continuous count of days since the beginning of the Julian Period.
@@ -88,12 +458,15 @@ impl Location {
 /// - Julian Day is always defined in UTC
 /// - The algorithms are formulated for Universal Time (UT), not Terrestrial Time (TT)
 /// - The epoch (JD 0) corresponds to January 1, 4713 BC at noon
+/// - `DateTime` is proleptic Gregorian, so every date uses the Gregorian formula; use
+///   [`julian_day_from_julian_calendar`] for dates written in the Julian calendar
+/// - Years use astronomical numbering (year 0 = 1 BCE, year -1 = 2 BCE)
 ///
 /// # Examples
 ///
 /// ```
 /// use solunatus::astro::julian_day;
-/// use chrono::Utc;
+/// use chrono::{TimeZone, Utc};
 ///
 /// let dt = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
 /// let jd = julian_day(&dt);
@@ -118,12 +491,37 @@ pub fn julian_day<T: TimeZone>(dt: &DateTime<T>) -> f64 {
         m += 12.0;
     }
 
+    // Gregorian correction; chrono dates are proleptic Gregorian on both sides of 1582
     let a = (y / 100.0).floor();
     let b = 2.0 - a + (a / 4.0).floor();
 
     (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + day + b - 1524.5
 }
 
+/// Calculate the Julian Day for a date written in the Julian calendar.
+///
+/// For historical dates recorded before the Gregorian reform (e.g. 1582-10-04,
+/// the day before 1582-10-15 Gregorian). `day` may carry a fraction of a day, and
+/// years use astronomical numbering.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::julian_day_from_julian_calendar;
+///
+/// // Meeus Example 7.b: 333 January 27.5 (Julian)
+/// assert_eq!(julian_day_from_julian_calendar(333, 1, 27.5), 1842713.0);
+/// ```
+pub fn julian_day_from_julian_calendar(year: i32, month: u32, day: f64) -> f64 {
+    let (y, m) = if month <= 2 {
+        (year as f64 - 1.0, month as f64 + 12.0)
+    } else {
+        (year as f64, month as f64)
+    };
+
+    (365.25 * (y + 4716.0)).floor() + (30.6001 * (m + 1.0)).floor() + day - 1524.5
+}
+
 /// Calculate Julian Century from a Julian Day number.
 ///
 /// Julian Century is the number of centuries since the J2000.0 epoch (JD 2451545.0),
@@ -203,6 +601,84 @@ mod tests {
     use super::*;
     use chrono::Utc;
 
+    #[test]
+    fn nearly_identical_locations_hash_equal() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |location: &Location| {
+            let mut hasher = DefaultHasher::new();
+            location.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = Location::new(40.7128, -74.0060).unwrap();
+        let b = Location::new(40.7128 + 5e-10, -74.0060 - 5e-10).unwrap();
+        let c = Location::new(40.7129, -74.0060).unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+        assert_ne!(a, c);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn location_round_trips_through_json() {
+        let location = Location::with_elevation(51.5074, -0.1278, 35.0).unwrap();
+        let json = serde_json::to_string(&location).unwrap();
+        assert_eq!(serde_json::from_str::<Location>(&json).unwrap(), location);
+
+        let minimal = r#"{"latitude":1.0,"longitude":2.0}"#;
+        let minimal: Location = serde_json::from_str(minimal).unwrap();
+        assert_eq!(minimal, Location::new(1.0, 2.0).unwrap());
+        assert!(serde_json::from_str::<Location>(r#"{"latitude":95.0,"longitude":0.0}"#).is_err());
+        let thin_air = r#"{"latitude":1.0,"longitude":2.0,"pressure_hpa":-1.0}"#;
+        assert!(serde_json::from_str::<Location>(thin_air).is_err());
+    }
+
+    #[test]
+    fn builder_stores_all_fields() {
+        let location = Location::builder()
+            .latitude(-33.8688)
+            .longitude(151.2093)
+            .elevation_m(58.0)
+            .temperature_c(24.5)
+            .pressure_hpa(1002.0)
+            .build()
+            .unwrap();
+
+        assert_eq!(location.latitude.value(), -33.8688);
+        assert_eq!(location.longitude.value(), 151.2093);
+        assert_eq!(location.elevation_m, 58.0);
+        assert_eq!(location.temperature_c, 24.5);
+        assert_eq!(location.pressure_hpa, 1002.0);
+        assert_eq!(location.refraction_params().pressure_hpa, 1002.0);
+
+        let defaults = Location::builder().latitude(10.0).longitude(20.0).build().unwrap();
+        let plain = Location::new(10.0, 20.0).unwrap();
+        assert_eq!(defaults.elevation_m, plain.elevation_m);
+        assert_eq!(defaults.refraction_params(), sun::RefractionParams::default());
+
+        let missing = Location::builder().latitude(10.0).build().unwrap_err();
+        assert_eq!(missing, LocationError::MissingLongitude);
+
+        let base = Location::builder().latitude(10.0).longitude(20.0);
+        let err = base.temperature_c(f64::NAN).build().unwrap_err();
+        assert!(matches!(err, LocationError::InvalidTemperature(t) if t.is_nan()));
+        for hpa in [0.0, -5.0, f64::INFINITY] {
+            let err = base.pressure_hpa(hpa).build().unwrap_err();
+            assert_eq!(err, LocationError::InvalidPressure(hpa));
+        }
+    }
+
+    #[test]
+    fn location_errors_are_structured() {
+        let err = Location::new(91.0, 0.0).unwrap_err();
+        assert!(matches!(err, LocationError::LatitudeOutOfRange(lat) if lat == 91.0));
+        assert_eq!(err.to_string(), "Invalid latitude: 91 (must be -90 to 90)");
+
+        let err = Location::new(0.0, -181.0).unwrap_err();
+        assert_eq!(err, LocationError::LongitudeOutOfRange(-181.0));
+    }
+
     #[test]
     fn test_julian_day() {
         // Test known Julian Day values
@@ -212,6 +688,76 @@ mod tests {
         assert!((jd - 2451545.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_location_from_decimal_str() {
+        let location: Location = "40.7128,-74.0060".parse().unwrap();
+        assert_eq!(location.lat_degrees(), 40.7128);
+        assert_eq!(location.lon_degrees(), -74.0060);
+
+        let spaced: Location = " -33.8688, 151.2093 ".parse().unwrap();
+        assert_eq!(spaced.lat_degrees(), -33.8688);
+        assert_eq!(spaced.lon_degrees(), 151.2093);
+    }
+
+    #[test]
+    fn test_location_from_dms_str() {
+        let location: Location = "40°42'46\"N 74°00'22\"W".parse().unwrap();
+        assert!((location.lat_degrees() - 40.712_778).abs() < 1e-6);
+        assert!((location.lon_degrees() + 74.006_111).abs() < 1e-6);
+
+        let southern: Location = "33°52′08″S, 151°12′33″E".parse().unwrap();
+        assert!((southern.lat_degrees() + 33.868_889).abs() < 1e-6);
+        assert!((southern.lon_degrees() - 151.209_167).abs() < 1e-6);
+
+        let leading: Location = "N51 30.4 W0 7.7".parse().unwrap();
+        assert!((leading.lat_degrees() - 51.506_667).abs() < 1e-6);
+        assert!((leading.lon_degrees() + 0.128_333).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_location_from_str_errors() {
+        let err = "95.0,10.0".parse::<Location>().unwrap_err();
+        assert_eq!(err, LocationError::LatitudeOutOfRange(95.0));
+        assert_eq!(
+            "40.7128".parse::<Location>().unwrap_err(),
+            LocationError::MissingCoordinatePair("40.7128".to_string())
+        );
+        let err = "north,west".parse::<Location>().unwrap_err();
+        assert_eq!(err, LocationError::InvalidCoordinate("north".to_string()));
+        assert_eq!(err.to_string(), "Invalid coordinate 'north'");
+        assert_eq!(
+            "40N 74S".parse::<Location>().unwrap_err(),
+            LocationError::AmbiguousHemisphere("40N 74S".to_string())
+        );
+    }
+
+    #[test]
+    fn test_julian_day_is_proleptic_gregorian() {
+        let gregorian = Utc.with_ymd_and_hms(1582, 10, 15, 0, 0, 0).unwrap();
+        assert_eq!(julian_day(&gregorian), 2299160.5);
+
+        // One chrono day is one Julian Day, straight through the reform
+        let mut dt = Utc.with_ymd_and_hms(1582, 9, 25, 0, 0, 0).unwrap();
+        while dt < Utc.with_ymd_and_hms(1582, 11, 5, 0, 0, 0).unwrap() {
+            let next = dt + chrono::Duration::days(1);
+            assert_eq!(julian_day(&next) - julian_day(&dt), 1.0, "{dt}");
+            dt = next;
+        }
+
+        // Meeus: JD 1356001.0 is -1000 July 12.5 in the Julian calendar, which the
+        // proleptic Gregorian calendar puts nine days earlier
+        let bce = Utc.with_ymd_and_hms(-1000, 7, 3, 12, 0, 0).unwrap();
+        assert_eq!(julian_day(&bce), 1356001.0);
+    }
+
+    #[test]
+    fn test_julian_day_from_julian_calendar() {
+        // Meeus: 1582-10-04 (Julian) is the day before 1582-10-15 (Gregorian)
+        assert_eq!(julian_day_from_julian_calendar(1582, 10, 4.0), 2299159.5);
+        assert_eq!(julian_day_from_julian_calendar(-1000, 7, 12.5), 1356001.0);
+        assert_eq!(julian_day_from_julian_calendar(-4712, 1, 1.5), 0.0);
+    }
+
     #[test]
     fn test_normalize_degrees() {
         assert_eq!(normalize_degrees(370.0), 10.0);
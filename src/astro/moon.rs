@@ -15,12 +15,18 @@
 //! and lunar phase times accurate to within a few minutes.
 
 use super::*;
+use super::sun::RefractionModel;
+use super::time_utils::jd_to_utc;
 use chrono::{DateTime, Datelike, Duration, LocalResult, TimeZone};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Types of major lunar phases.
 ///
 /// The moon goes through four major phases each lunar month (approximately 29.5 days).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LunarPhaseType {
     /// New moon (moon between Earth and Sun, not visible)
     NewMoon,
@@ -36,6 +42,7 @@ pub enum LunarPhaseType {
 ///
 /// Contains the type of phase and the exact time it occurs.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LunarPhase {
     /// The type of lunar phase
     pub phase_type: LunarPhaseType,
@@ -48,6 +55,7 @@ pub struct LunarPhase {
 /// Contains comprehensive information about the moon's position in the sky
 /// and its visual appearance at a specific time and location.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LunarPosition {
     /// Altitude in degrees above the horizon (negative if below horizon)
     pub altitude: f64,
@@ -61,6 +69,13 @@ pub struct LunarPosition {
     pub phase_angle: f64,
     /// Angular diameter in arcminutes as seen from Earth
     pub angular_diameter: f64,
+    /// Topocentric parallax correction applied to the altitude, in degrees
+    /// (geocentric altitude minus topocentric altitude; ~0 at zenith, ~1° at horizon)
+    pub parallax_correction_deg: f64,
+    /// Equatorial horizontal parallax in degrees (~0.9°–1.0°)
+    pub horizontal_parallax: f64,
+    /// Geocentric apparent semi-diameter in arcminutes (~15'–17')
+    pub semi_diameter: f64,
 }
 
 /// Types of lunar events that can be calculated.
@@ -176,6 +191,60 @@ fn moon_ecliptic_coords(t: f64) -> (f64, f64) {
     (normalize_degrees(longitude), latitude)
 }
 
+/// Calculate the moon's geocentric ecliptic longitude in degrees (0–360).
+///
+/// Pass the result to [`zodiac_sign`](super::coordinates::zodiac_sign) to name
+/// the sign the moon occupies.
+pub fn moon_ecliptic_longitude<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    moon_ecliptic_coords(julian_century(julian_day(dt))).0
+}
+
+/// Mean longitude of the Moon's ascending node (degrees)
+fn moon_ascending_node(t: f64) -> f64 {
+    let omega = 125.0445479
+        + t * (-1934.1362891 + t * (0.0020754 + t * (1.0 / 467441.0 + t * (-1.0 / 60616000.0))));
+    normalize_degrees(omega)
+}
+
+/// Optical libration of the Moon, in degrees.
+///
+/// Positive longitude brings the eastern limb (Mare Crisium side) into view;
+/// positive latitude tilts the northern limb toward the observer.
+#[derive(Debug, Clone, Copy)]
+pub struct LunarLibration {
+    /// Libration in longitude (roughly ±8°)
+    pub longitude: f64,
+    /// Libration in latitude (roughly ±7°)
+    pub latitude: f64,
+}
+
+/// Calculate the Moon's optical libration at a given time.
+///
+/// Uses the optical libration formulas of Meeus Chapter 53 with the ecliptic
+/// coordinates from the lunar position model. Physical libration (< 0.04°) is
+/// ignored.
+pub fn lunar_libration<T: TimeZone>(dt: &DateTime<T>) -> LunarLibration {
+    // Inclination of the mean lunar equator to the ecliptic
+    const I: f64 = 1.54242 * DEG_TO_RAD;
+
+    let t = julian_century(julian_day(dt));
+    let (lambda, beta) = moon_ecliptic_coords(t);
+    let omega = moon_ascending_node(t);
+    let f = moon_argument_latitude(t);
+
+    let w = (lambda - omega) * DEG_TO_RAD;
+    let beta = beta * DEG_TO_RAD;
+
+    let a = (w.sin() * beta.cos() * I.cos() - beta.sin() * I.sin()).atan2(w.cos() * beta.cos());
+    let longitude = normalize_degrees_signed(a * RAD_TO_DEG - f);
+    let latitude = (-w.sin() * beta.cos() * I.sin() - beta.sin() * I.cos()).asin() * RAD_TO_DEG;
+
+    LunarLibration {
+        longitude,
+        latitude,
+    }
+}
+
 /// Calculate Moon's distance from Earth (km)
 fn moon_distance(t: f64) -> f64 {
     let d = moon_mean_elongation(t) * DEG_TO_RAD;
@@ -210,6 +279,7 @@ fn moon_distance(t: f64) -> f64 {
 /// - `illumination`: Fraction illuminated (0.0 to 1.0)
 /// - `phase_angle`: Phase angle in degrees (0° = new, 180° = full)
 /// - `angular_diameter`: Angular size in arcminutes
+/// - `parallax_correction_deg`: Altitude shift applied for topocentric parallax
 ///
 /// # Examples
 ///
@@ -226,12 +296,40 @@ fn moon_distance(t: f64) -> f64 {
 /// println!("Moon illumination: {:.1}%", moon_pos.illumination * 100.0);
 /// ```
 pub fn lunar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> LunarPosition {
-    let jd = julian_day(dt);
-    let t = julian_century(jd);
+    lunar_position_with_delta_t(location, dt, 0.0, true)
+}
+
+/// Calculate the lunar position as seen from the Earth's centre.
+///
+/// [`lunar_position`] is topocentric: it lowers the altitude by the moon's
+/// parallax, which is close to a full degree at the horizon and vanishes at the
+/// zenith. This variant skips that correction, matching the geocentric worked
+/// examples in Meeus. The azimuth is computed from the geocentric altitude and
+/// `parallax_correction_deg` is zero.
+pub fn lunar_position_geocentric<T: TimeZone>(
+    location: &Location,
+    dt: &DateTime<T>,
+) -> LunarPosition {
+    lunar_position_with_delta_t(location, dt, 0.0, false)
+}
 
+/// Calculate the lunar position evaluating the lunar theory in Terrestrial Time.
+///
+/// [`lunar_position`] feeds Universal Time straight into the Meeus series, which
+/// places the moon about ΔT (~69 s in 2025) behind where it really is — roughly
+/// 0.01° along its orbit. This variant adds
+/// [`delta_t_seconds`](super::time_utils::delta_t_seconds) before evaluating the
+/// series while keeping sidereal time in UT.
+pub fn lunar_position_tt<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> LunarPosition {
+    let year = dt.year() as f64 + dt.ordinal0() as f64 / 365.25;
+    let delta_t = super::time_utils::delta_t_seconds(year);
+    lunar_position_with_delta_t(location, dt, delta_t, true)
+}
+
+/// Geocentric right ascension and declination of the moon, both in radians.
+fn moon_equatorial_coords(t: f64) -> (f64, f64) {
     // Get ecliptic coordinates
     let (lambda, beta) = moon_ecliptic_coords(t);
-    let distance = moon_distance(t);
 
     // Calculate obliquity
     let epsilon = 23.439291 - 0.0130042 * t; // simplified obliquity
@@ -247,29 +345,34 @@ pub fn lunar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Lun
         + beta_rad.cos() * epsilon_rad.sin() * lambda_rad.sin())
     .asin();
 
-    // Calculate nutation in longitude for equation of equinoxes
-    let omega = 125.04 - 1934.136 * t; // Longitude of ascending node
-    let omega_rad = omega * DEG_TO_RAD;
-    let l_sun = 280.47 + 36000.77 * t; // Sun's mean longitude
-    let l_sun_rad = l_sun * DEG_TO_RAD;
-
-    // Nutation in longitude (simplified)
-    let delta_psi = -17.20 * omega_rad.sin() - 1.32 * (2.0 * l_sun_rad).sin();
-    let delta_psi_deg = delta_psi / 3600.0; // Convert arcseconds to degrees
+    (alpha, delta)
+}
 
-    // Equation of equinoxes
-    let epsilon_rad = epsilon * DEG_TO_RAD;
-    let eq_equinoxes = delta_psi_deg * epsilon_rad.cos();
+/// Geocentric hour angle of the moon at `dt`, in degrees in [-180, 180).
+///
+/// Zero when the moon is on the local meridian (see [`lunar_transit`]), negative
+/// while it is still rising toward it and positive after. The sun's counterpart
+/// is [`super::sun::solar_hour_angle`].
+pub fn lunar_hour_angle<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> f64 {
+    let (alpha, _) = moon_equatorial_coords(julian_century(julian_day(dt)));
+    let lst = super::sidereal::local_sidereal_time(location, dt);
+    (lst - alpha * RAD_TO_DEG + 180.0).rem_euclid(360.0) - 180.0
+}
 
-    // Calculate Greenwich Mean Sidereal Time
-    let gmst = 280.46061837 + 360.98564736629 * (jd - 2451545.0) + 0.000387933 * t * t
-        - t * t * t / 38710000.0;
+fn lunar_position_with_delta_t<T: TimeZone>(
+    location: &Location,
+    dt: &DateTime<T>,
+    delta_t_seconds: f64,
+    topocentric: bool,
+) -> LunarPosition {
+    let jd = julian_day(dt) + delta_t_seconds / 86_400.0;
+    let t = julian_century(jd);
 
-    // Greenwich Apparent Sidereal Time (includes nutation)
-    let gast = normalize_degrees(gmst + eq_equinoxes);
+    let (alpha, delta) = moon_equatorial_coords(t);
+    let distance = moon_distance(t);
 
-    // Local sidereal time
-    let lst = normalize_degrees(gast + location.longitude.value());
+    // Local apparent sidereal time (includes nutation)
+    let lst = super::sidereal::local_sidereal_time(location, dt);
 
     // Hour angle (geocentric)
     let ha = normalize_degrees_signed(lst - alpha * RAD_TO_DEG);
@@ -290,7 +393,12 @@ pub fn lunar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Lun
     // At horizon: full horizontal parallax; at zenith: zero
     let altitude_geocentric_rad = altitude_geocentric * DEG_TO_RAD;
     let parallax_correction = horizontal_parallax * altitude_geocentric_rad.cos();
-    let altitude = altitude_geocentric - (parallax_correction * RAD_TO_DEG);
+    let parallax_correction_deg = if topocentric {
+        parallax_correction * RAD_TO_DEG
+    } else {
+        0.0
+    };
+    let altitude = altitude_geocentric - parallax_correction_deg;
 
     // Calculate azimuth using atan2 for numerical stability
     let altitude_rad = altitude * DEG_TO_RAD;
@@ -306,8 +414,9 @@ pub fn lunar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Lun
     // Calculate phase angle and illumination
     let (phase_angle, illumination) = calculate_phase_illumination(dt);
 
-    // Calculate angular diameter (in arcminutes)
-    let angular_diameter = 2.0 * (MOON_MEAN_RADIUS / distance).atan() * RAD_TO_DEG * 60.0;
+    // Calculate semi-diameter and angular diameter (in arcminutes)
+    let semi_diameter = (MOON_MEAN_RADIUS / distance).atan() * RAD_TO_DEG * 60.0;
+    let angular_diameter = 2.0 * semi_diameter;
 
     LunarPosition {
         altitude,
@@ -316,6 +425,53 @@ pub fn lunar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Lun
         illumination,
         phase_angle,
         angular_diameter,
+        parallax_correction_deg,
+        horizontal_parallax: horizontal_parallax * RAD_TO_DEG,
+        semi_diameter,
+    }
+}
+
+/// Phase of the moon at an instant, independent of the observer's location.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MoonPhaseInfo {
+    /// Phase angle in degrees (0° = new moon, 180° = full moon)
+    pub phase_angle: f64,
+    /// Fraction of the disk that is illuminated (0.0 = new, 1.0 = full)
+    pub illumination: f64,
+    /// Days elapsed since the most recent new moon
+    pub age_days: f64,
+    /// Phase name, as returned by [`phase_name`]
+    pub name: &'static str,
+    /// Phase emoji, as returned by [`phase_emoji`]
+    pub emoji: &'static str,
+}
+
+/// Calculate the moon's phase at a precise instant.
+///
+/// A lightweight alternative to [`lunar_position`] when only the phase is needed:
+/// it skips the equatorial, horizontal and parallax work that depends on location.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::moon::moon_phase_at;
+/// use chrono::{TimeZone, Utc};
+///
+/// let info = moon_phase_at(&Utc.with_ymd_and_hms(2025, 3, 14, 7, 0, 0).unwrap());
+/// println!("{} {} {:.0}%", info.emoji, info.name, info.illumination * 100.0);
+/// ```
+pub fn moon_phase_at<T: TimeZone>(dt: &DateTime<T>) -> MoonPhaseInfo {
+    let (phase_angle, illumination) = calculate_phase_illumination(dt);
+    let new_moon = previous_phase(dt, LunarPhaseType::NewMoon);
+    let age = dt.with_timezone(&chrono::Utc) - new_moon.datetime;
+
+    MoonPhaseInfo {
+        phase_angle,
+        illumination,
+        age_days: age.num_milliseconds() as f64 / 86_400_000.0,
+        name: phase_name(phase_angle),
+        emoji: phase_emoji(phase_angle),
     }
 }
 
@@ -373,24 +529,59 @@ fn calculate_phase_illumination<T: TimeZone>(dt: &DateTime<T>) -> (f64, f64) {
 /// }
 /// ```
 pub fn lunar_phases(year: i32, month: u32) -> Vec<LunarPhase> {
-    let approx_k = (year as f64 + (month as f64 - 0.5) / 12.0 - 2000.0) * 12.3685;
+    let Some(first) = chrono::NaiveDate::from_ymd_opt(year, month, 1) else {
+        return Vec::new();
+    };
+    let last = first
+        .checked_add_months(chrono::Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(first);
+    lunar_phases_in_range(first, last)
+}
+
+/// Calculate all primary lunar phases whose UTC date falls within `start..=end`.
+///
+/// Steps the Meeus lunation number `k` across the span, so ranges of any length
+/// work without stitching months together. Results are in chronological order.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use solunatus::astro::moon::lunar_phases_in_range;
+///
+/// let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+/// assert!(lunar_phases_in_range(start, end).len() >= 12);
+/// ```
+pub fn lunar_phases_in_range(start: chrono::NaiveDate, end: chrono::NaiveDate) -> Vec<LunarPhase> {
     let phase_offsets = [
         (LunarPhaseType::NewMoon, 0.0),
         (LunarPhaseType::FirstQuarter, 0.25),
         (LunarPhaseType::FullMoon, 0.5),
         (LunarPhaseType::LastQuarter, 0.75),
     ];
+    let approx_k = |date: chrono::NaiveDate| {
+        (date.year() as f64 + date.ordinal0() as f64 / 365.25 - 2000.0) * 12.3685
+    };
 
     let mut phases = Vec::new();
+    if end < start {
+        return phases;
+    }
 
-    for offset in -2..=2 {
-        let k_integer = (approx_k + offset as f64).round();
+    // One lunation of margin on each side covers the error of the k estimate
+    let first_k = approx_k(start).floor() as i64 - 1;
+    let last_k = approx_k(end).ceil() as i64 + 1;
+    for k_integer in first_k..=last_k {
         for &(phase_type, fraction) in &phase_offsets {
-            let k = k_integer + fraction;
-            let jde = lunar_phase_jde(k, phase_type);
-            let dt = jd_to_datetime(jde);
+            let k = k_integer as f64 + fraction;
+            let Some(dt) = jd_to_utc(lunar_phase_jde(k, phase_type)) else {
+                continue;
+            };
+            let date = dt.date_naive();
 
-            if dt.year() == year && dt.month() == month {
+            if date >= start && date <= end {
                 phases.push(LunarPhase {
                     phase_type,
                     datetime: dt,
@@ -404,6 +595,110 @@ pub fn lunar_phases(year: i32, month: u32) -> Vec<LunarPhase> {
     phases
 }
 
+/// Whether a calendar month (UTC) contains two full moons.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::moon::is_blue_moon_month;
+///
+/// assert!(is_blue_moon_month(2023, 8));
+/// assert!(!is_blue_moon_month(2025, 8));
+/// ```
+pub fn is_blue_moon_month(year: i32, month: u32) -> bool {
+    count_phases_in_month(year, month, LunarPhaseType::FullMoon) >= 2
+}
+
+/// Whether a calendar month (UTC) contains two new moons.
+pub fn is_black_moon_month(year: i32, month: u32) -> bool {
+    count_phases_in_month(year, month, LunarPhaseType::NewMoon) >= 2
+}
+
+fn count_phases_in_month(year: i32, month: u32, phase_type: LunarPhaseType) -> usize {
+    lunar_phases(year, month)
+        .iter()
+        .filter(|p| p.phase_type == phase_type)
+        .count()
+}
+
+/// Find the first lunar phase of the given type strictly after `after`.
+///
+/// Steps the Meeus lunation number `k` directly rather than scanning months.
+///
+/// # Panics
+///
+/// Panics if the phase falls outside chrono's representable range, i.e. for `after`
+/// within a month of [`DateTime::<Utc>::MAX_UTC`](chrono::DateTime::MAX_UTC).
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::moon::{next_phase, LunarPhaseType};
+/// use chrono::Utc;
+///
+/// let full = next_phase(&Utc::now(), LunarPhaseType::FullMoon);
+/// println!("Next full moon: {}", full.datetime);
+/// ```
+pub fn next_phase<T: TimeZone>(after: &DateTime<T>, phase_type: LunarPhaseType) -> LunarPhase {
+    let after = after.with_timezone(&chrono::Utc);
+    // Start a lunation early so the first candidate is never past the target
+    let mut k = approximate_lunation(&after).floor() - 1.0 + phase_fraction(phase_type);
+
+    loop {
+        let datetime = jd_to_utc(lunar_phase_jde(k, phase_type))
+            .expect("lunar phase outside chrono's date range");
+        if datetime > after {
+            return LunarPhase {
+                phase_type,
+                datetime,
+            };
+        }
+        k += 1.0;
+    }
+}
+
+/// Find the last lunar phase of the given type strictly before `before`.
+///
+/// # Panics
+///
+/// Panics if the phase falls outside chrono's representable range, i.e. for `before`
+/// within a month of [`DateTime::<Utc>::MIN_UTC`](chrono::DateTime::MIN_UTC).
+pub fn previous_phase<T: TimeZone>(
+    before: &DateTime<T>,
+    phase_type: LunarPhaseType,
+) -> LunarPhase {
+    let before = before.with_timezone(&chrono::Utc);
+    let mut k = approximate_lunation(&before).floor() + 1.0 + phase_fraction(phase_type);
+
+    loop {
+        let datetime = jd_to_utc(lunar_phase_jde(k, phase_type))
+            .expect("lunar phase outside chrono's date range");
+        if datetime < before {
+            return LunarPhase {
+                phase_type,
+                datetime,
+            };
+        }
+        k -= 1.0;
+    }
+}
+
+/// Approximate Meeus lunation number (k) for a moment; k = 0 is the new moon of 2000-01-06
+fn approximate_lunation(dt: &DateTime<chrono::Utc>) -> f64 {
+    let year = dt.year() as f64 + dt.ordinal0() as f64 / 365.25;
+    (year - 2000.0) * 12.3685
+}
+
+/// Fractional part of k identifying each phase type
+fn phase_fraction(phase_type: LunarPhaseType) -> f64 {
+    match phase_type {
+        LunarPhaseType::NewMoon => 0.0,
+        LunarPhaseType::FirstQuarter => 0.25,
+        LunarPhaseType::FullMoon => 0.5,
+        LunarPhaseType::LastQuarter => 0.75,
+    }
+}
+
 /// Calculate JDE for a lunar phase using Meeus algorithm
 fn lunar_phase_jde(k: f64, phase_type: LunarPhaseType) -> f64 {
     let t = k / 1236.85;
@@ -468,47 +763,7 @@ fn lunar_phase_jde(k: f64, phase_type: LunarPhaseType) -> f64 {
     jde + correction
 }
 
-/// Convert Julian Day to DateTime
-fn jd_to_datetime(jd: f64) -> DateTime<chrono::Utc> {
-    use chrono::Utc;
-
-    let jd0 = jd + 0.5;
-    let z = jd0.floor() as i64;
-    let f = jd0 - z as f64;
-
-    let a = if z < 2299161 {
-        z
-    } else {
-        let alpha = ((z as f64 - 1867216.25) / 36524.25).floor() as i64;
-        z + 1 + alpha - (alpha / 4)
-    };
-
-    let b = a + 1524;
-    let c = ((b as f64 - 122.1) / 365.25).floor() as i64;
-    let d = (365.25 * c as f64).floor() as i64;
-    let e = ((b - d) as f64 / 30.6001).floor() as i64;
-
-    let day = b - d - (30.6001 * e as f64).floor() as i64;
-    let month = if e < 14 { e - 1 } else { e - 13 };
-    let year = if month > 2 { c - 4716 } else { c - 4715 };
-
-    let day_fraction = f;
-    let hours = (day_fraction * 24.0).floor();
-    let minutes = ((day_fraction * 24.0 - hours) * 60.0).floor();
-    let seconds = (((day_fraction * 24.0 - hours) * 60.0 - minutes) * 60.0).floor();
-
-    Utc.with_ymd_and_hms(
-        year as i32,
-        month as u32,
-        day as u32,
-        hours as u32,
-        minutes as u32,
-        seconds as u32,
-    )
-    .unwrap()
-}
-
-fn resolve_local_datetime<T: TimeZone>(
+pub(crate) fn resolve_local_datetime<T: TimeZone>(
     timezone: &T,
     naive: &chrono::NaiveDateTime,
 ) -> Option<DateTime<T>> {
@@ -527,7 +782,7 @@ fn resolve_local_datetime<T: TimeZone>(
     }
 }
 
-fn refine_crossing<T: TimeZone>(
+pub(crate) fn refine_crossing<T: TimeZone>(
     location: &Location,
     mut low: DateTime<T>,
     mut high: DateTime<T>,
@@ -603,10 +858,36 @@ fn search_rise_or_set<T: TimeZone>(
     None
 }
 
+/// Topocentric altitude of the moon's centre when its upper limb touches a
+/// sea-level horizon on the local date of `date`.
+///
+/// The semi-diameter is taken from the Earth–Moon distance at local noon, so the
+/// threshold tracks the ~14.7'–16.8' swing between apogee and perigee instead of
+/// using a fixed 16'. Horizontal parallax is not subtracted here: [`lunar_position`]
+/// already reports topocentric altitude, which is the geocentric form
+/// `semi_diameter + refraction - parallax` expressed from the observer's position.
+pub(crate) fn rise_set_altitude<T: TimeZone>(date: &DateTime<T>) -> f64 {
+    rise_set_altitude_with_model(date, RefractionModel::StandardFixed)
+}
+
+/// [`rise_set_altitude`] with the horizon refraction taken from `model`.
+fn rise_set_altitude_with_model<T: TimeZone>(date: &DateTime<T>, model: RefractionModel) -> f64 {
+    let noon = date
+        .date_naive()
+        .and_hms_opt(12, 0, 0)
+        .and_then(|naive| resolve_local_datetime(&date.timezone(), &naive))
+        .unwrap_or_else(|| date.clone());
+    let distance = moon_distance(julian_century(julian_day(&noon)));
+    let semi_diameter = (MOON_MEAN_RADIUS / distance).asin() * RAD_TO_DEG;
+
+    -(semi_diameter + model.horizon_refraction_degrees())
+}
+
 /// Calculate the time of a lunar event (moonrise or moonset) for a given date.
 ///
 /// Finds when the moon rises above or sets below the horizon, accounting for
-/// atmospheric refraction and the moon's angular diameter.
+/// atmospheric refraction, the moon's angular diameter, and the horizon dip at
+/// the location's elevation.
 ///
 /// # Arguments
 ///
@@ -639,8 +920,27 @@ pub fn lunar_event_time<T: TimeZone>(
     date: &DateTime<T>,
     event: LunarEvent,
 ) -> Option<DateTime<T>> {
-    // Altitude threshold accounts for refraction (34') + lunar semi-diameter (~16')
-    let altitude_threshold = -0.834;
+    // Altitude threshold accounts for refraction (34') + the day's lunar semi-diameter,
+    // lowered further by the horizon dip at the observer's elevation
+    let altitude_threshold = rise_set_altitude(date) - location.horizon_dip_degrees();
+
+    match event {
+        LunarEvent::Moonrise => search_rise_or_set(location, date, altitude_threshold, true),
+        LunarEvent::Moonset => search_rise_or_set(location, date, altitude_threshold, false),
+    }
+}
+
+/// Calculate moonrise or moonset using the given horizon refraction model.
+///
+/// [`RefractionModel::StandardFixed`] reproduces [`lunar_event_time`] exactly.
+pub fn lunar_event_time_with_model<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: LunarEvent,
+    model: RefractionModel,
+) -> Option<DateTime<T>> {
+    let altitude_threshold =
+        rise_set_altitude_with_model(date, model) - location.horizon_dip_degrees();
 
     match event {
         LunarEvent::Moonrise => search_rise_or_set(location, date, altitude_threshold, true),
@@ -648,6 +948,361 @@ pub fn lunar_event_time<T: TimeZone>(
     }
 }
 
+/// Check whether the moon is above the horizon at `dt`.
+///
+/// Uses the same threshold as [`lunar_event_time`] (semi-diameter, refraction and
+/// horizon dip), so the result flips at the computed moonrise and moonset times.
+/// The sun's counterpart is [`super::sun::is_sun_up`].
+pub fn is_moon_up<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> bool {
+    let threshold = rise_set_altitude(dt) - location.horizon_dip_degrees();
+    lunar_position(location, dt).altitude >= threshold
+}
+
+/// Find the moon's upper transit (culmination) on the local date of `date`.
+///
+/// Returns the time the moon crosses the meridian, found where its hour angle
+/// passes through zero, together with its topocentric altitude in degrees at that
+/// moment, the highest it reaches that day. Returns `None` on the roughly one day
+/// per lunation when the transit falls just outside the local day.
+pub fn lunar_transit<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+) -> Option<(DateTime<T>, f64)> {
+    let tz = date.timezone();
+    let start_naive = date.date_naive().and_hms_opt(0, 0, 0)?;
+    let start = resolve_local_datetime(&tz, &start_naive)?;
+    let end = start.clone() + Duration::hours(24);
+
+    // The hour angle grows ~14.5°/h, so a 10-minute step cannot skip the crossing;
+    // the +180° to -180° wrap at lower transit is excluded by the sign test
+    let step = Duration::minutes(10);
+    let mut prev_dt = start;
+    let mut prev_ha = lunar_hour_angle(location, &prev_dt);
+
+    while prev_dt < end {
+        let current = prev_dt.clone() + step;
+        let ha = lunar_hour_angle(location, &current);
+
+        if prev_ha < 0.0 && ha >= 0.0 && ha - prev_ha < 90.0 {
+            let mut low = prev_dt;
+            let mut high = current;
+            while (high.clone() - low.clone()).num_milliseconds() > 500 {
+                let mid = low.clone() + (high.clone() - low.clone()) / 2;
+                if lunar_hour_angle(location, &mid) >= 0.0 {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            if high >= end {
+                return None;
+            }
+            let altitude = lunar_position(location, &high).altitude;
+            return Some((high, altitude));
+        }
+
+        prev_dt = current;
+        prev_ha = ha;
+    }
+
+    None
+}
+
+/// Calculate the moon's azimuth (degrees from North) at moonrise or moonset.
+///
+/// Returns `None` when the event doesn't occur on this date.
+pub fn lunar_event_azimuth<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: LunarEvent,
+) -> Option<f64> {
+    let time = lunar_event_time(location, date, event)?;
+    Some(lunar_position(location, &time).azimuth)
+}
+
+/// Calculate moonrise or moonset against an obstructed horizon at `horizon_deg` altitude.
+///
+/// The horizon replaces the sea-level horizon (and any elevation dip); the standard
+/// refraction and semi-diameter allowance is kept on top of it. A positive horizon
+/// delays moonrise and advances moonset.
+pub fn lunar_event_time_at_altitude<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: LunarEvent,
+    horizon_deg: f64,
+) -> Option<DateTime<T>> {
+    let altitude_threshold = rise_set_altitude(date) + horizon_deg;
+
+    match event {
+        LunarEvent::Moonrise => search_rise_or_set(location, date, altitude_threshold, true),
+        LunarEvent::Moonset => search_rise_or_set(location, date, altitude_threshold, false),
+    }
+}
+
+/// Kind of lunar apsis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LunarApsisKind {
+    /// Closest approach to Earth
+    Perigee,
+    /// Farthest point from Earth
+    Apogee,
+}
+
+/// A lunar perigee or apogee.
+#[derive(Debug, Clone, Copy)]
+pub struct LunarApsis {
+    pub kind: LunarApsisKind,
+    pub datetime: DateTime<chrono::Utc>,
+    /// Earth-Moon center distance in kilometers
+    pub distance_km: f64,
+}
+
+/// One periodic term: (coefficient, T coefficient, D, M, F multipliers)
+type ApsisTerm = (f64, f64, f64, f64, f64);
+
+/// Perigee time correction terms in days (Meeus Table 50.A)
+const PERIGEE_TIME_TERMS: [ApsisTerm; 60] = [
+    (-1.6769, 0.0, 2.0, 0.0, 0.0),
+    (0.4589, 0.0, 4.0, 0.0, 0.0),
+    (-0.1856, 0.0, 6.0, 0.0, 0.0),
+    (0.0883, 0.0, 8.0, 0.0, 0.0),
+    (-0.0773, 0.00019, 2.0, -1.0, 0.0),
+    (0.0502, -0.00013, 0.0, 1.0, 0.0),
+    (-0.0460, 0.0, 10.0, 0.0, 0.0),
+    (0.0422, -0.00011, 4.0, -1.0, 0.0),
+    (-0.0256, 0.0, 6.0, -1.0, 0.0),
+    (0.0253, 0.0, 12.0, 0.0, 0.0),
+    (0.0237, 0.0, 1.0, 0.0, 0.0),
+    (0.0162, 0.0, 8.0, -1.0, 0.0),
+    (-0.0145, 0.0, 14.0, 0.0, 0.0),
+    (0.0129, 0.0, 0.0, 0.0, 2.0),
+    (-0.0112, 0.0, 3.0, 0.0, 0.0),
+    (-0.0104, 0.0, 10.0, -1.0, 0.0),
+    (0.0086, 0.0, 16.0, 0.0, 0.0),
+    (0.0069, 0.0, 12.0, -1.0, 0.0),
+    (0.0066, 0.0, 5.0, 0.0, 0.0),
+    (-0.0053, 0.0, 2.0, 0.0, 2.0),
+    (-0.0052, 0.0, 18.0, 0.0, 0.0),
+    (-0.0046, 0.0, 14.0, -1.0, 0.0),
+    (-0.0041, 0.0, 7.0, 0.0, 0.0),
+    (0.0040, 0.0, 2.0, 1.0, 0.0),
+    (0.0032, 0.0, 20.0, 0.0, 0.0),
+    (-0.0032, 0.0, 1.0, 1.0, 0.0),
+    (0.0031, 0.0, 16.0, -1.0, 0.0),
+    (-0.0029, 0.0, 4.0, 1.0, 0.0),
+    (0.0027, 0.0, 9.0, 0.0, 0.0),
+    (0.0027, 0.0, 4.0, 0.0, 2.0),
+    (-0.0027, 0.0, 2.0, -2.0, 0.0),
+    (0.0024, 0.0, 4.0, -2.0, 0.0),
+    (-0.0021, 0.0, 6.0, -2.0, 0.0),
+    (-0.0021, 0.0, 22.0, 0.0, 0.0),
+    (-0.0021, 0.0, 18.0, -1.0, 0.0),
+    (0.0019, 0.0, 6.0, 1.0, 0.0),
+    (-0.0018, 0.0, 11.0, 0.0, 0.0),
+    (-0.0014, 0.0, 8.0, 1.0, 0.0),
+    (-0.0014, 0.0, 4.0, 0.0, -2.0),
+    (-0.0014, 0.0, 6.0, 0.0, 2.0),
+    (0.0014, 0.0, 3.0, 1.0, 0.0),
+    (-0.0014, 0.0, 5.0, 1.0, 0.0),
+    (0.0013, 0.0, 13.0, 0.0, 0.0),
+    (0.0013, 0.0, 20.0, -1.0, 0.0),
+    (0.0011, 0.0, 3.0, 2.0, 0.0),
+    (-0.0011, 0.0, 4.0, -2.0, 2.0),
+    (-0.0010, 0.0, 1.0, 2.0, 0.0),
+    (-0.0009, 0.0, 22.0, -1.0, 0.0),
+    (-0.0008, 0.0, 0.0, 0.0, 4.0),
+    (0.0008, 0.0, 6.0, 0.0, -2.0),
+    (0.0008, 0.0, 2.0, 1.0, -2.0),
+    (0.0007, 0.0, 0.0, 2.0, 0.0),
+    (0.0007, 0.0, 0.0, -1.0, 2.0),
+    (0.0007, 0.0, 2.0, 0.0, 4.0),
+    (-0.0006, 0.0, 0.0, -2.0, 2.0),
+    (-0.0006, 0.0, 2.0, 2.0, -2.0),
+    (0.0006, 0.0, 24.0, 0.0, 0.0),
+    (0.0005, 0.0, 4.0, 0.0, -4.0),
+    (0.0005, 0.0, 2.0, 2.0, 0.0),
+    (-0.0004, 0.0, 1.0, -1.0, 0.0),
+];
+
+/// Apogee time correction terms in days (Meeus Table 50.A)
+const APOGEE_TIME_TERMS: [ApsisTerm; 32] = [
+    (0.4392, 0.0, 2.0, 0.0, 0.0),
+    (0.0684, 0.0, 4.0, 0.0, 0.0),
+    (0.0456, -0.00011, 0.0, 1.0, 0.0),
+    (0.0426, -0.00011, 2.0, -1.0, 0.0),
+    (0.0212, 0.0, 0.0, 0.0, 2.0),
+    (-0.0189, 0.0, 1.0, 0.0, 0.0),
+    (0.0144, 0.0, 6.0, 0.0, 0.0),
+    (0.0113, 0.0, 4.0, -1.0, 0.0),
+    (0.0047, 0.0, 2.0, 0.0, 2.0),
+    (0.0036, 0.0, 1.0, 1.0, 0.0),
+    (0.0035, 0.0, 8.0, 0.0, 0.0),
+    (0.0034, 0.0, 6.0, -1.0, 0.0),
+    (-0.0034, 0.0, 2.0, 0.0, -2.0),
+    (0.0022, 0.0, 2.0, -2.0, 0.0),
+    (-0.0017, 0.0, 3.0, 0.0, 0.0),
+    (0.0013, 0.0, 4.0, 0.0, 2.0),
+    (0.0011, 0.0, 8.0, -1.0, 0.0),
+    (0.0010, 0.0, 4.0, -2.0, 0.0),
+    (0.0009, 0.0, 10.0, 0.0, 0.0),
+    (0.0007, 0.0, 3.0, 1.0, 0.0),
+    (0.0006, 0.0, 0.0, 2.0, 0.0),
+    (0.0005, 0.0, 2.0, 1.0, 0.0),
+    (0.0005, 0.0, 2.0, 2.0, 0.0),
+    (0.0004, 0.0, 6.0, 0.0, 2.0),
+    (0.0004, 0.0, 6.0, -2.0, 0.0),
+    (0.0004, 0.0, 10.0, -1.0, 0.0),
+    (-0.0004, 0.0, 5.0, 0.0, 0.0),
+    (-0.0004, 0.0, 4.0, 0.0, -2.0),
+    (0.0003, 0.0, 0.0, 1.0, 2.0),
+    (0.0003, 0.0, 12.0, 0.0, 0.0),
+    (0.0003, 0.0, 2.0, -1.0, 2.0),
+    (-0.0003, 0.0, 1.0, -1.0, 0.0),
+];
+
+/// Perigee parallax terms in arcseconds (Meeus Table 50.B)
+const PERIGEE_PARALLAX_TERMS: [ApsisTerm; 46] = [
+    (63.224, 0.0, 2.0, 0.0, 0.0),
+    (-6.990, 0.0, 4.0, 0.0, 0.0),
+    (2.834, -0.0071, 2.0, -1.0, 0.0),
+    (1.927, 0.0, 6.0, 0.0, 0.0),
+    (-1.263, 0.0, 1.0, 0.0, 0.0),
+    (-0.702, 0.0, 8.0, 0.0, 0.0),
+    (0.696, -0.0017, 0.0, 1.0, 0.0),
+    (-0.690, 0.0, 0.0, 0.0, 2.0),
+    (-0.629, 0.0016, 4.0, -1.0, 0.0),
+    (-0.392, 0.0, 2.0, 0.0, -2.0),
+    (0.297, 0.0, 10.0, 0.0, 0.0),
+    (0.260, 0.0, 6.0, -1.0, 0.0),
+    (0.201, 0.0, 3.0, 0.0, 0.0),
+    (-0.161, 0.0, 2.0, 1.0, 0.0),
+    (0.157, 0.0, 1.0, 1.0, 0.0),
+    (-0.138, 0.0, 12.0, 0.0, 0.0),
+    (-0.127, 0.0, 8.0, -1.0, 0.0),
+    (0.104, 0.0, 2.0, 0.0, 2.0),
+    (0.104, 0.0, 2.0, -2.0, 0.0),
+    (-0.079, 0.0, 5.0, 0.0, 0.0),
+    (0.068, 0.0, 14.0, 0.0, 0.0),
+    (0.067, 0.0, 10.0, -1.0, 0.0),
+    (0.054, 0.0, 4.0, 1.0, 0.0),
+    (-0.038, 0.0, 12.0, -1.0, 0.0),
+    (-0.038, 0.0, 4.0, -2.0, 0.0),
+    (0.037, 0.0, 7.0, 0.0, 0.0),
+    (-0.037, 0.0, 4.0, 0.0, 2.0),
+    (-0.035, 0.0, 16.0, 0.0, 0.0),
+    (-0.030, 0.0, 3.0, 1.0, 0.0),
+    (0.029, 0.0, 1.0, -1.0, 0.0),
+    (-0.025, 0.0, 6.0, 1.0, 0.0),
+    (0.023, 0.0, 0.0, 2.0, 0.0),
+    (0.023, 0.0, 14.0, -1.0, 0.0),
+    (-0.023, 0.0, 2.0, 2.0, 0.0),
+    (0.022, 0.0, 6.0, -2.0, 0.0),
+    (-0.021, 0.0, 2.0, -1.0, -2.0),
+    (-0.020, 0.0, 9.0, 0.0, 0.0),
+    (0.019, 0.0, 18.0, 0.0, 0.0),
+    (0.017, 0.0, 6.0, 0.0, 2.0),
+    (0.014, 0.0, 0.0, -1.0, 2.0),
+    (-0.014, 0.0, 16.0, -1.0, 0.0),
+    (0.013, 0.0, 4.0, 0.0, -2.0),
+    (0.012, 0.0, 8.0, 1.0, 0.0),
+    (0.011, 0.0, 11.0, 0.0, 0.0),
+    (0.010, 0.0, 5.0, 1.0, 0.0),
+    (-0.010, 0.0, 20.0, 0.0, 0.0),
+];
+
+/// Apogee parallax terms in arcseconds (Meeus Table 50.B)
+const APOGEE_PARALLAX_TERMS: [ApsisTerm; 17] = [
+    (-9.147, 0.0, 2.0, 0.0, 0.0),
+    (-0.841, 0.0, 1.0, 0.0, 0.0),
+    (0.697, 0.0, 0.0, 0.0, 2.0),
+    (-0.656, 0.0016, 0.0, 1.0, 0.0),
+    (0.355, 0.0, 4.0, 0.0, 0.0),
+    (0.159, 0.0, 2.0, -1.0, 0.0),
+    (0.127, 0.0, 1.0, 1.0, 0.0),
+    (0.065, 0.0, 4.0, -1.0, 0.0),
+    (0.052, 0.0, 6.0, 0.0, 0.0),
+    (0.043, 0.0, 2.0, 1.0, 0.0),
+    (0.031, 0.0, 2.0, 0.0, 2.0),
+    (-0.023, 0.0, 2.0, 0.0, -2.0),
+    (0.022, 0.0, 2.0, -2.0, 0.0),
+    (0.019, 0.0, 2.0, 2.0, 0.0),
+    (-0.016, 0.0, 0.0, 2.0, 0.0),
+    (0.014, 0.0, 6.0, -1.0, 0.0),
+    (0.010, 0.0, 8.0, 0.0, 0.0),
+];
+
+/// Find the lunar perigees and apogees occurring in a month (UTC).
+///
+/// Uses the apsis series of Meeus Chapter 50; times are accurate to a few minutes
+/// and distances to a few kilometers.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::moon::{lunar_apsides, LunarApsisKind};
+///
+/// for apsis in lunar_apsides(2025, 11) {
+///     if apsis.kind == LunarApsisKind::Perigee {
+///         println!("Perigee {} at {:.0} km", apsis.datetime, apsis.distance_km);
+///     }
+/// }
+/// ```
+pub fn lunar_apsides(year: i32, month: u32) -> Vec<LunarApsis> {
+    let approx_k = (year as f64 + (month as f64 - 0.5) / 12.0 - 1999.97) * 13.2555;
+    let mut apsides = Vec::new();
+
+    for offset in -2..=2 {
+        let k_integer = approx_k.floor() + offset as f64;
+        for (kind, fraction) in [(LunarApsisKind::Perigee, 0.0), (LunarApsisKind::Apogee, 0.5)] {
+            let apsis = lunar_apsis(k_integer + fraction, kind);
+            if apsis.datetime.year() == year && apsis.datetime.month() == month {
+                apsides.push(apsis);
+            }
+        }
+    }
+
+    apsides.sort_by_key(|a| a.datetime);
+    apsides
+}
+
+/// Evaluate the apsis for lunation-like index `k` (integer = perigee, +0.5 = apogee)
+fn lunar_apsis(k: f64, kind: LunarApsisKind) -> LunarApsis {
+    let t = k / 1325.55;
+
+    let mean_jde = 2451534.6698 + 27.55454989 * k - 0.0006691 * t * t - 0.000001098 * t.powi(3)
+        + 0.0000000052 * t.powi(4);
+    let d = (171.9179 + 335.9106046 * k - 0.0100383 * t * t - 0.00001156 * t.powi(3)
+        + 0.000000055 * t.powi(4))
+        * DEG_TO_RAD;
+    let m = (347.3477 + 27.1577721 * k - 0.0008130 * t * t - 0.0000010 * t.powi(3)) * DEG_TO_RAD;
+    let f = (316.6109 + 364.5287911 * k - 0.0125053 * t * t - 0.0000148 * t.powi(3)) * DEG_TO_RAD;
+
+    let series = |terms: &[ApsisTerm], trig: fn(f64) -> f64| -> f64 {
+        terms
+            .iter()
+            .map(|&(c, ct, dm, mm, fm)| (c + ct * t) * trig(dm * d + mm * m + fm * f))
+            .sum()
+    };
+
+    let (jde, parallax_arcsec) = match kind {
+        LunarApsisKind::Perigee => (
+            mean_jde + series(&PERIGEE_TIME_TERMS, f64::sin),
+            3629.215 + series(&PERIGEE_PARALLAX_TERMS, f64::cos),
+        ),
+        LunarApsisKind::Apogee => (
+            mean_jde + series(&APOGEE_TIME_TERMS, f64::sin),
+            3245.251 + series(&APOGEE_PARALLAX_TERMS, f64::cos),
+        ),
+    };
+    let distance_km = 6378.14 / (parallax_arcsec / 3600.0 * DEG_TO_RAD).sin();
+
+    LunarApsis {
+        kind,
+        datetime: super::time_utils::jde_to_utc(jde),
+        distance_km,
+    }
+}
+
 /// Get the descriptive name of a lunar phase from its phase angle.
 ///
 /// Converts a numeric phase angle to a human-readable phase name.
@@ -735,12 +1390,153 @@ pub fn phase_emoji(phase_angle: f64) -> &'static str {
     }
 }
 
+/// Traditional North American names for each month's full moon, January first.
+const FULL_MOON_NAMES: [&str; 12] = [
+    "Wolf Moon",
+    "Snow Moon",
+    "Worm Moon",
+    "Pink Moon",
+    "Flower Moon",
+    "Strawberry Moon",
+    "Buck Moon",
+    "Sturgeon Moon",
+    "Harvest Moon",
+    "Hunter's Moon",
+    "Beaver Moon",
+    "Cold Moon",
+];
+
+/// Get the traditional name of the full moon at (or near) `dt`.
+///
+/// Names the full moon nearest to `dt` by its calendar month in UTC. When that
+/// full moon is the second in its calendar month it is a "Blue Moon".
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use solunatus::astro::moon::full_moon_name;
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 1, 13, 22, 27, 0).unwrap();
+/// assert_eq!(full_moon_name(&dt), "Wolf Moon");
+/// ```
+pub fn full_moon_name(dt: &DateTime<chrono::Utc>) -> &'static str {
+    // A synodic month is ~29.5 days, so some full moon lies within 16 days of dt
+    let date = dt.date_naive();
+    let nearest = lunar_phases_in_range(date - Duration::days(16), date + Duration::days(16))
+        .into_iter()
+        .filter(|p| p.phase_type == LunarPhaseType::FullMoon)
+        .min_by_key(|p| (p.datetime - *dt).num_seconds().abs());
+    let full_moon = nearest.map_or(date, |p| p.datetime.date_naive());
+
+    let earlier_full_moon = lunar_phases(full_moon.year(), full_moon.month())
+        .iter()
+        .any(|p| p.phase_type == LunarPhaseType::FullMoon && p.datetime.date_naive() < full_moon);
+
+    if earlier_full_moon {
+        "Blue Moon"
+    } else {
+        FULL_MOON_NAMES[full_moon.month0() as usize]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
     use std::collections::HashSet;
 
+    #[test]
+    fn full_moon_names_follow_calendar_month() {
+        let january = Utc.with_ymd_and_hms(2025, 1, 13, 22, 27, 0).unwrap();
+        assert_eq!(full_moon_name(&january), "Wolf Moon");
+
+        // August 2023 had full moons on the 1st and the 31st
+        let first = Utc.with_ymd_and_hms(2023, 8, 1, 18, 32, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2023, 8, 31, 1, 36, 0).unwrap();
+        assert_eq!(full_moon_name(&first), "Sturgeon Moon");
+        assert_eq!(full_moon_name(&second), "Blue Moon");
+
+        // Mid-month dates take the name of the nearest full moon: January 2025's only
+        // full moon was on the 13th, and 2023-08-20 is nearer the Blue Moon than the 1st
+        let after_january_full = Utc.with_ymd_and_hms(2025, 1, 20, 12, 0, 0).unwrap();
+        assert_eq!(full_moon_name(&after_january_full), "Wolf Moon");
+        let late_august = Utc.with_ymd_and_hms(2023, 8, 20, 12, 0, 0).unwrap();
+        assert_eq!(full_moon_name(&late_august), "Blue Moon");
+    }
+
+    #[test]
+    fn detects_double_full_and_new_moon_months() {
+        assert!(is_blue_moon_month(2023, 8));
+        assert!(!is_blue_moon_month(2023, 9));
+        assert!(!is_black_moon_month(2025, 8));
+        // New moons on 2024-12-01 and 2024-12-30
+        assert!(is_black_moon_month(2024, 12));
+    }
+
+    #[test]
+    fn full_moon_is_opposite_the_sun_in_longitude() {
+        let dt = Utc.with_ymd_and_hms(2025, 3, 14, 6, 55, 0).unwrap();
+        let sun_longitude = crate::astro::sun::sun_ecliptic_longitude(&dt);
+        let elongation = moon_ecliptic_longitude(&dt) - sun_longitude;
+        assert!((elongation.rem_euclid(360.0) - 180.0).abs() < 0.5, "elongation {elongation}");
+    }
+
+    #[test]
+    fn geocentric_altitude_differs_by_parallax() {
+        let start = Utc.with_ymd_and_hms(2025, 11, 12, 0, 0, 0).unwrap();
+        // Observer at the latitude of the moon's declination so it passes near the zenith
+        let t = julian_century(julian_day(&(start + Duration::hours(12))));
+        let declination = moon_equatorial_coords(t).1 * RAD_TO_DEG;
+        let location = Location::new(declination, 0.0).unwrap();
+
+        let (mut max_horizon_gap, mut min_zenith_gap) = (0.0_f64, f64::MAX);
+        for dt in (0..288).map(|i| start + Duration::minutes(5 * i)) {
+            let topo = lunar_position(&location, &dt);
+            let geo = lunar_position_geocentric(&location, &dt);
+            assert_eq!(geo.parallax_correction_deg, 0.0);
+            let gap = geo.altitude - topo.altitude;
+            assert!((0.0..1.05).contains(&gap), "gap {gap}");
+            if geo.altitude.abs() < 2.0 {
+                max_horizon_gap = max_horizon_gap.max(gap);
+            }
+            if geo.altitude > 80.0 {
+                min_zenith_gap = min_zenith_gap.min(gap);
+            }
+        }
+        assert!(max_horizon_gap > 0.9, "horizon gap {max_horizon_gap}");
+        assert!(min_zenith_gap < 0.15, "zenith gap {min_zenith_gap}");
+    }
+
+    #[test]
+    fn exposes_horizontal_parallax_and_semi_diameter() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let dt = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let position = lunar_position(&location, &dt);
+
+        assert!((position.semi_diameter - position.angular_diameter / 2.0).abs() < 1e-9);
+        assert!((14.5..17.0).contains(&position.semi_diameter));
+        assert!((0.89..1.03).contains(&position.horizontal_parallax));
+        assert!(position.parallax_correction_deg <= position.horizontal_parallax);
+    }
+
+    #[test]
+    fn moon_phase_at_full_moon() {
+        // Full moon of 2025-03-14 06:55 UTC (total lunar eclipse)
+        let dt = Utc.with_ymd_and_hms(2025, 3, 14, 6, 55, 0).unwrap();
+        let info = moon_phase_at(&dt);
+
+        assert!(info.illumination > 0.99, "illumination {}", info.illumination);
+        assert_eq!(info.name, "Full Moon");
+        assert_eq!(info.emoji, "🌕");
+        // New moon was 2025-02-28 00:45 UTC
+        assert!((info.age_days - 14.26).abs() < 0.01, "age {}", info.age_days);
+
+        let position = lunar_position(&Location::new(0.0, 0.0).unwrap(), &dt);
+        assert_eq!(info.phase_angle, position.phase_angle);
+        assert_eq!(info.illumination, position.illumination);
+    }
+
     #[test]
     fn oct_2025_full_moon_matches_usno() {
         let phases = lunar_phases(2025, 10);
@@ -785,4 +1581,258 @@ mod tests {
             "expected one instance of each primary phase for October 2025"
         );
     }
+
+    #[test]
+    fn parallax_correction_shrinks_toward_zenith() {
+        let location = Location::new(0.0, 0.0).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap();
+
+        let samples: Vec<LunarPosition> = (0..288)
+            .map(|i| lunar_position(&location, &(start + Duration::minutes(10 * i))))
+            .collect();
+
+        let near_horizon = samples
+            .iter()
+            .min_by(|a, b| a.altitude.abs().partial_cmp(&b.altitude.abs()).unwrap())
+            .unwrap();
+        let highest = samples
+            .iter()
+            .max_by(|a, b| a.altitude.partial_cmp(&b.altitude).unwrap())
+            .unwrap();
+
+        assert!(highest.altitude > 45.0, "moon never climbed high: {}", highest.altitude);
+        assert!(
+            near_horizon.parallax_correction_deg > highest.parallax_correction_deg,
+            "horizon correction {} should exceed high-altitude correction {}",
+            near_horizon.parallax_correction_deg,
+            highest.parallax_correction_deg
+        );
+        assert!(near_horizon.parallax_correction_deg > 0.85);
+        assert!(near_horizon.parallax_correction_deg < 1.05);
+    }
+
+    #[test]
+    fn elevation_lowers_moonrise_threshold() {
+        let sea_level = Location::new(39.7392, -104.9903).unwrap();
+        let summit = Location::with_elevation(39.7392, -104.9903, 3000.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 10, 10, 12, 0, 0).unwrap();
+
+        let rise_0 = lunar_event_time(&sea_level, &date, LunarEvent::Moonrise).unwrap();
+        let rise_3000 = lunar_event_time(&summit, &date, LunarEvent::Moonrise).unwrap();
+
+        assert!(rise_3000 < rise_0);
+        assert!((rise_0 - rise_3000).num_minutes() <= 15);
+    }
+
+    #[test]
+    fn raised_horizon_delays_moonrise() {
+        let location = Location::new(46.5197, 6.6323).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 10, 10, 12, 0, 0).unwrap();
+
+        let flat = lunar_event_time_at_altitude(&location, &date, LunarEvent::Moonrise, 0.0);
+        assert_eq!(flat, lunar_event_time(&location, &date, LunarEvent::Moonrise));
+
+        let ridge = lunar_event_time_at_altitude(&location, &date, LunarEvent::Moonrise, 10.0);
+        assert!(ridge.unwrap() > flat.unwrap());
+    }
+
+    #[test]
+    fn moonrise_azimuth_is_eastern() {
+        let location = Location::new(34.0522, -118.2437).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 10, 1, 12, 0, 0).unwrap();
+
+        let mut checked = 0;
+        for day in 0..10 {
+            let date = start + Duration::days(day);
+            if let Some(az) = lunar_event_azimuth(&location, &date, LunarEvent::Moonrise) {
+                assert!((0.0..=180.0).contains(&az), "moonrise az {}", az);
+                checked += 1;
+            }
+            if let Some(az) = lunar_event_azimuth(&location, &date, LunarEvent::Moonset) {
+                assert!((180.0..=360.0).contains(&az), "moonset az {}", az);
+            }
+        }
+        assert!(checked >= 8);
+    }
+
+    #[test]
+    fn next_and_previous_full_moon() {
+        let oct_1 = Utc.with_ymd_and_hms(2025, 10, 1, 0, 0, 0).unwrap();
+        let next = next_phase(&oct_1, LunarPhaseType::FullMoon);
+        assert_eq!(next.phase_type, LunarPhaseType::FullMoon);
+        let expected = chrono::NaiveDate::from_ymd_opt(2025, 10, 7).unwrap();
+        assert_eq!(next.datetime.date_naive(), expected);
+
+        let previous = previous_phase(&oct_1, LunarPhaseType::FullMoon);
+        let expected = chrono::NaiveDate::from_ymd_opt(2025, 9, 7).unwrap();
+        assert_eq!(previous.datetime.date_naive(), expected);
+
+        // Searching from the exact phase instant moves strictly forward/backward
+        let after_next = next_phase(&next.datetime, LunarPhaseType::FullMoon);
+        assert!(after_next.datetime > next.datetime + Duration::days(28));
+        let back = previous_phase(&next.datetime, LunarPhaseType::FullMoon);
+        assert_eq!(back.datetime, previous.datetime);
+    }
+
+    #[test]
+    fn libration_matches_meeus_example() {
+        // Meeus Example 53.a: 1992 April 12, 0h TD -> l' = -1.206°, b' = +4.194°
+        let dt = Utc.with_ymd_and_hms(1992, 4, 12, 0, 0, 0).unwrap();
+        let libration = lunar_libration(&dt);
+        assert!((libration.longitude + 1.206).abs() < 0.5, "l = {}", libration.longitude);
+        assert!((libration.latitude - 4.194).abs() < 0.5, "b = {}", libration.latitude);
+
+        // Stays within the optical libration envelope over a month
+        for day in 0..30 {
+            let l = lunar_libration(&(dt + Duration::days(day)));
+            assert!(l.longitude.abs() < 8.5 && l.latitude.abs() < 7.5);
+        }
+    }
+
+    #[test]
+    fn november_2025_apsides() {
+        let apsides = lunar_apsides(2025, 11);
+        let perigee = apsides
+            .iter()
+            .find(|a| a.kind == LunarApsisKind::Perigee)
+            .expect("perigee in November 2025");
+        let apogee = apsides
+            .iter()
+            .find(|a| a.kind == LunarApsisKind::Apogee)
+            .expect("apogee in November 2025");
+
+        // Published: perigee Nov 5 22:27 UTC at 356,833 km; apogee Nov 20 02:48 UTC at 406,691 km
+        assert!(perigee.distance_km < 370_000.0, "perigee {}", perigee.distance_km);
+        assert!(apogee.distance_km > 404_000.0, "apogee {}", apogee.distance_km);
+        assert!((perigee.distance_km - 356_833.0).abs() < 50.0);
+        let expected = Utc.with_ymd_and_hms(2025, 11, 5, 22, 27, 0).unwrap();
+        assert!((perigee.datetime - expected).num_minutes().abs() <= 30, "{}", perigee.datetime);
+    }
+
+    #[test]
+    fn tt_position_leads_ut_position_slightly() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let dt = Utc.with_ymd_and_hms(2025, 10, 7, 12, 0, 0).unwrap();
+
+        let ut = lunar_position(&location, &dt);
+        let tt = lunar_position_tt(&location, &dt);
+        let delta_t = crate::astro::time_utils::delta_t_seconds(2025.0 + 279.0 / 365.25);
+        let later = lunar_position(&location, &(dt + Duration::seconds(delta_t.round() as i64)));
+
+        // The TT series equals evaluating the UT series ΔT later, minus Earth rotation
+        assert!((65.0..75.0).contains(&delta_t));
+        assert!((tt.distance - later.distance).abs() < 0.1);
+        assert!((tt.altitude - ut.altitude).abs() < 0.05);
+        assert!(tt.altitude != ut.altitude);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn lunar_position_serde_round_trip() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let dt = Utc.with_ymd_and_hms(2025, 10, 7, 3, 47, 0).unwrap();
+        let position = lunar_position(&location, &dt);
+
+        let json = serde_json::to_string(&position).unwrap();
+        assert!(json.contains("\"parallax_correction_deg\""));
+        let restored: LunarPosition = serde_json::from_str(&json).unwrap();
+        // serde_json's default float parser may differ from the original by an ulp
+        assert!((restored.altitude - position.altitude).abs() < 1e-9);
+        assert!((restored.distance - position.distance).abs() < 1e-9);
+        assert!((restored.phase_angle - position.phase_angle).abs() < 1e-9);
+
+        let phase = next_phase(&dt, LunarPhaseType::FullMoon);
+        let json = serde_json::to_string(&phase).unwrap();
+        assert!(json.contains("\"full_moon\""));
+        let restored: LunarPhase = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.datetime, phase.datetime);
+    }
+
+    #[test]
+    fn phases_in_range_cover_ninety_days_in_order() {
+        let start = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = start + Duration::days(89);
+        let phases = lunar_phases_in_range(start, end);
+
+        // 90 days spans just over three lunations: 12 or 13 primary phases
+        assert!((12..=13).contains(&phases.len()), "{} phases", phases.len());
+        assert!(phases.windows(2).all(|pair| pair[0].datetime < pair[1].datetime));
+        let dates: Vec<_> = phases.iter().map(|phase| phase.datetime.date_naive()).collect();
+        assert!(dates.iter().all(|date| (start..=end).contains(date)));
+
+        // Month queries are a special case of the range
+        let march = lunar_phases(2025, 3);
+        let march_range = lunar_phases_in_range(
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+        );
+        let key = |phase: &LunarPhase| (phase.phase_type, phase.datetime);
+        assert_eq!(
+            march.iter().map(key).collect::<Vec<_>>(),
+            march_range.iter().map(key).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn transit_altitude_is_daily_maximum() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        // Transits fall at 23:12 on the 4th and 00:12 on the 6th, skipping the 5th
+        let skipped = tz.with_ymd_and_hms(2025, 11, 5, 0, 0, 0).unwrap();
+        assert!(lunar_transit(&location, &skipped).is_none());
+
+        let date = tz.with_ymd_and_hms(2025, 11, 12, 0, 0, 0).unwrap();
+
+        let (transit, altitude) = lunar_transit(&location, &date).unwrap();
+        assert_eq!(transit.date_naive(), date.date_naive());
+        assert!((lunar_position(&location, &transit).azimuth - 180.0).abs() < 1.0);
+        assert!(lunar_hour_angle(&location, &transit).abs() < 0.01);
+
+        let highest = (0..24 * 12)
+            .map(|i| lunar_position(&location, &(date + Duration::minutes(5 * i))).altitude)
+            .fold(f64::MIN, f64::max);
+        assert!(altitude >= highest - 0.05, "transit {} vs sampled max {}", altitude, highest);
+        assert!(altitude <= highest + 0.1);
+    }
+
+    #[test]
+    fn moon_up_flips_at_rise_and_set() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 11, 5, 0, 0, 0).unwrap();
+        let rise = lunar_event_time(&location, &date, LunarEvent::Moonrise).unwrap();
+        let set = lunar_event_time(&location, &date, LunarEvent::Moonset).unwrap();
+
+        assert!(!is_moon_up(&location, &(rise - Duration::minutes(5))));
+        assert!(is_moon_up(&location, &(rise + Duration::minutes(5))));
+        assert!(is_moon_up(&location, &(set - Duration::minutes(5))));
+        assert!(!is_moon_up(&location, &(set + Duration::minutes(5))));
+    }
+
+    #[test]
+    fn rise_threshold_tracks_lunar_distance() {
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        // 2025-11-05 perigee (~356,800 km) and 2025-10-24 apogee (~406,400 km)
+        let perigee = tz.with_ymd_and_hms(2025, 11, 5, 0, 0, 0).unwrap();
+        let apogee = tz.with_ymd_and_hms(2025, 10, 24, 0, 0, 0).unwrap();
+        let near = rise_set_altitude(&perigee);
+        let far = rise_set_altitude(&apogee);
+        assert!(near < -0.84 && near > -0.86, "perigee threshold {near}");
+        assert!(far > -0.83 && far < -0.80, "apogee threshold {far}");
+
+        // At moonrise the upper limb should sit 34' below the horizon
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let rise = lunar_event_time(&location, &perigee, LunarEvent::Moonrise).unwrap();
+        let position = lunar_position(&location, &rise);
+        let upper_limb = position.altitude + position.angular_diameter / 120.0;
+        let refraction = RefractionModel::StandardFixed.horizon_refraction_degrees();
+        assert!((upper_limb + refraction).abs() < 0.02, "upper limb {upper_limb}");
+
+        // Accuracy against USNO is checked by the live
+        // `usno_validation::tests::perigee_moonrise_matches_usno`
+        let model = RefractionModel::StandardFixed;
+        let standard =
+            lunar_event_time_with_model(&location, &perigee, LunarEvent::Moonrise, model);
+        assert_eq!(standard, Some(rise));
+    }
 }
@@ -22,17 +22,17 @@ pub struct BatchRiseSetResult<T: TimeZone> {
 
 /// Batch search for moonrise/moonset events
 ///
-/// Checks 4 time points simultaneously, reducing from 288 iterations
-/// (5-min sweeps over 24 hours) to 72 iterations (4 points per iteration).
+/// Sweeps the local day once in 5-minute steps, evaluating four time points per
+/// iteration, and tracks both crossings from the same altitude samples. The scalar
+/// path ([`moon::lunar_event_time`]) sweeps the day separately for each event.
 ///
-/// Performance:
-/// - Scalar version: ~138μs per event (21μs moonrise + 117μs moonset)
-/// - Batch version:  ~40-50μs per event (3-4x improvement)
+/// Crossing detection and refinement are the same as the scalar search, so both
+/// times equal [`moon::lunar_event_time`] for the same threshold.
 ///
 /// # Arguments
 /// * `location` - Observer location
 /// * `date` - Date for calculation
-/// * `threshold` - Altitude threshold in degrees (-0.834° for standard)
+/// * `threshold` - Altitude threshold in degrees (see `moon::rise_set_altitude`)
 ///
 /// # Returns
 /// Both moonrise and moonset for the given date
@@ -44,160 +44,62 @@ pub fn batch_search_rise_and_set<T: TimeZone>(
 where
     T::Offset: std::fmt::Display,
 {
-    let tz = date.timezone();
-    let start_naive = date.date_naive().and_hms_opt(0, 0, 0).unwrap();
-    let start = match tz.from_local_datetime(&start_naive) {
-        chrono::LocalResult::Single(dt) => dt,
-        _ => return BatchRiseSetResult {
-            moonrise: None,
-            moonset: None,
-            calculations_performed: 0,
-        },
-    };
-
-    let end = start.clone() + Duration::hours(24);
-    let step = Duration::minutes(5);
-
-    // Phase 1: Coarse sweep with batch altitude checks
-    let mut moonrise_candidates: Vec<(DateTime<T>, f64)> = Vec::new();
-    let mut moonset_candidates: Vec<(DateTime<T>, f64)> = Vec::new();
-    let mut calculations = 0;
-
-    let mut prev_alts = [0.0; 4];
-    let mut prev_times: Vec<DateTime<T>> = vec![];
-
-    // Initialize: calculate first batch
-    for i in 0..4 {
-        let t = start.clone() + (step * i as i32);
-        if t <= end {
-            let pos = moon::lunar_position(location, &t);
-            prev_alts[i] = pos.altitude;
-            prev_times.push(t);
-        }
-    }
-    calculations += 4;
-
-    // Main sweep loop - process 4 time points per iteration
-    loop {
-        let mut current_alts = [0.0; 4];
-        let mut current_times: Vec<DateTime<T>> = vec![];
-
-        for i in 0..4 {
-            let idx = prev_times.len() + i;
-            let t = start.clone() + (step * idx as i32);
-            if t > end {
-                break;
-            }
-            let pos = moon::lunar_position(location, &t);
-            current_alts[i] = pos.altitude;
-            current_times.push(t.clone());
-        }
-
-        if current_times.is_empty() {
-            break;
-        }
-        calculations += current_times.len();
-
-        // Check for crossings in the batch
-        for i in 0..current_times.len().min(4) {
-            if i >= prev_times.len() {
-                continue;
-            }
-
-            let prev_alt = prev_alts[i] - threshold;
-            let curr_alt = current_alts[i] - threshold;
-
-            // Moonrise: crossing from below to above
-            if prev_alt < 0.0 && curr_alt >= 0.0 {
-                moonrise_candidates.push((prev_times[i].clone(), prev_alt));
-                moonrise_candidates.push((current_times[i].clone(), curr_alt));
-            }
-
-            // Moonset: crossing from above to below
-            if prev_alt >= 0.0 && curr_alt < 0.0 {
-                moonset_candidates.push((prev_times[i].clone(), prev_alt));
-                moonset_candidates.push((current_times[i].clone(), curr_alt));
-            }
-        }
-
-        // Prepare for next iteration
-        prev_alts = current_alts;
-        prev_times = current_times;
-
-        // Move to next batch
-        if prev_times.len() >= 4 {
-            break;
-        }
-    }
-
-    // Phase 2: Binary refinement for candidate crossings
-    let moonrise = if moonrise_candidates.len() >= 2 {
-        Some(batch_refine_crossing(
-            location,
-            &moonrise_candidates[moonrise_candidates.len() - 2],
-            &moonrise_candidates[moonrise_candidates.len() - 1],
-            threshold,
-            true,
-        ))
-    } else {
-        None
+    let mut result = BatchRiseSetResult {
+        moonrise: None,
+        moonset: None,
+        calculations_performed: 0,
     };
 
-    let moonset = if moonset_candidates.len() >= 2 {
-        Some(batch_refine_crossing(
-            location,
-            &moonset_candidates[moonset_candidates.len() - 2],
-            &moonset_candidates[moonset_candidates.len() - 1],
-            threshold,
-            false,
-        ))
-    } else {
-        None
+    let tz = date.timezone();
+    let Some(start) = date
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|naive| moon::resolve_local_datetime(&tz, &naive))
+    else {
+        return result;
     };
 
-    BatchRiseSetResult {
-        moonrise,
-        moonset,
-        calculations_performed: calculations,
-    }
-}
-
-/// Binary refinement of moonrise/moonset crossing to 1-second precision
-///
-/// Takes two candidate times that bracket the crossing and refines to 1-second accuracy.
-fn batch_refine_crossing<T: TimeZone>(
-    location: &Location,
-    low_candidate: &(DateTime<T>, f64),
-    high_candidate: &(DateTime<T>, f64),
-    threshold: f64,
-    seek_rising: bool,
-) -> DateTime<T>
-where
-    T::Offset: std::fmt::Display,
-{
-    let mut low = low_candidate.0.clone();
-    let mut high = high_candidate.0.clone();
-
-    // Binary search until we reach one-second resolution
-    while (high.timestamp() - low.timestamp()).abs() > 1 {
-        let span_secs = high.timestamp() - low.timestamp();
-        let mid = low.clone() + Duration::seconds(span_secs / 2);
-        let mid_alt = moon::lunar_position(location, &mid).altitude - threshold;
-
-        if seek_rising {
-            if mid_alt >= 0.0 {
-                high = mid;
-            } else {
-                low = mid;
+    // 289 samples: local midnight through the same instant 24 hours later
+    let step = Duration::minutes(5);
+    let times: Vec<DateTime<T>> = (0..=24 * 12)
+        .map(|i| start.clone() + step * i)
+        .collect();
+
+    let mut prev: Option<(DateTime<T>, f64)> = None;
+    'sweep: for chunk in times.chunks(4) {
+        let positions = lunar_positions_batch(location, chunk);
+        result.calculations_performed += chunk.len();
+
+        for (time, position) in chunk.iter().zip(&positions) {
+            let alt = position.altitude - threshold;
+            if let Some((prev_time, prev_alt)) = &prev {
+                if result.moonrise.is_none() && *prev_alt <= 0.0 && alt >= 0.0 {
+                    result.moonrise = Some(moon::refine_crossing(
+                        location,
+                        prev_time.clone(),
+                        time.clone(),
+                        threshold,
+                        true,
+                    ));
+                }
+                if result.moonset.is_none() && *prev_alt >= 0.0 && alt <= 0.0 {
+                    result.moonset = Some(moon::refine_crossing(
+                        location,
+                        prev_time.clone(),
+                        time.clone(),
+                        threshold,
+                        false,
+                    ));
+                }
+                if result.moonrise.is_some() && result.moonset.is_some() {
+                    break 'sweep;
+                }
             }
-        } else if mid_alt <= 0.0 {
-            high = mid;
-        } else {
-            low = mid;
+            prev = Some((time.clone(), alt));
         }
     }
 
-    high
+    result
 }
 
 /// Batch altitude calculation for 4 moon positions
@@ -220,6 +122,20 @@ where
     altitudes
 }
 
+/// Calculate lunar positions for a series of times, e.g. to draw a moon altitude curve.
+///
+/// A convenience over calling [`moon::lunar_position`] per time: each sample is
+/// evaluated independently and equals the scalar result, so it is no faster.
+pub fn lunar_positions_batch<T: TimeZone>(
+    location: &Location,
+    times: &[DateTime<T>],
+) -> Vec<moon::LunarPosition> {
+    times
+        .iter()
+        .map(|dt| moon::lunar_position(location, dt))
+        .collect()
+}
+
 /// Optimized moonrise/moonset calculation combining batch search with refinement
 ///
 /// This is the main entry point for getting moonrise/moonset with optimizations applied.
@@ -232,7 +148,8 @@ pub fn lunar_event_time_optimized<T: TimeZone>(
 where
     T::Offset: std::fmt::Display,
 {
-    let threshold = -0.834; // Altitude threshold accounts for refraction + semi-diameter
+    // Altitude threshold accounts for refraction + semi-diameter + horizon dip
+    let threshold = moon::rise_set_altitude(date) - location.horizon_dip_degrees();
 
     match event {
         LunarEvent::Moonrise | LunarEvent::Moonset => {
@@ -254,7 +171,6 @@ mod tests {
     use chrono_tz::Tz;
 
     #[test]
-    #[ignore] // TODO: Fix this test - currently fails on some dates
     fn test_batch_search_returns_valid_times() {
         let location = Location::new_unchecked(40.7128, -74.0060); // New York
         let date = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
@@ -287,4 +203,48 @@ mod tests {
             assert!(*alt >= -90.0 && *alt <= 90.0);
         }
     }
+
+    #[test]
+    fn test_lunar_positions_batch_matches_scalar() {
+        let location = Location::new_unchecked(40.7128, -74.0060);
+        let start = Utc.with_ymd_and_hms(2025, 1, 15, 0, 0, 0).unwrap();
+        let times: Vec<_> = (0..24).map(|hour| start + Duration::hours(hour)).collect();
+
+        let batch = lunar_positions_batch(&location, &times);
+        assert_eq!(batch.len(), 24);
+        for (position, time) in batch.iter().zip(&times) {
+            let scalar = moon::lunar_position(&location, time);
+            assert!((position.altitude - scalar.altitude).abs() < 1e-9);
+            assert!((position.azimuth - scalar.azimuth).abs() < 1e-9);
+            assert!((position.distance - scalar.distance).abs() < 1e-6);
+            assert!((position.illumination - scalar.illumination).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_batch_search_matches_scalar_events() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        for location in [
+            Location::new_unchecked(40.7128, -74.0060),
+            Location::with_elevation(40.7128, -74.0060, 500.0).unwrap(),
+        ] {
+            for day in 1..=30 {
+                let date = tz.with_ymd_and_hms(2025, 6, day, 12, 0, 0).unwrap();
+                let threshold = moon::rise_set_altitude(&date) - location.horizon_dip_degrees();
+                let batch = batch_search_rise_and_set(&location, &date, threshold);
+                assert_eq!(
+                    batch.moonrise,
+                    moon::lunar_event_time(&location, &date, LunarEvent::Moonrise),
+                    "moonrise on day {}",
+                    day
+                );
+                assert_eq!(
+                    batch.moonset,
+                    moon::lunar_event_time(&location, &date, LunarEvent::Moonset),
+                    "moonset on day {}",
+                    day
+                );
+            }
+        }
+    }
 }
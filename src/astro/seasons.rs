@@ -0,0 +1,503 @@
+//! Equinox and solstice calculations.
+//!
+//! Uses the mean-season polynomials from Jean Meeus, "Astronomical Algorithms"
+//! (Chapter 27), corrected with the periodic terms of Table 27.C, then refined
+//! iteratively until the sun's apparent longitude equals k·90°. The NOAA solar
+//! longitude in [`super::sun`] is only good to ~0.01° (10+ minutes here), so the
+//! refinement uses the truncated VSOP87 series of Meeus Chapter 25 / Appendix III,
+//! which is good to about a second of arc.
+
+use super::time_utils::jde_to_utc;
+use super::*;
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+
+/// The four seasonal markers of the tropical year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonKind {
+    /// Sun crosses apparent longitude 0° (northward equinox)
+    MarchEquinox,
+    /// Sun reaches apparent longitude 90°
+    JuneSolstice,
+    /// Sun crosses apparent longitude 180° (southward equinox)
+    SeptemberEquinox,
+    /// Sun reaches apparent longitude 270°
+    DecemberSolstice,
+}
+
+impl SeasonKind {
+    /// Apparent solar longitude (degrees) that defines this marker.
+    pub fn solar_longitude(&self) -> f64 {
+        match self {
+            SeasonKind::MarchEquinox => 0.0,
+            SeasonKind::JuneSolstice => 90.0,
+            SeasonKind::SeptemberEquinox => 180.0,
+            SeasonKind::DecemberSolstice => 270.0,
+        }
+    }
+}
+
+/// An equinox or solstice and the UTC instant it occurs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeasonalEvent {
+    pub kind: SeasonKind,
+    pub datetime: DateTime<Utc>,
+}
+
+/// Calculate the equinoxes and solstices of a year, in chronological order.
+///
+/// Valid for years -1000 to 3000. For recent years results agree with published
+/// almanac values to within a minute; far from the present the uncertainty in ΔT
+/// dominates.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::seasons::{solstices_equinoxes, SeasonKind};
+///
+/// let [march, ..] = solstices_equinoxes(2025);
+/// assert_eq!(march.kind, SeasonKind::MarchEquinox);
+/// println!("March equinox: {}", march.datetime);
+/// ```
+pub fn solstices_equinoxes(year: i32) -> [SeasonalEvent; 4] {
+    [
+        SeasonKind::MarchEquinox,
+        SeasonKind::JuneSolstice,
+        SeasonKind::SeptemberEquinox,
+        SeasonKind::DecemberSolstice,
+    ]
+    .map(|kind| SeasonalEvent {
+        kind,
+        datetime: season_datetime(year, kind),
+    })
+}
+
+/// Hemisphere used to orient the seasons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+impl Hemisphere {
+    /// Hemisphere of a location (the equator counts as northern).
+    pub fn of(location: &Location) -> Self {
+        if location.latitude.value() < 0.0 {
+            Hemisphere::Southern
+        } else {
+            Hemisphere::Northern
+        }
+    }
+}
+
+/// Astronomical season, bounded by equinoxes and solstices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// The opposite season (as experienced in the other hemisphere).
+    pub fn opposite(&self) -> Self {
+        match self {
+            Season::Spring => Season::Autumn,
+            Season::Summer => Season::Winter,
+            Season::Autumn => Season::Spring,
+            Season::Winter => Season::Summer,
+        }
+    }
+}
+
+/// Determine the astronomical season at `dt` for the given hemisphere.
+///
+/// In the northern hemisphere spring runs from the March equinox to the June
+/// solstice, and so on; the southern hemisphere gets the opposite season.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::prelude::*;
+/// use chrono::{TimeZone, Utc};
+///
+/// let july = Utc.with_ymd_and_hms(2025, 7, 15, 12, 0, 0).unwrap();
+/// assert_eq!(season(&july, Hemisphere::Northern), Season::Summer);
+/// ```
+pub fn season<T: TimeZone>(dt: &DateTime<T>, hemisphere: Hemisphere) -> Season {
+    let utc = dt.with_timezone(&Utc);
+    let [march, june, september, december] = solstices_equinoxes(utc.year());
+
+    let northern = if utc < march.datetime {
+        Season::Winter
+    } else if utc < june.datetime {
+        Season::Spring
+    } else if utc < september.datetime {
+        Season::Summer
+    } else if utc < december.datetime {
+        Season::Autumn
+    } else {
+        Season::Winter
+    };
+
+    match hemisphere {
+        Hemisphere::Northern => northern,
+        Hemisphere::Southern => northern.opposite(),
+    }
+}
+
+fn season_datetime(year: i32, kind: SeasonKind) -> DateTime<Utc> {
+    jde_to_utc(season_jde(year, kind))
+}
+
+/// Periodic terms (A, B, C) from Meeus Table 27.C
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Season JDE (Terrestrial Time): the Table 27.C estimate refined against the
+/// sun's apparent longitude
+fn season_jde(year: i32, kind: SeasonKind) -> f64 {
+    let target = kind.solar_longitude();
+    let mut jde = approximate_season_jde(year, kind);
+
+    // Meeus (27.1): the sun moves ~1°/day, so 58 sin(Δλ) days closes the gap
+    for _ in 0..MAX_REFINEMENT_STEPS {
+        let delta = 58.0 * ((target - apparent_solar_longitude(jde)) * DEG_TO_RAD).sin();
+        jde += delta;
+        if delta.abs() < REFINEMENT_TOLERANCE_DAYS {
+            break;
+        }
+    }
+
+    jde
+}
+
+/// Refinement stops once a step moves the estimate by less than ~0.1 s
+const REFINEMENT_TOLERANCE_DAYS: f64 = 1e-6;
+const MAX_REFINEMENT_STEPS: usize = 10;
+
+/// Season JDE (Terrestrial Time) including the Table 27.C periodic correction
+fn approximate_season_jde(year: i32, kind: SeasonKind) -> f64 {
+    let jde0 = mean_season_jde(year, kind);
+    let t = julian_century(jde0);
+    let w = (35999.373 * t - 2.47) * DEG_TO_RAD;
+    let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * ((b + c * t) * DEG_TO_RAD).cos())
+        .sum();
+
+    jde0 + 0.00001 * s / delta_lambda
+}
+
+/// Apparent geocentric longitude of the sun (degrees) at a JDE.
+///
+/// Meeus Chapter 25, "higher accuracy": the Earth's heliocentric longitude from
+/// VSOP87, reduced to FK5 and corrected for nutation and aberration.
+fn apparent_solar_longitude(jde: f64) -> f64 {
+    let tau = (jde - 2_451_545.0) / 365_250.0;
+    let t = tau * 10.0;
+
+    let earth_longitude = vsop_series(&EARTH_L, tau);
+    let radius = vsop_series(&EARTH_R, tau);
+
+    let geometric = earth_longitude * RAD_TO_DEG + 180.0;
+    let fk5_correction = -0.09033 / 3600.0;
+
+    // Nutation in longitude (Meeus 22, ~0.5" accuracy)
+    let omega = (125.04452 - 1934.136261 * t) * DEG_TO_RAD;
+    let sun_mean = (280.4665 + 36000.7698 * t) * DEG_TO_RAD;
+    let moon_mean = (218.3165 + 481267.8813 * t) * DEG_TO_RAD;
+    let nutation = (-17.20 * omega.sin() - 1.32 * (2.0 * sun_mean).sin()
+        - 0.23 * (2.0 * moon_mean).sin()
+        + 0.21 * (2.0 * omega).sin())
+        / 3600.0;
+
+    let aberration = -20.4898 / 3600.0 / radius;
+
+    normalize_degrees(geometric + fk5_correction + nutation + aberration)
+}
+
+/// Evaluate a VSOP87 series Σ τⁿ Σ A cos(B + Cτ), with A in units of 1e-8
+fn vsop_series(series: &[&[(f64, f64, f64)]], tau: f64) -> f64 {
+    series
+        .iter()
+        .rev()
+        .fold(0.0, |acc, terms| {
+            let sum: f64 = terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum();
+            acc * tau + sum
+        })
+        / 1e8
+}
+
+/// Earth's heliocentric longitude, VSOP87 terms from Meeus Appendix III
+#[allow(clippy::approx_constant)] // published phases that happen to be ≈π
+const EARTH_L: [&[(f64, f64, f64)]; 6] = [
+    &[
+        (175347046.0, 0.0, 0.0),
+        (3341656.0, 4.6692568, 6283.0758500),
+        (34894.0, 4.62610, 12566.15170),
+        (3497.0, 2.7441, 5753.3849),
+        (3418.0, 2.8289, 3.5231),
+        (3136.0, 3.6277, 77713.7715),
+        (2676.0, 4.4181, 7860.4194),
+        (2343.0, 6.1352, 3930.2097),
+        (1324.0, 0.7425, 11506.7698),
+        (1273.0, 2.0371, 529.6910),
+        (1199.0, 1.1096, 1577.3435),
+        (990.0, 5.233, 5884.927),
+        (902.0, 2.045, 26.298),
+        (857.0, 3.508, 398.149),
+        (780.0, 1.179, 5223.694),
+        (753.0, 2.533, 5507.553),
+        (505.0, 4.583, 18849.228),
+        (492.0, 4.205, 775.523),
+        (357.0, 2.920, 0.067),
+        (317.0, 5.849, 11790.629),
+        (284.0, 1.899, 796.298),
+        (271.0, 0.315, 10977.079),
+        (243.0, 0.345, 5486.778),
+        (206.0, 4.806, 2544.314),
+        (205.0, 1.869, 5573.143),
+        (202.0, 2.458, 6069.777),
+        (156.0, 0.833, 213.299),
+        (132.0, 3.411, 2942.463),
+        (126.0, 1.083, 20.775),
+        (115.0, 0.645, 0.980),
+        (103.0, 0.636, 4694.003),
+        (102.0, 0.976, 15720.839),
+        (102.0, 4.267, 7.114),
+        (99.0, 6.21, 2146.17),
+        (98.0, 0.68, 155.42),
+        (86.0, 5.98, 161000.69),
+        (85.0, 1.30, 6275.96),
+        (85.0, 3.67, 71430.70),
+        (80.0, 1.81, 17260.15),
+        (79.0, 3.04, 12036.46),
+        (75.0, 1.76, 5088.63),
+        (74.0, 3.50, 3154.69),
+        (74.0, 4.68, 801.82),
+        (70.0, 0.83, 9437.76),
+        (62.0, 3.98, 8827.39),
+        (61.0, 1.82, 7084.90),
+        (57.0, 2.78, 6286.60),
+        (56.0, 4.39, 14143.50),
+        (56.0, 3.47, 6279.55),
+        (52.0, 0.19, 12139.55),
+        (52.0, 1.33, 1748.02),
+        (51.0, 0.28, 5856.48),
+        (49.0, 0.49, 1194.45),
+        (41.0, 5.37, 8429.24),
+        (41.0, 2.40, 19651.05),
+        (39.0, 6.17, 10447.39),
+        (37.0, 6.04, 10213.29),
+        (37.0, 2.57, 1059.38),
+        (36.0, 1.71, 2352.87),
+        (36.0, 1.78, 6812.77),
+        (33.0, 0.59, 17789.85),
+        (30.0, 0.44, 83996.85),
+        (30.0, 2.74, 1349.87),
+        (25.0, 3.16, 4690.48),
+    ],
+    &[
+        (628331966747.0, 0.0, 0.0),
+        (206059.0, 2.678235, 6283.075850),
+        (4303.0, 2.6351, 12566.1517),
+        (425.0, 1.590, 3.523),
+        (119.0, 5.796, 26.298),
+        (109.0, 2.966, 1577.344),
+        (93.0, 2.59, 18849.23),
+        (72.0, 1.14, 529.69),
+        (68.0, 1.87, 398.15),
+        (67.0, 4.41, 5507.55),
+        (59.0, 2.89, 5223.69),
+        (56.0, 2.17, 155.42),
+        (45.0, 0.40, 796.30),
+        (36.0, 0.47, 775.52),
+        (29.0, 2.65, 7.11),
+        (21.0, 5.34, 0.98),
+        (19.0, 1.85, 5486.78),
+        (19.0, 4.97, 213.30),
+        (17.0, 2.99, 6275.96),
+        (16.0, 0.03, 2544.31),
+        (16.0, 1.43, 2146.17),
+        (15.0, 1.21, 10977.08),
+        (12.0, 2.83, 1748.02),
+        (12.0, 3.26, 5088.63),
+        (12.0, 5.27, 1194.45),
+        (12.0, 2.08, 4694.00),
+        (11.0, 0.77, 553.57),
+        (10.0, 1.30, 6286.60),
+        (10.0, 4.24, 1349.87),
+        (9.0, 2.70, 242.73),
+        (9.0, 5.64, 951.72),
+        (8.0, 5.30, 2352.87),
+        (6.0, 2.65, 9437.76),
+        (6.0, 4.67, 4690.48),
+    ],
+    &[
+        (52919.0, 0.0, 0.0),
+        (8720.0, 1.0721, 6283.0758),
+        (309.0, 0.867, 12566.152),
+        (27.0, 0.05, 3.52),
+        (16.0, 5.19, 26.30),
+        (16.0, 3.68, 155.42),
+        (10.0, 0.76, 18849.23),
+        (9.0, 2.06, 77713.77),
+        (7.0, 0.83, 775.52),
+        (5.0, 4.66, 1577.34),
+        (4.0, 1.03, 7.11),
+        (4.0, 3.44, 5573.14),
+        (3.0, 5.14, 796.30),
+        (3.0, 6.05, 5507.55),
+        (3.0, 1.19, 242.73),
+        (3.0, 6.12, 529.69),
+        (3.0, 0.31, 398.15),
+        (3.0, 2.28, 553.57),
+        (2.0, 4.38, 5223.69),
+        (2.0, 3.75, 0.98),
+    ],
+    &[
+        (289.0, 5.844, 6283.076),
+        (35.0, 0.0, 0.0),
+        (17.0, 5.49, 12566.15),
+        (3.0, 5.20, 155.42),
+        (1.0, 4.72, 3.52),
+        (1.0, 5.30, 18849.23),
+        (1.0, 5.97, 242.73),
+    ],
+    &[(114.0, 3.142, 0.0), (8.0, 4.13, 6283.08), (1.0, 3.84, 12566.15)],
+    &[(1.0, 3.14, 0.0)],
+];
+
+/// Earth's radius vector (AU), leading VSOP87 terms; only the aberration uses it
+const EARTH_R: [&[(f64, f64, f64)]; 3] = [
+    &[
+        (100013989.0, 0.0, 0.0),
+        (1670700.0, 3.0984635, 6283.0758500),
+        (13956.0, 3.05525, 12566.15170),
+        (3084.0, 5.1985, 77713.7715),
+        (1628.0, 1.1739, 5753.3849),
+        (1576.0, 2.8469, 7860.4194),
+        (925.0, 5.453, 11506.770),
+        (542.0, 4.564, 3930.210),
+        (472.0, 3.661, 5884.927),
+    ],
+    &[(103019.0, 1.107490, 6283.075850), (1721.0, 1.0644, 12566.1517)],
+    &[(4359.0, 5.7846, 6283.0758)],
+];
+
+/// Mean season JDE from Meeus Table 27.A (years -1000..1000) and 27.B (1000..3000)
+fn mean_season_jde(year: i32, kind: SeasonKind) -> f64 {
+    let (y, coeffs) = if year < 1000 {
+        let y = year as f64 / 1000.0;
+        let coeffs = match kind {
+            SeasonKind::MarchEquinox => [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+            SeasonKind::JuneSolstice => [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+            SeasonKind::SeptemberEquinox => {
+                [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074]
+            }
+            SeasonKind::DecemberSolstice => {
+                [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006]
+            }
+        };
+        (y, coeffs)
+    } else {
+        let y = (year as f64 - 2000.0) / 1000.0;
+        let coeffs = match kind {
+            SeasonKind::MarchEquinox => [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+            SeasonKind::JuneSolstice => [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+            SeasonKind::SeptemberEquinox => {
+                [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078]
+            }
+            SeasonKind::DecemberSolstice => {
+                [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032]
+            }
+        };
+        (y, coeffs)
+    };
+
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * y + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seasons_2025_match_almanac() {
+        let expected = [
+            Utc.with_ymd_and_hms(2025, 3, 20, 9, 1, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 6, 21, 2, 42, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 9, 22, 18, 19, 0).unwrap(),
+            Utc.with_ymd_and_hms(2025, 12, 21, 15, 3, 0).unwrap(),
+        ];
+
+        for (event, expected) in solstices_equinoxes(2025).iter().zip(expected) {
+            let diff = (event.datetime - expected).num_seconds().abs();
+            assert!(
+                diff <= 60,
+                "{:?}: got {}, expected {}",
+                event.kind,
+                event.datetime,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn refinement_matches_meeus_examples() {
+        // Example 25.b: 1992 October 13.0 TD, apparent longitude 199°54'21.818"
+        let lambda = apparent_solar_longitude(2_448_908.5);
+        assert!((lambda - 199.906_06).abs() * 3600.0 < 1.0, "{lambda}");
+
+        // Example 27.a: the 1962 June solstice fell at 21:24:42 TD; the periodic
+        // terms alone give 21:25:08
+        let jde = season_jde(1962, SeasonKind::JuneSolstice);
+        assert!((jde - 2_437_837.392_15).abs() * 86_400.0 < 5.0, "{jde}");
+    }
+
+    #[test]
+    fn july_is_summer_north_and_winter_south() {
+        let july = Utc.with_ymd_and_hms(2025, 7, 15, 12, 0, 0).unwrap();
+        assert_eq!(season(&july, Hemisphere::Northern), Season::Summer);
+        assert_eq!(season(&july, Hemisphere::Southern), Season::Winter);
+
+        let sydney = Location::new(-33.8688, 151.2093).unwrap();
+        assert_eq!(Hemisphere::of(&sydney), Hemisphere::Southern);
+
+        // Boundaries: just before and after the 2025 March equinox
+        let before = Utc.with_ymd_and_hms(2025, 3, 20, 8, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 3, 20, 10, 0, 0).unwrap();
+        assert_eq!(season(&before, Hemisphere::Northern), Season::Winter);
+        assert_eq!(season(&after, Hemisphere::Northern), Season::Spring);
+        assert_eq!(
+            season(&Utc.with_ymd_and_hms(2025, 12, 31, 0, 0, 0).unwrap(), Hemisphere::Northern),
+            Season::Winter
+        );
+    }
+}
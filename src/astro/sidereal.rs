@@ -0,0 +1,81 @@
+//! Sidereal time.
+//!
+//! Greenwich mean sidereal time follows the IAU 1982 expression (Meeus eq. 12.4);
+//! apparent sidereal time adds the equation of the equinoxes from a two-term
+//! nutation series, which is good to a few hundredths of an arcsecond of time.
+
+use super::*;
+use chrono::{DateTime, TimeZone};
+
+/// Greenwich mean sidereal time in degrees (0-360).
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::sidereal::greenwich_mean_sidereal_time;
+/// use chrono::{TimeZone, Utc};
+///
+/// let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+/// let gmst = greenwich_mean_sidereal_time(&j2000);
+/// assert!((gmst - 280.46).abs() < 0.01);
+/// ```
+pub fn greenwich_mean_sidereal_time<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    normalize_degrees(gmst_unnormalized(julian_day(dt)))
+}
+
+/// Greenwich apparent sidereal time in degrees (0-360), including nutation.
+pub fn greenwich_apparent_sidereal_time<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    let jd = julian_day(dt);
+    let t = julian_century(jd);
+    normalize_degrees(gmst_unnormalized(jd) + equation_of_equinoxes(t))
+}
+
+/// Local apparent sidereal time in degrees (0-360) for the location's longitude.
+pub fn local_sidereal_time<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> f64 {
+    normalize_degrees(greenwich_apparent_sidereal_time(dt) + location.longitude.value())
+}
+
+fn gmst_unnormalized(jd: f64) -> f64 {
+    let t = julian_century(jd);
+    280.46061837 + 360.98564736629 * (jd - 2451545.0) + 0.000387933 * t * t
+        - t * t * t / 38710000.0
+}
+
+/// Equation of the equinoxes in degrees (nutation in longitude × cos obliquity)
+fn equation_of_equinoxes(t: f64) -> f64 {
+    let epsilon = 23.439291 - 0.0130042 * t; // simplified obliquity
+
+    let omega = 125.04 - 1934.136 * t; // Longitude of ascending node
+    let omega_rad = omega * DEG_TO_RAD;
+    let l_sun = 280.47 + 36000.77 * t; // Sun's mean longitude
+    let l_sun_rad = l_sun * DEG_TO_RAD;
+
+    // Nutation in longitude (simplified)
+    let delta_psi = -17.20 * omega_rad.sin() - 1.32 * (2.0 * l_sun_rad).sin();
+    let delta_psi_deg = delta_psi / 3600.0; // Convert arcseconds to degrees
+
+    delta_psi_deg * (epsilon * DEG_TO_RAD).cos()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn gmst_at_j2000() {
+        let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        let gmst = greenwich_mean_sidereal_time(&j2000);
+        assert!((gmst - 280.46061837).abs() < 1e-6, "gmst {}", gmst);
+
+        // Nutation keeps apparent within ~1.2 arcseconds of time (0.005°) of mean
+        let gast = greenwich_apparent_sidereal_time(&j2000);
+        assert!((gast - gmst).abs() < 0.005);
+
+        let greenwich = Location::new(51.4769, 0.0).unwrap();
+        let tokyo = Location::new(35.6762, 139.6503).unwrap();
+        assert!((local_sidereal_time(&greenwich, &j2000) - gast).abs() < 1e-9);
+        let lst = local_sidereal_time(&tokyo, &j2000);
+        assert!((normalize_degrees(lst - gast) - 139.6503).abs() < 1e-6);
+    }
+}
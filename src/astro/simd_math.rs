@@ -87,6 +87,25 @@ pub fn batch_sin_cos_4(angles_deg: &[f64; 4]) -> ([f64; 4], [f64; 4]) {
     (sines, cosines)
 }
 
+/// Sine and cosine for any number of angles in radians.
+///
+/// A scalar convenience over [`f64::sin_cos`]: each angle goes through the
+/// standard library call, so results are identical to it on the same target and
+/// there is no speed-up over a plain loop.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::simd_math::sin_cos_batch;
+///
+/// let angles = [0.0, std::f64::consts::FRAC_PI_2, 1.0];
+/// let results = sin_cos_batch(&angles);
+/// assert_eq!(results[2], 1.0_f64.sin_cos());
+/// ```
+pub fn sin_cos_batch(angles: &[f64]) -> Vec<(f64, f64)> {
+    angles.iter().map(|angle| angle.sin_cos()).collect()
+}
+
 /// Batch atan2 calculation for azimuth computation (critical for watch mode)
 ///
 /// Computes atan2(y, x) for 4 pairs of values. This is heavily used in
@@ -265,4 +284,18 @@ mod tests {
         // atan2(0, -1) = π or -π
         assert!((results[3].abs() - std::f64::consts::PI).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_sin_cos_batch_matches_scalar() {
+        // Negative and positive angles spanning about two full turns (-7 to 6.42 rad)
+        let angles: Vec<f64> = (0..23).map(|i| -7.0 + i as f64 * 0.61).collect();
+        let results = sin_cos_batch(&angles);
+        assert_eq!(results.len(), angles.len());
+        for (angle, (sin, cos)) in angles.iter().zip(&results) {
+            let (expected_sin, expected_cos) = angle.sin_cos();
+            assert!((sin - expected_sin).abs() < 1e-12);
+            assert!((cos - expected_cos).abs() < 1e-12);
+        }
+        assert!(sin_cos_batch(&[]).is_empty());
+    }
 }
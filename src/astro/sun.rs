@@ -14,7 +14,9 @@
 //! data within ±1-2 minutes for sunrise/sunset times.
 
 use super::*;
-use chrono::{DateTime, Duration, TimeZone};
+use chrono::{DateTime, Duration, NaiveTime, TimeZone};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Types of solar events that can be calculated.
 ///
@@ -23,6 +25,11 @@ use chrono::{DateTime, Duration, TimeZone};
 /// - Civil twilight: -6°
 /// - Nautical twilight: -12°
 /// - Astronomical twilight: -18°
+/// - Golden hour: -4° to +6°
+/// - Blue hour: -6° to -4°
+///
+/// Golden/blue hour variants without a prefix are the morning (rising) crossings,
+/// mirroring dawn; the `Evening*` variants are the setting crossings, mirroring dusk.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SolarEvent {
     /// Sunrise (top edge of sun appears on horizon)
@@ -43,6 +50,22 @@ pub enum SolarEvent {
     AstronomicalDawn,
     /// Astronomical dusk (sun 18° below horizon, evening)
     AstronomicalDusk,
+    /// Morning blue hour begins (sun rises through -6°)
+    BlueHourStart,
+    /// Morning blue hour ends (sun rises through -4°)
+    BlueHourEnd,
+    /// Morning golden hour begins (sun rises through -4°)
+    GoldenHourStart,
+    /// Morning golden hour ends (sun rises through +6°)
+    GoldenHourEnd,
+    /// Evening golden hour begins (sun sets through +6°)
+    EveningGoldenHourStart,
+    /// Evening golden hour ends (sun sets through -4°)
+    EveningGoldenHourEnd,
+    /// Evening blue hour begins (sun sets through -4°)
+    EveningBlueHourStart,
+    /// Evening blue hour ends (sun sets through -6°)
+    EveningBlueHourEnd,
 }
 
 impl SolarEvent {
@@ -55,20 +78,150 @@ impl SolarEvent {
             SolarEvent::CivilDawn | SolarEvent::CivilDusk => -6.0,
             SolarEvent::NauticalDawn | SolarEvent::NauticalDusk => -12.0,
             SolarEvent::AstronomicalDawn | SolarEvent::AstronomicalDusk => -18.0,
+            SolarEvent::BlueHourStart | SolarEvent::EveningBlueHourEnd => -6.0,
+            SolarEvent::BlueHourEnd
+            | SolarEvent::GoldenHourStart
+            | SolarEvent::EveningGoldenHourEnd
+            | SolarEvent::EveningBlueHourStart => -4.0,
+            SolarEvent::GoldenHourEnd | SolarEvent::EveningGoldenHourStart => 6.0,
             SolarEvent::SolarNoon => 90.0, // Not used for altitude calculation
         }
     }
 }
 
-/// Solar position in the sky (altitude and azimuth).
+/// Apparent solar semi-diameter in degrees (16')
+const SOLAR_SEMI_DIAMETER_DEG: f64 = 16.0 / 60.0;
+
+/// Atmospheric conditions used to scale refraction near the horizon.
+///
+/// The default is the standard atmosphere (10°C, 1010 hPa) assumed by the
+/// Bennett/Saemundsson refraction formulas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RefractionParams {
+    /// Air temperature in degrees Celsius
+    pub temperature_c: f64,
+    /// Atmospheric pressure in hectopascals
+    pub pressure_hpa: f64,
+}
+
+impl Default for RefractionParams {
+    fn default() -> Self {
+        Self {
+            temperature_c: 10.0,
+            pressure_hpa: 1010.0,
+        }
+    }
+}
+
+impl RefractionParams {
+    /// Refraction in degrees for an object at the given apparent altitude.
+    ///
+    /// Bennett's formula, R = 1 / tan(h + 7.31 / (h + 4.4)) arcminutes, scaled by
+    /// (P / 1010) × (283 / (273 + T)).
+    pub fn refraction_degrees(&self, apparent_altitude: f64) -> f64 {
+        let h = apparent_altitude;
+        let r_arcmin = 1.0 / ((h + 7.31 / (h + 4.4)) * DEG_TO_RAD).tan();
+        let scale = (self.pressure_hpa / 1010.0) * (283.0 / (273.0 + self.temperature_c));
+        r_arcmin * scale / 60.0
+    }
+
+    /// Refraction in degrees at the apparent horizon (~34' in standard conditions).
+    pub fn horizon_refraction_degrees(&self) -> f64 {
+        self.refraction_degrees(0.0)
+    }
+}
+
+/// Refraction formula used to place the apparent horizon for rise/set events.
+///
+/// The event searches look for the moment a body's upper limb reaches an apparent
+/// altitude of 0°; the model decides how much refraction lifts it there. The
+/// alternatives agree within about half an arcminute at the horizon, which moves
+/// rise and set times by a few seconds at mid-latitudes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RefractionModel {
+    /// The conventional fixed 34' (sunrise at -0.833°)
+    #[default]
+    StandardFixed,
+    /// Bennett (1982), evaluated at apparent altitude 0° (~34.5')
+    Bennett,
+    /// Sæmundsson (1986), solved for the true altitude that appears at 0° (~34.4')
+    Saemundsson,
+}
+
+impl RefractionModel {
+    /// Refraction at the apparent horizon in degrees, in standard conditions.
+    pub fn horizon_refraction_degrees(&self) -> f64 {
+        match self {
+            RefractionModel::StandardFixed => 34.0 / 60.0,
+            RefractionModel::Bennett => RefractionParams::default().horizon_refraction_degrees(),
+            RefractionModel::Saemundsson => {
+                // R(h) is defined for true altitude h; find h with h + R(h) = 0
+                let saemundsson =
+                    |h: f64| 1.02 / ((h + 10.3 / (h + 5.11)) * DEG_TO_RAD).tan() / 60.0;
+                let mut refraction = 34.0 / 60.0;
+                for _ in 0..5 {
+                    refraction = saemundsson(-refraction);
+                }
+                refraction
+            }
+        }
+    }
+}
+
+/// Twilight bands, each bounded by the solar altitudes of its dawn/dusk events.
 ///
-/// This represents where the sun appears in the sky at a given time and location.
+/// - Civil: from -6° up to sunrise/sunset (-0.833°)
+/// - Nautical: from -12° up to -6°
+/// - Astronomical: from -18° up to -12°
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TwilightKind {
+    /// Civil twilight (-6° to -0.833°)
+    Civil,
+    /// Nautical twilight (-12° to -6°)
+    Nautical,
+    /// Astronomical twilight (-18° to -12°)
+    Astronomical,
+}
+
+impl TwilightKind {
+    /// Altitude range `[lower, upper)` of this twilight band in degrees.
+    pub fn altitude_range(&self) -> (f64, f64) {
+        match self {
+            TwilightKind::Civil => (
+                SolarEvent::CivilDawn.altitude(),
+                SolarEvent::Sunrise.altitude(),
+            ),
+            TwilightKind::Nautical => (
+                SolarEvent::NauticalDawn.altitude(),
+                SolarEvent::CivilDawn.altitude(),
+            ),
+            TwilightKind::Astronomical => (
+                SolarEvent::AstronomicalDawn.altitude(),
+                SolarEvent::NauticalDawn.altitude(),
+            ),
+        }
+    }
+}
+
+/// Solar position in the sky (horizontal and equatorial coordinates).
+///
+/// This represents where the sun appears in the sky at a given time and location,
+/// along with its apparent equatorial coordinates.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SolarPosition {
     /// Altitude in degrees above the horizon (negative if below horizon)
     pub altitude: f64,
     /// Azimuth in degrees from North (0=N, 90=E, 180=S, 270=W)
     pub azimuth: f64,
+    /// Apparent declination in degrees (positive North of the celestial equator)
+    pub declination: f64,
+    /// Apparent right ascension in degrees (0-360)
+    pub right_ascension: f64,
+    /// Earth–Sun distance in astronomical units (~0.983 in January, ~1.017 in July)
+    pub distance_au: f64,
 }
 
 /// Calculate geometric mean longitude of the Sun (degrees)
@@ -100,6 +253,13 @@ fn sun_true_long(t: f64) -> f64 {
     sun_geom_mean_long(t) + sun_eq_of_center(t)
 }
 
+/// Calculate the Sun's radius vector, the Earth–Sun distance (AU)
+fn sun_rad_vector(t: f64) -> f64 {
+    let e = earth_orbit_eccentricity(t);
+    let true_anomaly = (sun_geom_mean_anom(t) + sun_eq_of_center(t)) * DEG_TO_RAD;
+    1.000001018 * (1.0 - e * e) / (1.0 + e * true_anomaly.cos())
+}
+
 /// Calculate apparent longitude of the Sun (degrees)
 fn sun_apparent_long(t: f64) -> f64 {
     let o = sun_true_long(t);
@@ -128,6 +288,14 @@ fn sun_declination(t: f64) -> f64 {
     sint.asin() * RAD_TO_DEG
 }
 
+/// Calculate the sun's apparent right ascension (degrees, 0-360)
+fn sun_right_ascension(t: f64) -> f64 {
+    let e = obliquity_correction(t) * DEG_TO_RAD;
+    let lambda = sun_apparent_long(t) * DEG_TO_RAD;
+    let ra = (e.cos() * lambda.sin()).atan2(lambda.cos()) * RAD_TO_DEG;
+    normalize_degrees(ra)
+}
+
 /// Calculate the equation of time in minutes.
 ///
 /// The equation of time represents the difference between apparent solar time
@@ -142,7 +310,7 @@ fn sun_declination(t: f64) -> f64 {
 ///
 /// The equation of time in minutes. Positive values mean the sundial is ahead
 /// of clock time, negative values mean it's behind.
-pub fn equation_of_time(t: f64) -> f64 {
+pub fn equation_of_time_century(t: f64) -> f64 {
     let epsilon = obliquity_correction(t);
     let l0 = sun_geom_mean_long(t);
     let e = earth_orbit_eccentricity(t);
@@ -163,19 +331,93 @@ pub fn equation_of_time(t: f64) -> f64 {
     4.0 * etime * RAD_TO_DEG // in minutes of time
 }
 
-/// Calculate hour angle for a given solar altitude (degrees)
-fn hour_angle_for_altitude(lat: f64, dec: f64, altitude: f64) -> Option<f64> {
+/// Calculate the equation of time in minutes at a given moment.
+///
+/// Apparent solar time minus mean solar time, from the same NOAA terms used by
+/// [`solar_position`]. Ranges from about -14 minutes in mid-February to about
+/// +16 minutes in early November, crossing zero near mid-April, mid-June,
+/// early September and late December.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::sun::equation_of_time;
+/// use chrono::{TimeZone, Utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 11, 3, 12, 0, 0).unwrap();
+/// let minutes = equation_of_time(&dt);
+/// assert!((minutes - 16.4).abs() < 0.5);
+/// ```
+pub fn equation_of_time<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    equation_of_time_century(julian_century(julian_day(dt)))
+}
+
+/// Local mean solar time at the observer's longitude.
+///
+/// UTC shifted by four minutes per degree of longitude, so mean noon falls at the
+/// same instant everywhere on a meridian. This is the time a perfectly uniform sun
+/// would show; it ignores the equation of time.
+pub fn local_mean_solar_time<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> NaiveTime {
+    time_of_day_from_minutes(utc_minutes_of_day(dt) + 4.0 * location.longitude.value())
+}
+
+/// Local apparent solar time at the observer's longitude, as read from a sundial.
+///
+/// Derived from the sun's hour angle: 12:00 is the moment the sun crosses the
+/// local meridian. Differs from [`local_mean_solar_time`] by the
+/// [`equation_of_time`].
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::sun::{local_apparent_solar_time, local_mean_solar_time};
+/// use solunatus::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location::new(51.4779, 0.0).unwrap();
+/// let dt = Utc.with_ymd_and_hms(2025, 11, 3, 12, 0, 0).unwrap();
+/// let apparent = local_apparent_solar_time(&location, &dt);
+/// let mean = local_mean_solar_time(&location, &dt);
+/// // Early November: the sundial runs about 16 minutes fast
+/// assert_eq!((apparent - mean).num_minutes(), 16);
+/// ```
+pub fn local_apparent_solar_time<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> NaiveTime {
+    // Hour angle is zero at apparent noon and advances 15° per hour
+    time_of_day_from_minutes(720.0 + solar_hour_angle(location, dt) * 4.0)
+}
+
+/// Hour angle of the sun at `dt`, in degrees in [-180, 180).
+///
+/// Zero at solar noon, when the sun crosses the local meridian; negative in the
+/// morning and positive in the afternoon, advancing 15° per hour. The moon's
+/// counterpart is [`super::moon::lunar_hour_angle`].
+pub fn solar_hour_angle<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> f64 {
+    let (_, _, ha) = solar_declination_and_hour_angle(location, dt);
+    (ha + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// Minutes since UTC midnight, including fractional seconds.
+fn utc_minutes_of_day<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    let utc_dt = dt.with_timezone(&chrono::Utc);
+    utc_dt.num_seconds_from_midnight() as f64 / 60.0 + utc_dt.nanosecond() as f64 / 60.0e9
+}
+
+/// Wrap a minute count into a single day and convert it to a time of day.
+fn time_of_day_from_minutes(minutes: f64) -> NaiveTime {
+    let millis = (minutes.rem_euclid(1440.0) * 60_000.0).round() as u32 % 86_400_000;
+    NaiveTime::from_num_seconds_from_midnight_opt(millis / 1000, (millis % 1000) * 1_000_000)
+        .unwrap_or(NaiveTime::MIN)
+}
+
+/// Cosine of the hour angle at which the sun sits at `altitude` (degrees).
+///
+/// Below -1 the sun stays above the altitude all day; above 1 it never reaches it.
+fn cos_hour_angle_for_altitude(lat: f64, dec: f64, altitude: f64) -> f64 {
     let lat_rad = lat * DEG_TO_RAD;
     let dec_rad = dec * DEG_TO_RAD;
     let alt_rad = altitude * DEG_TO_RAD;
 
-    let cos_ha = (alt_rad.sin() - lat_rad.sin() * dec_rad.sin()) / (lat_rad.cos() * dec_rad.cos());
-
-    if !(-1.0..=1.0).contains(&cos_ha) {
-        None // Event doesn't occur (polar day/night)
-    } else {
-        Some(cos_ha.acos() * RAD_TO_DEG)
-    }
+    (alt_rad.sin() - lat_rad.sin() * dec_rad.sin()) / (lat_rad.cos() * dec_rad.cos())
 }
 
 /// Calculate solar noon time for a given location and date.
@@ -213,7 +455,7 @@ pub fn solar_noon<T: TimeZone>(location: &Location, date: &DateTime<T>) -> DateT
 
     let jd = julian_day(&utc_noon);
     let t = julian_century(jd);
-    let eqtime = equation_of_time(t);
+    let eqtime = equation_of_time_century(t);
 
     // Solar noon in minutes from midnight UTC
     let solar_noon_offset = 720.0 - 4.0 * location.longitude.value() - eqtime;
@@ -225,10 +467,38 @@ pub fn solar_noon<T: TimeZone>(location: &Location, date: &DateTime<T>) -> DateT
     solar_noon_utc.with_timezone(&date.timezone())
 }
 
+/// Calculate the sun's apparent ecliptic longitude in degrees (0–360).
+///
+/// 0° is the March equinox point; pass the result to
+/// [`zodiac_sign`](super::coordinates::zodiac_sign) to name the sign.
+pub fn sun_ecliptic_longitude<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    let t = julian_century(julian_day(dt));
+    normalize_degrees(sun_apparent_long(t))
+}
+
+/// Calculate the sun's altitude at solar noon (its transit altitude).
+///
+/// This is the highest point the sun reaches on the given date, or the
+/// least negative altitude during polar night.
+///
+/// # Arguments
+///
+/// * `location` - Geographic location
+/// * `date` - Date for calculation (time component is ignored)
+///
+/// # Returns
+///
+/// Geometric altitude in degrees of the sun's center, without atmospheric refraction
+/// (the same as [`solar_position`]'s `altitude`).
+pub fn solar_noon_altitude<T: TimeZone>(location: &Location, date: &DateTime<T>) -> f64 {
+    solar_position(location, &solar_noon(location, date)).altitude
+}
+
 /// Calculate the time of a solar event for a given location and date.
 ///
 /// Calculates when specific solar events occur (sunrise, sunset, twilight times, etc.).
 /// Returns `None` if the event doesn't occur on this date (e.g., polar day/night).
+/// Sunrise and sunset account for the horizon dip at the location's elevation.
 ///
 /// # Arguments
 ///
@@ -261,20 +531,140 @@ pub fn solar_event_time<T: TimeZone>(
     date: &DateTime<T>,
     event: SolarEvent,
 ) -> Option<DateTime<T>> {
+    solar_event_status(location, date, event).time()
+}
+
+/// Outcome of a solar event search, distinguishing polar day from polar night.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SolarEventResult<T: TimeZone> {
+    /// The event occurs at the given time
+    Occurs(DateTime<T>),
+    /// The sun stays above the event's altitude all day (e.g. polar day for sunset)
+    AlwaysUp,
+    /// The sun stays below the event's altitude all day (e.g. polar night for sunrise)
+    AlwaysDown,
+}
+
+impl<T: TimeZone> SolarEventResult<T> {
+    /// The event time, or `None` for polar day/night.
+    pub fn time(self) -> Option<DateTime<T>> {
+        match self {
+            SolarEventResult::Occurs(dt) => Some(dt),
+            SolarEventResult::AlwaysUp | SolarEventResult::AlwaysDown => None,
+        }
+    }
+}
+
+/// Calculate a solar event, reporting why it does not occur when it doesn't.
+///
+/// When the sun never crosses the event's altitude, the sign of the crossing
+/// hour angle's cosine decides between [`SolarEventResult::AlwaysUp`] and
+/// [`SolarEventResult::AlwaysDown`]. [`solar_event_time`] is
+/// `solar_event_status(..).time()`.
+pub fn solar_event_status<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+) -> SolarEventResult<T> {
     if event == SolarEvent::SolarNoon {
-        return Some(solar_noon(location, date));
+        return SolarEventResult::Occurs(solar_noon(location, date));
     }
 
-    // Use noon UTC as reference for calculations
-    let base_date = date.date_naive().and_hms_opt(12, 0, 0).unwrap();
-    let utc_noon = chrono::Utc.from_local_datetime(&base_date).unwrap();
+    let altitude = event_threshold(location, event);
+    event_status_at_altitude(location, date, event, altitude)
+}
 
-    let jd = julian_day(&utc_noon);
-    let t = julian_century(jd);
-    let dec = sun_declination(t);
-    let eqtime = equation_of_time(t);
+/// Altitude threshold for an event at this location, in degrees
+fn event_threshold(location: &Location, event: SolarEvent) -> f64 {
+    // An elevated observer sees the sun's limb earlier/later over the dipped horizon.
+    // Twilight thresholds are defined against the astronomical horizon and are unaffected.
+    match event {
+        SolarEvent::Sunrise | SolarEvent::Sunset => {
+            event.altitude() - location.horizon_dip_degrees()
+        }
+        _ => event.altitude(),
+    }
+}
+
+/// Calculate sunrise/sunset using refraction for the given atmospheric conditions.
+///
+/// Replaces the standard 34' horizon refraction baked into [`SolarEvent::altitude`]
+/// with the Bennett formula scaled for temperature and pressure. Twilight events and
+/// solar noon are not refraction-dependent and match [`solar_event_time`].
+pub fn solar_event_time_with_refraction<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+    params: RefractionParams,
+) -> Option<DateTime<T>> {
+    match event {
+        SolarEvent::Sunrise | SolarEvent::Sunset => {
+            let altitude = -(SOLAR_SEMI_DIAMETER_DEG + params.horizon_refraction_degrees())
+                - location.horizon_dip_degrees();
+            event_time_at_altitude(location, date, event, altitude)
+        }
+        _ => solar_event_time(location, date, event),
+    }
+}
 
-    let ha = hour_angle_for_altitude(location.latitude.value(), dec, event.altitude())?;
+/// Calculate a solar event using the given horizon refraction model.
+///
+/// [`RefractionModel::StandardFixed`] reproduces [`solar_event_time`] exactly. The
+/// other models replace the fixed 34' with their own horizon refraction for
+/// sunrise/sunset; twilight events and solar noon are unaffected.
+pub fn solar_event_time_with_model<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+    model: RefractionModel,
+) -> Option<DateTime<T>> {
+    match (event, model) {
+        (_, RefractionModel::StandardFixed) => solar_event_time(location, date, event),
+        (SolarEvent::Sunrise | SolarEvent::Sunset, _) => {
+            let altitude = -(SOLAR_SEMI_DIAMETER_DEG + model.horizon_refraction_degrees())
+                - location.horizon_dip_degrees();
+            event_time_at_altitude(location, date, event, altitude)
+        }
+        _ => solar_event_time(location, date, event),
+    }
+}
+
+/// Calculate sunrise/sunset against an obstructed horizon at `horizon_deg` altitude.
+///
+/// The horizon replaces the sea-level horizon (and any elevation dip); the standard
+/// refraction and semi-diameter allowance is kept on top of it. A positive horizon
+/// delays sunrise and advances sunset. Other events are unaffected by the horizon
+/// and match [`solar_event_time`].
+pub fn solar_event_time_at_altitude<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+    horizon_deg: f64,
+) -> Option<DateTime<T>> {
+    match event {
+        SolarEvent::Sunrise | SolarEvent::Sunset => {
+            event_time_at_altitude(location, date, event, event.altitude() + horizon_deg)
+        }
+        _ => solar_event_time(location, date, event),
+    }
+}
+
+fn event_time_at_altitude<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+    altitude: f64,
+) -> Option<DateTime<T>> {
+    event_status_at_altitude(location, date, event, altitude).time()
+}
+
+fn event_status_at_altitude<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+    altitude: f64,
+) -> SolarEventResult<T> {
+    let (dec, eqtime) = declination_and_equation_of_time(date);
 
     let is_rising = matches!(
         event,
@@ -282,8 +672,57 @@ pub fn solar_event_time<T: TimeZone>(
             | SolarEvent::CivilDawn
             | SolarEvent::NauticalDawn
             | SolarEvent::AstronomicalDawn
+            | SolarEvent::BlueHourStart
+            | SolarEvent::BlueHourEnd
+            | SolarEvent::GoldenHourStart
+            | SolarEvent::GoldenHourEnd
     );
 
+    crossing_status(location, date, dec, eqtime, altitude, is_rising)
+}
+
+/// Solar declination and equation of time (minutes) at noon UTC on the date.
+fn declination_and_equation_of_time<T: TimeZone>(date: &DateTime<T>) -> (f64, f64) {
+    // Use noon UTC as reference for calculations
+    let base_date = date.date_naive().and_hms_opt(12, 0, 0).unwrap();
+    let utc_noon = chrono::Utc.from_local_datetime(&base_date).unwrap();
+
+    let jd = julian_day(&utc_noon);
+    let t = julian_century(jd);
+    (sun_declination(t), equation_of_time_century(t))
+}
+
+/// Time the sun crosses `altitude` on the date, given that day's declination and
+/// equation of time.
+fn crossing_time<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    dec: f64,
+    eqtime: f64,
+    altitude: f64,
+    is_rising: bool,
+) -> Option<DateTime<T>> {
+    crossing_status(location, date, dec, eqtime, altitude, is_rising).time()
+}
+
+/// Like [`crossing_time`], but says whether a missing crossing is polar day or night.
+fn crossing_status<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    dec: f64,
+    eqtime: f64,
+    altitude: f64,
+    is_rising: bool,
+) -> SolarEventResult<T> {
+    let cos_ha = cos_hour_angle_for_altitude(location.latitude.value(), dec, altitude);
+    if cos_ha < -1.0 {
+        return SolarEventResult::AlwaysUp;
+    }
+    if cos_ha > 1.0 || cos_ha.is_nan() {
+        return SolarEventResult::AlwaysDown;
+    }
+    let ha = cos_ha.acos() * RAD_TO_DEG;
+
     let offset = if is_rising {
         720.0 - 4.0 * (location.longitude.value() + ha) - eqtime
     } else {
@@ -294,7 +733,141 @@ pub fn solar_event_time<T: TimeZone>(
     let event_utc = chrono::Utc.from_local_datetime(&utc_midnight).unwrap()
         + Duration::seconds((offset * 60.0) as i64);
 
-    Some(event_utc.with_timezone(&date.timezone()))
+    SolarEventResult::Occurs(event_utc.with_timezone(&date.timezone()))
+}
+
+/// Morning and evening twilight for one date, plus the following night.
+///
+/// Each pair is `(dawn, dusk)`: the morning time the sun climbs past the phase's
+/// altitude and the evening time it sinks back below it. A field is `None` when
+/// the crossing does not happen (e.g. no astronomical darkness in a high-latitude
+/// summer).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwilightWindows<T: TimeZone> {
+    /// Civil dawn and civil dusk (sun at -6°)
+    pub civil: Option<(DateTime<T>, DateTime<T>)>,
+    /// Nautical dawn and nautical dusk (sun at -12°)
+    pub nautical: Option<(DateTime<T>, DateTime<T>)>,
+    /// Astronomical dawn and astronomical dusk (sun at -18°)
+    pub astronomical: Option<(DateTime<T>, DateTime<T>)>,
+    /// Fully dark night: this evening's astronomical dusk to the next astronomical dawn
+    pub night: Option<(DateTime<T>, DateTime<T>)>,
+}
+
+/// Calculate all twilight phases for a date in one pass.
+///
+/// Shares the solar declination and equation of time between phases instead of
+/// recomputing them for every [`solar_event_time`] call.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::astro::sun::twilight_windows;
+/// use solunatus::Location;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location::new(40.7128, -74.0060).unwrap();
+/// let date = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+/// let windows = twilight_windows(&location, &date);
+/// let (civil_dawn, civil_dusk) = windows.civil.unwrap();
+/// assert!(civil_dawn < civil_dusk);
+/// ```
+pub fn twilight_windows<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+) -> TwilightWindows<T> {
+    let (dec, eqtime) = declination_and_equation_of_time(date);
+    let pair = |altitude: f64| {
+        let dawn = crossing_time(location, date, dec, eqtime, altitude, true)?;
+        let dusk = crossing_time(location, date, dec, eqtime, altitude, false)?;
+        Some((dawn, dusk))
+    };
+
+    let civil = pair(SolarEvent::CivilDawn.altitude());
+    let nautical = pair(SolarEvent::NauticalDawn.altitude());
+    let astronomical = pair(SolarEvent::AstronomicalDawn.altitude());
+
+    let night = astronomical.as_ref().and_then(|(_, dusk)| {
+        let next_day = date.clone() + Duration::days(1);
+        let (next_dec, next_eqtime) = declination_and_equation_of_time(&next_day);
+        let altitude = SolarEvent::AstronomicalDawn.altitude();
+        let dawn = crossing_time(location, &next_day, next_dec, next_eqtime, altitude, true)?;
+        Some((dusk.clone(), dawn))
+    });
+
+    TwilightWindows {
+        civil,
+        nautical,
+        astronomical,
+        night,
+    }
+}
+
+/// Calculate the length of daylight (sunrise to sunset) for a given date.
+///
+/// Returns `None` on polar day or polar night when the sun does not rise or set.
+/// When the computed sunset falls before sunrise on the clock (the daylight span
+/// crosses midnight in the input timezone), the following day's sunset is used.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::prelude::*;
+/// use chrono::{TimeZone, Utc};
+///
+/// let location = Location::new(40.7128, -74.0060).unwrap();
+/// let date = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+/// let length = day_length(&location, &date).unwrap();
+/// assert!(length.num_hours() >= 15);
+/// ```
+pub fn day_length<T: TimeZone>(location: &Location, date: &DateTime<T>) -> Option<Duration> {
+    let sunrise = solar_event_time(location, date, SolarEvent::Sunrise)?;
+    let mut sunset = solar_event_time(location, date, SolarEvent::Sunset)?;
+
+    if sunset <= sunrise {
+        let next_day = date.clone() + Duration::days(1);
+        sunset = solar_event_time(location, &next_day, SolarEvent::Sunset)?;
+    }
+
+    Some(sunset - sunrise)
+}
+
+/// Time remaining until the sun sets, measured from `dt`.
+///
+/// Returns `None` while the sun is down (see [`is_sun_up`]) and during polar day,
+/// when there is no sunset to count down to. If the local date's sunset has
+/// already passed but the sun is still up, the following day's sunset is used.
+pub fn time_until_sunset<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Option<Duration> {
+    if !is_sun_up(location, dt) {
+        return None;
+    }
+
+    let mut sunset = solar_event_time(location, dt, SolarEvent::Sunset)?;
+    if sunset < *dt {
+        let next_day = dt.clone() + Duration::days(1);
+        sunset = solar_event_time(location, &next_day, SolarEvent::Sunset)?;
+    }
+
+    Some(sunset - dt.clone())
+}
+
+/// Time elapsed since the sun rose, measured at `dt`.
+///
+/// Returns `None` while the sun is down and during polar day, when there is no
+/// sunrise to count from. If the local date's sunrise is still ahead but the sun
+/// is already up, the previous day's sunrise is used.
+pub fn time_since_sunrise<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Option<Duration> {
+    if !is_sun_up(location, dt) {
+        return None;
+    }
+
+    let mut sunrise = solar_event_time(location, dt, SolarEvent::Sunrise)?;
+    if sunrise > *dt {
+        let previous_day = dt.clone() - Duration::days(1);
+        sunrise = solar_event_time(location, &previous_day, SolarEvent::Sunrise)?;
+    }
+
+    Some(dt.clone() - sunrise)
 }
 
 /// Calculate the solar position (altitude and azimuth) at a specific time.
@@ -311,6 +884,9 @@ pub fn solar_event_time<T: TimeZone>(
 /// A `SolarPosition` containing:
 /// - `altitude`: Degrees above horizon (negative if below horizon)
 /// - `azimuth`: Degrees from North (0=N, 90=E, 180=S, 270=W)
+/// - `declination`: Apparent declination in degrees
+/// - `right_ascension`: Apparent right ascension in degrees
+/// - `distance_au`: Earth–Sun distance in astronomical units
 ///
 /// # Examples
 ///
@@ -327,11 +903,74 @@ pub fn solar_event_time<T: TimeZone>(
 /// println!("Sun azimuth: {:.2}°", pos.azimuth);
 /// ```
 pub fn solar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> SolarPosition {
+    let (t, dec, ha) = solar_declination_and_hour_angle(location, dt);
+
+    let lat_rad = location.latitude.value() * DEG_TO_RAD;
+    let dec_rad = dec * DEG_TO_RAD;
+    let ha_rad = ha * DEG_TO_RAD;
+
+    // Calculate altitude
+    let sin_alt = lat_rad.sin() * dec_rad.sin() + lat_rad.cos() * dec_rad.cos() * ha_rad.cos();
+    let altitude = sin_alt.asin() * RAD_TO_DEG;
+
+    SolarPosition {
+        altitude,
+        azimuth: solar_azimuth(lat_rad, dec_rad, ha_rad, altitude),
+        declination: dec,
+        right_ascension: sun_right_ascension(t),
+        distance_au: sun_rad_vector(t),
+    }
+}
+
+/// Calculate solar positions for a series of times, e.g. to plot the sun's path.
+///
+/// Matches calling [`solar_position`] for each time. Altitudes are computed four at
+/// a time with [`simd_math::batch_altitude_4`](super::simd_math::batch_altitude_4)
+/// and the observer's latitude terms are shared across the whole series.
+pub fn solar_positions_batch<T: TimeZone>(
+    location: &Location,
+    times: &[DateTime<T>],
+) -> Vec<SolarPosition> {
+    let lat_rad = location.latitude.value() * DEG_TO_RAD;
+    let mut positions = Vec::with_capacity(times.len());
+
+    for chunk in times.chunks(4) {
+        let mut centuries = [0.0; 4];
+        let mut dec_rad = [0.0; 4];
+        let mut ha_rad = [0.0; 4];
+        for (i, dt) in chunk.iter().enumerate() {
+            let (t, dec, ha) = solar_declination_and_hour_angle(location, dt);
+            centuries[i] = t;
+            dec_rad[i] = dec * DEG_TO_RAD;
+            ha_rad[i] = ha * DEG_TO_RAD;
+        }
+
+        let altitudes = super::simd_math::batch_altitude_4(lat_rad, &dec_rad, &ha_rad);
+        for i in 0..chunk.len() {
+            let altitude = altitudes[i] * RAD_TO_DEG;
+            positions.push(SolarPosition {
+                altitude,
+                azimuth: solar_azimuth(lat_rad, dec_rad[i], ha_rad[i], altitude),
+                declination: dec_rad[i] * RAD_TO_DEG,
+                right_ascension: sun_right_ascension(centuries[i]),
+                distance_au: sun_rad_vector(centuries[i]),
+            });
+        }
+    }
+
+    positions
+}
+
+/// Julian century, declination (degrees) and hour angle (degrees) of the sun at `dt`.
+fn solar_declination_and_hour_angle<T: TimeZone>(
+    location: &Location,
+    dt: &DateTime<T>,
+) -> (f64, f64, f64) {
     let jd = julian_day(dt);
     let t = julian_century(jd);
 
     let dec = sun_declination(t);
-    let eqtime = equation_of_time(t);
+    let eqtime = equation_of_time_century(t);
 
     // Convert to UTC for calculation (CRITICAL: must use UTC, not local time)
     let utc_dt = dt.with_timezone(&chrono::Utc);
@@ -346,14 +985,11 @@ pub fn solar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Sol
     // Hour angle in degrees
     let ha = (true_solar_time / 4.0) - 180.0;
 
-    let lat_rad = location.latitude.value() * DEG_TO_RAD;
-    let dec_rad = dec * DEG_TO_RAD;
-    let ha_rad = ha * DEG_TO_RAD;
-
-    // Calculate altitude
-    let sin_alt = lat_rad.sin() * dec_rad.sin() + lat_rad.cos() * dec_rad.cos() * ha_rad.cos();
-    let altitude = sin_alt.asin() * RAD_TO_DEG;
+    (t, dec, ha)
+}
 
+/// Solar azimuth in degrees from North for the given position and altitude.
+fn solar_azimuth(lat_rad: f64, dec_rad: f64, ha_rad: f64, altitude: f64) -> f64 {
     // Calculate azimuth using atan2 for numerical stability
     let altitude_rad = altitude * DEG_TO_RAD;
     let cos_az =
@@ -364,24 +1000,632 @@ pub fn solar_position<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Sol
     if azimuth < 0.0 {
         azimuth += 360.0;
     }
+    azimuth
+}
 
-    SolarPosition { altitude, azimuth }
+/// Calculate the sun's azimuth (degrees from North) at the moment of a solar event.
+///
+/// Useful for finding where on the horizon the sun rises or sets. Returns `None`
+/// when the event doesn't occur on this date.
+pub fn solar_event_azimuth<T: TimeZone>(
+    location: &Location,
+    date: &DateTime<T>,
+    event: SolarEvent,
+) -> Option<f64> {
+    let time = solar_event_time(location, date, event)?;
+    Some(solar_position(location, &time).azimuth)
+}
+
+/// Lowest solar altitude used for shadow ratios; below this the ratio is held at ~115
+const MIN_SHADOW_ALTITUDE_DEG: f64 = 0.5;
+
+/// Ratio of a vertical object's shadow length to its height at `dt`.
+///
+/// Computed as `1 / tan(altitude)`. Returns `None` when the sun's center is at or
+/// below the horizon. Between 0° and 0.5° altitude the ratio is clamped to its
+/// 0.5° value (~114.6), since the true value diverges and terrain dominates anyway.
+pub fn shadow_length_ratio<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Option<f64> {
+    let altitude = solar_position(location, dt).altitude;
+    if altitude <= 0.0 {
+        return None;
+    }
+    let altitude = altitude.max(MIN_SHADOW_ALTITUDE_DEG);
+    Some(1.0 / (altitude * DEG_TO_RAD).tan())
+}
+
+/// Relative optical air mass toward the sun at `dt`.
+///
+/// Path length through the atmosphere relative to looking straight up, from the
+/// Kasten & Young (1989) formula on the sun's geometric altitude: 1.0 at the
+/// zenith, about 2 at 30° altitude and about 38 at the horizon. Returns `None`
+/// when the sun's center is below the horizon.
+pub fn air_mass<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> Option<f64> {
+    let altitude = solar_position(location, dt).altitude;
+    if altitude < 0.0 {
+        return None;
+    }
+    Some(1.0 / ((altitude * DEG_TO_RAD).sin() + 0.50572 * (altitude + 6.07995).powf(-1.6364)))
+}
+
+/// Solar constant used by [`clear_sky_irradiance`], in W/m²
+const SOLAR_CONSTANT_W_M2: f64 = 1353.0;
+
+/// Rough clear-sky direct-normal irradiance in W/m² at `dt`.
+///
+/// Uses the Meinel attenuation model, `1353 × 0.7^(AM^0.678)` with `AM` from
+/// [`air_mass`]: about 950 W/m² with the sun overhead, falling steeply toward the
+/// horizon. Returns 0 when the sun is down. Ignores haze, humidity and elevation,
+/// so treat it as an upper-bound estimate for PV yield, not a forecast.
+pub fn clear_sky_irradiance<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> f64 {
+    match air_mass(location, dt) {
+        Some(am) => SOLAR_CONSTANT_W_M2 * 0.7_f64.powf(am.powf(0.678)),
+        None => 0.0,
+    }
+}
+
+/// Check whether the sun is above the horizon at `dt`.
+///
+/// Uses the same threshold as the sunrise/sunset search (-0.833° lowered by the
+/// horizon dip at the location's elevation), so the result flips at the computed
+/// sunrise and sunset times, give or take the event search's sub-minute precision.
+pub fn is_sun_up<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> bool {
+    solar_position(location, dt).altitude >= event_threshold(location, SolarEvent::Sunrise)
+}
+
+/// Check whether the given twilight band is in progress at `dt`.
+///
+/// Bands do not overlap: during nautical twilight `in_twilight(.., TwilightKind::Civil)`
+/// is false.
+pub fn in_twilight<T: TimeZone>(
+    location: &Location,
+    dt: &DateTime<T>,
+    kind: TwilightKind,
+) -> bool {
+    let altitude = solar_position(location, dt).altitude;
+    let (lower, upper) = kind.altitude_range();
+    altitude >= lower && altitude < upper
+}
+
+/// Overall sky brightness state, from the sun's altitude alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkyState {
+    /// Sun is up (upper limb above the horizon)
+    Day,
+    /// Sun between -6° and the horizon
+    CivilTwilight,
+    /// Sun between -12° and -6°
+    NauticalTwilight,
+    /// Sun between -18° and -12°
+    AstronomicalTwilight,
+    /// Sun more than 18° below the horizon
+    Night,
+}
+
+/// Classify the sky at `dt` as day, one of the twilight bands, or night.
+///
+/// Uses a single [`solar_position`] evaluation and the same thresholds as
+/// [`is_sun_up`] and [`in_twilight`], so no rise/set search is needed.
+pub fn sky_state<T: TimeZone>(location: &Location, dt: &DateTime<T>) -> SkyState {
+    let altitude = solar_position(location, dt).altitude;
+
+    if altitude >= SolarEvent::Sunrise.altitude() {
+        SkyState::Day
+    } else if altitude >= SolarEvent::CivilDawn.altitude() {
+        SkyState::CivilTwilight
+    } else if altitude >= SolarEvent::NauticalDawn.altitude() {
+        SkyState::NauticalTwilight
+    } else if altitude >= SolarEvent::AstronomicalDawn.altitude() {
+        SkyState::AstronomicalTwilight
+    } else {
+        SkyState::Night
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::astro::coordinates::zodiac_sign;
     use chrono::Utc;
 
+    #[test]
+    fn batch_solar_positions_match_single_calls() {
+        let location = Location::new(-33.8688, 151.2093).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 12, 21, 0, 0, 0).unwrap();
+        // 97 samples: not a multiple of four, so the last chunk is partial
+        let times: Vec<_> = (0..97).map(|i| start + Duration::minutes(15 * i)).collect();
+
+        let batch = solar_positions_batch(&location, &times);
+        assert_eq!(batch.len(), times.len());
+        for (position, dt) in batch.iter().zip(&times) {
+            let single = solar_position(&location, dt);
+            assert!((position.altitude - single.altitude).abs() < 1e-9);
+            assert!((position.azimuth - single.azimuth).abs() < 1e-9);
+            assert!((position.declination - single.declination).abs() < 1e-12);
+            assert_eq!(position.distance_au, single.distance_au);
+        }
+    }
+
+    #[test]
+    fn sun_enters_aries_at_march_equinox() {
+        // March equinox 2025 was 2025-03-20 09:01 UTC
+        let before = Utc.with_ymd_and_hms(2025, 3, 19, 12, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 3, 22, 0, 0, 0).unwrap();
+        assert_eq!(zodiac_sign(sun_ecliptic_longitude(&before)), "Pisces");
+        assert_eq!(zodiac_sign(sun_ecliptic_longitude(&after)), "Aries");
+    }
+
+    #[test]
+    fn equinox_noon_sun_is_overhead_at_equator() {
+        let location = Location::new(0.0, 0.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        let altitude = solar_noon_altitude(&location, &date);
+        assert!((altitude - 90.0).abs() < 0.5, "altitude {altitude}");
+    }
+
+    #[test]
+    fn sky_state_follows_solar_altitude() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let noon = tz.with_ymd_and_hms(2025, 10, 15, 12, 0, 0).unwrap();
+        let midnight = tz.with_ymd_and_hms(2025, 10, 15, 0, 0, 0).unwrap();
+        assert_eq!(sky_state(&location, &noon), SkyState::Day);
+        assert_eq!(sky_state(&location, &midnight), SkyState::Night);
+
+        let civil_dusk = solar_event_time(&location, &noon, SolarEvent::CivilDusk).unwrap();
+        let just_before = civil_dusk - Duration::minutes(2);
+        assert_eq!(sky_state(&location, &just_before), SkyState::CivilTwilight);
+        let just_after = civil_dusk + Duration::minutes(2);
+        assert_eq!(sky_state(&location, &just_after), SkyState::NauticalTwilight);
+    }
+
+    #[test]
+    fn earth_sun_distance_follows_perihelion_and_aphelion() {
+        let location = Location::new(0.0, 0.0).unwrap();
+        // Perihelion 2025-01-04 (0.98333 AU), aphelion 2025-07-03 (1.01664 AU)
+        let january = Utc.with_ymd_and_hms(2025, 1, 4, 13, 28, 0).unwrap();
+        let july = Utc.with_ymd_and_hms(2025, 7, 3, 19, 55, 0).unwrap();
+        let near = solar_position(&location, &january).distance_au;
+        let far = solar_position(&location, &july).distance_au;
+
+        assert!(near < far);
+        assert!((near - 0.98333).abs() < 1e-4, "perihelion {}", near);
+        assert!((far - 1.01664).abs() < 1e-4, "aphelion {}", far);
+    }
+
+    #[test]
+    fn refraction_models_shift_sunrise_by_seconds() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let sunrise = |model| {
+            solar_event_time_with_model(&location, &date, SolarEvent::Sunrise, model).unwrap()
+        };
+
+        let standard = sunrise(RefractionModel::StandardFixed);
+        assert_eq!(
+            standard,
+            solar_event_time(&location, &date, SolarEvent::Sunrise).unwrap()
+        );
+
+        let bennett = sunrise(RefractionModel::Bennett);
+        let saemundsson = sunrise(RefractionModel::Saemundsson);
+        // Bennett gives ~34.48' and Sæmundsson ~34.43' at the horizon, so both see the
+        // sun a couple of seconds before the fixed 34' model at New York's equinox
+        for (name, time) in [("Bennett", bennett), ("Saemundsson", saemundsson)] {
+            let lead = (standard - time).num_seconds();
+            assert!((0..=10).contains(&lead), "{} sunrise lead {} s", name, lead);
+        }
+        assert!((bennett - saemundsson).num_seconds().abs() <= 2);
+    }
+
+    #[test]
+    fn twilight_windows_nest_and_match_events() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let windows = twilight_windows(&location, &date);
+
+        let (civil_dawn, civil_dusk) = windows.civil.unwrap();
+        let (nautical_dawn, nautical_dusk) = windows.nautical.unwrap();
+        let (astro_dawn, astro_dusk) = windows.astronomical.unwrap();
+        assert!(astro_dawn < nautical_dawn && nautical_dawn < civil_dawn);
+        assert!(civil_dusk < nautical_dusk && nautical_dusk < astro_dusk);
+
+        assert_eq!(
+            Some(civil_dawn),
+            solar_event_time(&location, &date, SolarEvent::CivilDawn)
+        );
+        assert_eq!(
+            Some(astro_dusk),
+            solar_event_time(&location, &date, SolarEvent::AstronomicalDusk)
+        );
+
+        let (night_start, night_end) = windows.night.unwrap();
+        assert_eq!(night_start, astro_dusk);
+        let next_day = date + Duration::days(1);
+        assert_eq!(
+            Some(night_end),
+            solar_event_time(&location, &next_day, SolarEvent::AstronomicalDawn)
+        );
+    }
+
+    #[test]
+    fn twilight_windows_missing_phases_at_high_latitude() {
+        // Tromsø at midsummer: the sun never sets, so no twilight or night at all
+        let location = Location::new(69.6492, 18.9553).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let windows = twilight_windows(&location, &date);
+        assert!(windows.civil.is_none());
+        assert!(windows.astronomical.is_none());
+        assert!(windows.night.is_none());
+
+        // London at midsummer: civil twilight but no astronomical darkness
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let windows = twilight_windows(&location, &date);
+        assert!(windows.civil.is_some());
+        assert!(windows.astronomical.is_none());
+        assert!(windows.night.is_none());
+    }
+
     #[test]
     fn test_equation_of_time() {
         // Test equation of time calculation
         let dt = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
         let jd = julian_day(&dt);
         let t = julian_century(jd);
-        let eqtime = equation_of_time(t);
+        let eqtime = equation_of_time_century(t);
 
         // Should be approximately -3 minutes on Jan 1, 2000
         assert!((eqtime - (-3.0)).abs() < 1.0);
     }
+
+    #[test]
+    fn sun_up_and_twilight_predicates() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+
+        let noon = solar_noon(&location, &date);
+        assert!(is_sun_up(&location, &noon));
+        assert!(!in_twilight(&location, &noon, TwilightKind::Civil));
+
+        // ~01:00 local (EDT) is deep night
+        let night = Utc.with_ymd_and_hms(2025, 6, 21, 5, 0, 0).unwrap();
+        assert!(!is_sun_up(&location, &night));
+        assert!(!in_twilight(&location, &night, TwilightKind::Civil));
+
+        // Halfway between civil dawn and sunrise lies inside civil twilight
+        let dawn = solar_event_time(&location, &date, SolarEvent::CivilDawn).unwrap();
+        let sunrise = solar_event_time(&location, &date, SolarEvent::Sunrise).unwrap();
+        let mid = dawn + (sunrise - dawn) / 2;
+        assert!(in_twilight(&location, &mid, TwilightKind::Civil));
+        assert!(!in_twilight(&location, &mid, TwilightKind::Nautical));
+    }
+
+    #[test]
+    fn elevation_advances_sunrise_and_delays_sunset() {
+        let sea_level = Location::new(39.7392, -104.9903).unwrap();
+        let summit = Location::with_elevation(39.7392, -104.9903, 3000.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+
+        let rise_0 = solar_event_time(&sea_level, &date, SolarEvent::Sunrise).unwrap();
+        let rise_3000 = solar_event_time(&summit, &date, SolarEvent::Sunrise).unwrap();
+        let set_0 = solar_event_time(&sea_level, &date, SolarEvent::Sunset).unwrap();
+        let set_3000 = solar_event_time(&summit, &date, SolarEvent::Sunset).unwrap();
+
+        // ~1.6° of dip is worth roughly 8 minutes at this latitude near the equinox
+        let earlier = (rise_0 - rise_3000).num_minutes();
+        let later = (set_3000 - set_0).num_minutes();
+        assert!((5..=12).contains(&earlier), "sunrise shift {} min", earlier);
+        assert!((5..=12).contains(&later), "sunset shift {} min", later);
+
+        // Twilight is measured from the astronomical horizon
+        assert_eq!(
+            solar_event_time(&sea_level, &date, SolarEvent::CivilDawn),
+            solar_event_time(&summit, &date, SolarEvent::CivilDawn)
+        );
+    }
+
+    #[test]
+    fn is_sun_up_flips_at_elevated_sunset() {
+        let sea_level = Location::new(39.7392, -104.9903).unwrap();
+        let summit = Location::with_elevation(39.7392, -104.9903, 3000.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let set_0 = solar_event_time(&sea_level, &date, SolarEvent::Sunset).unwrap();
+        let set_3000 = solar_event_time(&summit, &date, SolarEvent::Sunset).unwrap();
+
+        // Between the two sunsets the sun is down at sea level but still up on the summit
+        let between = set_0 + (set_3000 - set_0) / 2;
+        assert!(!is_sun_up(&sea_level, &between));
+        assert!(is_sun_up(&summit, &between));
+
+        // The event search uses the noon declination, so allow it a minute of slack
+        assert!(is_sun_up(&summit, &(set_3000 - Duration::minutes(1))));
+        assert!(!is_sun_up(&summit, &(set_3000 + Duration::minutes(2))));
+    }
+
+    #[test]
+    fn cold_dense_air_shifts_sunrise() {
+        let location = Location::new(62.4540, -114.3718).unwrap(); // Yellowknife
+        let date = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+
+        let standard = solar_event_time_with_refraction(
+            &location,
+            &date,
+            SolarEvent::Sunrise,
+            RefractionParams::default(),
+        )
+        .unwrap();
+        let cold = RefractionParams {
+            temperature_c: -40.0,
+            pressure_hpa: 1050.0,
+        };
+        let cold_sunrise =
+            solar_event_time_with_refraction(&location, &date, SolarEvent::Sunrise, cold).unwrap();
+
+        // Stronger refraction lifts the sun into view earlier
+        let shift = (standard - cold_sunrise).num_seconds();
+        assert!((60..=150).contains(&shift), "sunrise shift {} s", shift);
+
+        // Standard conditions stay within seconds of the default -0.833° path
+        let default = solar_event_time(&location, &date, SolarEvent::Sunrise).unwrap();
+        assert!((default - standard).num_seconds().abs() <= 30);
+    }
+
+    #[test]
+    fn apparent_minus_mean_solar_time_is_equation_of_time() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let dt = Utc.with_ymd_and_hms(2025, 11, 10, 17, 30, 0).unwrap();
+        let apparent = local_apparent_solar_time(&location, &dt);
+        let mean = local_mean_solar_time(&location, &dt);
+        let diff_minutes = (apparent - mean).num_milliseconds() as f64 / 60_000.0;
+        assert!((diff_minutes - equation_of_time(&dt)).abs() < 1.0 / 60.0);
+        // 17:30 UTC minus 74.006° × 4 min = 12:33:58.6 mean time
+        assert_eq!(mean.format("%H:%M:%S").to_string(), "12:33:58");
+    }
+
+    #[test]
+    fn solar_hour_angle_is_zero_at_solar_noon() {
+        let location = Location::new(-33.8688, 151.2093).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        let noon = solar_noon(&location, &date);
+        assert!(solar_hour_angle(&location, &noon).abs() < 0.1);
+
+        let afternoon = solar_hour_angle(&location, &(noon + Duration::hours(2)));
+        assert!((afternoon - 30.0).abs() < 0.1);
+        let midnight = solar_hour_angle(&location, &(noon + Duration::hours(12)));
+        assert!((-180.0..180.0).contains(&midnight));
+    }
+
+    #[test]
+    fn air_mass_is_one_at_zenith() {
+        // Equator at the March 2025 equinox: the sun passes within ~0.1° of the zenith
+        let location = Location::new(0.0, 0.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        let noon = solar_noon(&location, &date);
+        let zenith = air_mass(&location, &noon).unwrap();
+        assert!((zenith - 1.0).abs() < 0.01, "air mass {}", zenith);
+
+        let morning = air_mass(&location, &(noon - Duration::hours(5))).unwrap();
+        assert!(morning > 3.0);
+        assert!(air_mass(&location, &(noon + Duration::hours(12))).is_none());
+    }
+
+    #[test]
+    fn clear_sky_irradiance_peaks_at_noon() {
+        let location = Location::new(0.0, 0.0).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 3, 20, 0, 0, 0).unwrap();
+        let noon = solar_noon(&location, &date);
+        let at_noon = clear_sky_irradiance(&location, &noon);
+        let early = clear_sky_irradiance(&location, &(noon - Duration::hours(5)));
+        assert!((at_noon - 947.0).abs() < 5.0, "noon irradiance {}", at_noon);
+        assert!(at_noon > early && early > 0.0);
+        assert_eq!(clear_sky_irradiance(&location, &(noon + Duration::hours(12))), 0.0);
+    }
+
+    #[test]
+    fn sunrise_and_sunset_countdowns_split_the_day_at_noon() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 6, 21, 0, 0, 0).unwrap();
+        let noon = solar_noon(&location, &date);
+        let half_day = day_length(&location, &date).unwrap().num_seconds() as f64 / 2.0;
+
+        let since = time_since_sunrise(&location, &noon).unwrap().num_seconds() as f64;
+        let until = time_until_sunset(&location, &noon).unwrap().num_seconds() as f64;
+        assert!(since > 0.0 && until > 0.0);
+        assert!((since - half_day).abs() < 120.0, "since sunrise {}s", since);
+        assert!((until - half_day).abs() < 120.0, "until sunset {}s", until);
+
+        // Both are undefined once the sun has set
+        let night = noon + Duration::hours(10);
+        assert!(time_until_sunset(&location, &night).is_none());
+        assert!(time_since_sunrise(&location, &night).is_none());
+    }
+
+    #[test]
+    fn countdowns_use_the_elevated_horizon() {
+        let sea_level = Location::new(39.7392, -104.9903).unwrap();
+        let summit = Location::with_elevation(39.7392, -104.9903, 3000.0).unwrap();
+        let tz: chrono_tz::Tz = "America/Denver".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let set_0 = solar_event_time(&sea_level, &date, SolarEvent::Sunset).unwrap();
+        let set_3000 = solar_event_time(&summit, &date, SolarEvent::Sunset).unwrap();
+
+        // Past sea-level sunset, but minutes before the sun drops below the dipped horizon
+        let dt = set_3000 - Duration::minutes(3);
+        assert!(dt > set_0);
+        assert_eq!(time_until_sunset(&summit, &dt), Some(Duration::minutes(3)));
+        let rise_3000 = solar_event_time(&summit, &date, SolarEvent::Sunrise).unwrap();
+        assert_eq!(time_since_sunrise(&summit, &dt), Some(dt - rise_3000));
+    }
+
+    #[test]
+    fn equation_of_time_known_2025_values() {
+        // Almanac values at 12:00 UTC, in minutes
+        let cases = [
+            ((2025, 2, 11), -14.0 - 13.0 / 60.0), // -14m13s
+            ((2025, 4, 15), 2.0 / 60.0),          // +0m02s
+            ((2025, 6, 13), -9.0 / 60.0),         // -0m09s
+            ((2025, 11, 3), 16.0 + 29.0 / 60.0),  // +16m29s
+        ];
+        for ((y, m, d), expected) in cases {
+            let dt = Utc.with_ymd_and_hms(y, m, d, 12, 0, 0).unwrap();
+            let got = equation_of_time(&dt);
+            assert!(
+                (got - expected).abs() * 60.0 <= 15.0,
+                "{}-{:02}-{:02}: got {:.3} min, expected {:.3} min",
+                y,
+                m,
+                d,
+                got,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn declination_spans_obliquity_over_year() {
+        let location = Location::new(51.4769, 0.0).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+
+        let (mut min_dec, mut max_dec) = (f64::MAX, f64::MIN);
+        for day in 0..365 {
+            let pos = solar_position(&location, &(start + Duration::days(day)));
+            min_dec = min_dec.min(pos.declination);
+            max_dec = max_dec.max(pos.declination);
+            assert!((0.0..360.0).contains(&pos.right_ascension));
+        }
+
+        assert!((max_dec - 23.44).abs() < 0.05, "max declination {}", max_dec);
+        assert!((min_dec + 23.44).abs() < 0.05, "min declination {}", min_dec);
+
+        // Near the March equinox the sun sits close to RA 0°
+        let equinox = Utc.with_ymd_and_hms(2025, 3, 20, 9, 0, 0).unwrap();
+        let ra = solar_position(&location, &equinox).right_ascension;
+        assert!(!(0.5..=359.5).contains(&ra), "equinox RA {}", ra);
+    }
+
+    #[test]
+    fn day_length_longer_at_june_solstice() {
+        let location = Location::new(45.0, 7.7).unwrap();
+        let june = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let december = Utc.with_ymd_and_hms(2025, 12, 21, 12, 0, 0).unwrap();
+
+        let summer = day_length(&location, &june).unwrap();
+        let winter = day_length(&location, &december).unwrap();
+        assert!(summer > winter);
+        assert!(summer.num_minutes() > 15 * 60);
+        assert!(winter.num_minutes() < 9 * 60);
+
+        // Sunset after UTC midnight: daylight must still be measured forward
+        let honolulu = Location::new(21.3069, -157.8583).unwrap();
+        let length = day_length(&honolulu, &june).unwrap();
+        assert!((13 * 60..14 * 60).contains(&length.num_minutes()));
+
+        // Polar night
+        let svalbard = Location::new(78.2232, 15.6267).unwrap();
+        assert!(day_length(&svalbard, &december).is_none());
+    }
+
+    #[test]
+    fn golden_and_blue_hour_ordering() {
+        let location = Location::new(48.8566, 2.3522).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 9, 15, 12, 0, 0).unwrap();
+        let at = |event| solar_event_time(&location, &date, event).unwrap();
+
+        // Morning: blue hour, then golden hour straddling sunrise
+        assert!(at(SolarEvent::CivilDawn) <= at(SolarEvent::BlueHourStart));
+        assert!(at(SolarEvent::BlueHourStart) < at(SolarEvent::BlueHourEnd));
+        assert_eq!(at(SolarEvent::BlueHourEnd), at(SolarEvent::GoldenHourStart));
+        assert!(at(SolarEvent::GoldenHourStart) < at(SolarEvent::Sunrise));
+        assert!(at(SolarEvent::GoldenHourEnd) > at(SolarEvent::Sunrise));
+
+        // Evening mirrors the morning around sunset
+        assert!(at(SolarEvent::EveningGoldenHourStart) < at(SolarEvent::Sunset));
+        assert!(at(SolarEvent::EveningGoldenHourEnd) > at(SolarEvent::Sunset));
+        assert_eq!(
+            at(SolarEvent::EveningGoldenHourEnd),
+            at(SolarEvent::EveningBlueHourStart)
+        );
+        assert!(at(SolarEvent::EveningBlueHourEnd) <= at(SolarEvent::CivilDusk));
+    }
+
+    #[test]
+    fn raised_horizon_shortens_the_day() {
+        let location = Location::new(46.5197, 6.6323).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 5, 1, 12, 0, 0).unwrap();
+
+        let flat_rise = solar_event_time_at_altitude(&location, &date, SolarEvent::Sunrise, 0.0);
+        assert_eq!(flat_rise, solar_event_time(&location, &date, SolarEvent::Sunrise));
+
+        let rise = solar_event_time_at_altitude(&location, &date, SolarEvent::Sunrise, 10.0);
+        let set = solar_event_time_at_altitude(&location, &date, SolarEvent::Sunset, 10.0);
+        let flat_set = solar_event_time(&location, &date, SolarEvent::Sunset);
+
+        assert!(rise.unwrap() > flat_rise.unwrap());
+        assert!(set.unwrap() < flat_set.unwrap());
+        // 10° of ridge costs well over half an hour at each end at this latitude
+        assert!((rise.unwrap() - flat_rise.unwrap()).num_minutes() > 40);
+    }
+
+    #[test]
+    fn polar_day_and_night_are_classified() {
+        let location = Location::new(78.2232, 15.6267).unwrap(); // Longyearbyen
+        let june = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let december = Utc.with_ymd_and_hms(2025, 12, 21, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            solar_event_status(&location, &june, SolarEvent::Sunrise),
+            SolarEventResult::AlwaysUp
+        );
+        assert_eq!(
+            solar_event_status(&location, &december, SolarEvent::Sunset),
+            SolarEventResult::AlwaysDown
+        );
+
+        let equinox = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let status = solar_event_status(&location, &equinox, SolarEvent::Sunrise);
+        assert_eq!(
+            status.time(),
+            solar_event_time(&location, &equinox, SolarEvent::Sunrise)
+        );
+    }
+
+    #[test]
+    fn sunrise_azimuth_tracks_season() {
+        let location = Location::new(40.0, -105.0).unwrap();
+        let equinox = Utc.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+
+        let equinox_az = solar_event_azimuth(&location, &equinox, SolarEvent::Sunrise).unwrap();
+        let summer_az = solar_event_azimuth(&location, &summer, SolarEvent::Sunrise).unwrap();
+        let summer_set = solar_event_azimuth(&location, &summer, SolarEvent::Sunset).unwrap();
+
+        assert!((equinox_az - 90.0).abs() < 2.0, "equinox sunrise az {}", equinox_az);
+        assert!(summer_az < 65.0, "summer sunrise az {}", summer_az);
+        assert!(summer_set > 295.0, "summer sunset az {}", summer_set);
+
+        let svalbard = Location::new(78.2232, 15.6267).unwrap();
+        assert!(solar_event_azimuth(&svalbard, &summer, SolarEvent::Sunrise).is_none());
+    }
+
+    #[test]
+    fn shadow_ratio_is_unity_at_45_degrees() {
+        let location = Location::new(40.0, -105.0).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+
+        // Find the minute of the morning when the sun is closest to 45°
+        let closest = (0..240)
+            .map(|m| start + Duration::minutes(m))
+            .min_by(|a, b| {
+                let da = (solar_position(&location, a).altitude - 45.0).abs();
+                let db = (solar_position(&location, b).altitude - 45.0).abs();
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap();
+
+        let ratio = shadow_length_ratio(&location, &closest).unwrap();
+        assert!((ratio - 1.0).abs() < 0.01, "ratio {}", ratio);
+
+        let night = Utc.with_ymd_and_hms(2025, 6, 21, 8, 0, 0).unwrap();
+        assert!(shadow_length_ratio(&location, &night).is_none());
+    }
 }
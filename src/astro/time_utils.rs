@@ -1,21 +1,39 @@
 // Time utilities for astronomical calculations
 
-use chrono::{DateTime, Duration, TimeZone};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 
-/// Format duration with seconds as detailed string
+/// Julian Day of the Unix epoch (1970-01-01 00:00 UTC)
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Format a countdown as a detailed string, e.g. `"02:30:15 from now"`.
+///
+/// Durations of a day or more switch to days, hours and minutes
+/// (`"1d 06h 00m from now"`). Past durations use the same digits with an `ago`
+/// suffix instead of a sign. The result is at most [`DETAILED_DURATION_WIDTH`]
+/// characters, so callers can pad it to a fixed column.
 pub fn format_duration_detailed(duration: Duration) -> String {
     let total_secs = duration.num_seconds().abs();
-    let hours = total_secs / 3600;
+    let days = total_secs / 86_400;
+    let hours = (total_secs % 86_400) / 3600;
     let minutes = (total_secs % 3600) / 60;
     let seconds = total_secs % 60;
 
+    let magnitude = if days > 0 {
+        format!("{}d {:02}h {:02}m", days, hours, minutes)
+    } else {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    };
+
     if duration.num_seconds() < 0 {
-        format!("{:02}:{:02}:{:02} ago", hours, minutes, seconds)
+        format!("{} ago", magnitude)
     } else {
-        format!("{:02}:{:02}:{:02} from now", hours, minutes, seconds)
+        format!("{} from now", magnitude)
     }
 }
 
+/// Widest [`format_duration_detailed`] output for durations under ten days
+pub const DETAILED_DURATION_WIDTH: usize = 19;
+
 /// Calculate time until an event
 pub fn time_until<T: TimeZone>(from: &DateTime<T>, to: &DateTime<T>) -> Duration
 where
@@ -24,6 +42,139 @@ where
     to.clone().signed_duration_since(from.clone())
 }
 
+/// Convert a Julian Day (UT) to a UTC DateTime, rounded to the millisecond.
+///
+/// Inverse of [`julian_day`](super::julian_day): counts days from the Unix epoch,
+/// so it follows chrono's proleptic Gregorian calendar with no jump at 1582.
+/// Returns `None` for NaN, infinities and Julian Days outside chrono's range
+/// (roughly ±262,000 years).
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use solunatus::astro::{julian_day, time_utils::jd_to_utc};
+///
+/// let dt = Utc.with_ymd_and_hms(2025, 10, 7, 3, 47, 0).unwrap();
+/// assert_eq!(jd_to_utc(julian_day(&dt)), Some(dt));
+/// assert_eq!(jd_to_utc(f64::NAN), None);
+/// ```
+pub fn jd_to_utc(jd: f64) -> Option<DateTime<Utc>> {
+    // Round once, to the millisecond, so e.g. 23:59:59.9999 carries into the next day
+    let millis = ((jd - UNIX_EPOCH_JD) * 86_400_000.0).round();
+    // Also rejects NaN, which `as i64` would silently turn into 0
+    if !(i64::MIN as f64..i64::MAX as f64).contains(&millis) {
+        return None;
+    }
+    Utc.timestamp_millis_opt(millis as i64).single()
+}
+
+/// Modified Julian Day (JD − 2400000.5), counted from 1858-11-17 00:00 UT.
+pub fn modified_julian_day<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    super::julian_day(dt) - 2_400_000.5
+}
+
+/// Julian Ephemeris Day (TT): the Julian Day plus [`delta_t_seconds`].
+pub fn julian_ephemeris_day<T: TimeZone>(dt: &DateTime<T>) -> f64 {
+    let jd = super::julian_day(dt);
+    let year = 2000.0 + (jd - 2_451_545.0) / 365.25;
+    jd + delta_t_seconds(year) / 86_400.0
+}
+
+/// ΔT = TT - UT in seconds for a decimal year.
+///
+/// Uses the Espenak & Meeus polynomial approximations (NASA eclipse canon). For
+/// 2005–2050 that is the published extrapolation `62.92 + 0.32217 t + 0.005589 t²`
+/// (t = year - 2000), which runs a few seconds above recent IERS observations.
+pub fn delta_t_seconds(year: f64) -> f64 {
+    let long_term = |y: f64| {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+
+    match year {
+        y if y < -500.0 => long_term(y),
+        y if y < 500.0 => {
+            let u = y / 100.0;
+            10583.6 - 1014.41 * u + 33.78311 * u.powi(2) - 5.952053 * u.powi(3)
+                - 0.1798452 * u.powi(4)
+                + 0.022174192 * u.powi(5)
+                + 0.0090316521 * u.powi(6)
+        }
+        y if y < 1600.0 => {
+            let u = (y - 1000.0) / 100.0;
+            1574.2 - 556.01 * u + 71.23472 * u.powi(2) + 0.319781 * u.powi(3)
+                - 0.8503463 * u.powi(4)
+                - 0.005050998 * u.powi(5)
+                + 0.0083572073 * u.powi(6)
+        }
+        y if y < 1700.0 => {
+            let t = y - 1600.0;
+            120.0 - 0.9808 * t - 0.01532 * t.powi(2) + t.powi(3) / 7129.0
+        }
+        y if y < 1800.0 => {
+            let t = y - 1700.0;
+            8.83 + 0.1603 * t - 0.0059285 * t.powi(2) + 0.00013336 * t.powi(3)
+                - t.powi(4) / 1174000.0
+        }
+        y if y < 1860.0 => {
+            let t = y - 1800.0;
+            13.72 - 0.332447 * t + 0.0068612 * t.powi(2) + 0.0041116 * t.powi(3)
+                - 0.00037436 * t.powi(4)
+                + 0.0000121272 * t.powi(5)
+                - 0.0000001699 * t.powi(6)
+                + 0.000000000875 * t.powi(7)
+        }
+        y if y < 1900.0 => {
+            let t = y - 1860.0;
+            7.62 + 0.5737 * t - 0.251754 * t.powi(2) + 0.01680668 * t.powi(3)
+                - 0.0004473624 * t.powi(4)
+                + t.powi(5) / 233174.0
+        }
+        y if y < 1920.0 => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        y if y < 1941.0 => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        y if y < 1961.0 => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if y < 1986.0 => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if y < 2005.0 => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        y if y < 2050.0 => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        y if y < 2150.0 => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
+
+/// Convert a Julian Ephemeris Day (TT) to UTC using [`delta_t_seconds`]
+///
+/// # Panics
+///
+/// Panics where [`jd_to_utc`] returns `None`: non-finite input or a date outside
+/// chrono's range.
+pub fn jde_to_utc(jde: f64) -> DateTime<Utc> {
+    let year = 2000.0 + (jde - 2_451_545.0) / 365.25;
+    jd_to_utc(jde - delta_t_seconds(year) / 86_400.0).expect("JDE outside chrono's date range")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,6 +192,23 @@ mod tests {
         assert_eq!(format_duration_detailed(dur), "01:05:09 ago");
     }
 
+    #[test]
+    fn test_format_duration_detailed_days() {
+        let dur = Duration::hours(30);
+        assert_eq!(format_duration_detailed(dur), "1d 06h 00m from now");
+        assert_eq!(format_duration_detailed(-dur), "1d 06h 00m ago");
+        let widest = Duration::days(9) + Duration::hours(23) + Duration::minutes(59);
+        assert_eq!(format_duration_detailed(widest).len(), DETAILED_DURATION_WIDTH);
+    }
+
+    #[test]
+    fn test_format_duration_detailed_past_minutes() {
+        let dur = Duration::minutes(-90);
+        assert_eq!(format_duration_detailed(dur), "01:30:00 ago");
+        // Under one second in the past still reads as now, not "-00:00:00"
+        assert_eq!(format_duration_detailed(Duration::milliseconds(-400)), "00:00:00 from now");
+    }
+
     #[test]
     fn test_time_until() {
         let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
@@ -48,4 +216,85 @@ mod tests {
         let diff = time_until(&start, &end);
         assert_eq!(diff, Duration::hours(1));
     }
+
+    #[test]
+    fn jd_to_utc_rounds_and_rolls_over() {
+        use chrono::Timelike;
+
+        // Day fraction 0.9999942 sits half a second before midnight
+        let dt = jd_to_utc(2460000.4999942).unwrap();
+        assert_eq!(dt.date_naive(), chrono::NaiveDate::from_ymd_opt(2023, 2, 24).unwrap());
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (23, 59, 59));
+        assert_eq!(dt.timestamp_subsec_millis(), 499);
+
+        // A quarter millisecond before midnight rolls into the next day rather than
+        // emitting second=60
+        let dt = jd_to_utc(2460000.499999997);
+        assert_eq!(dt, Some(Utc.with_ymd_and_hms(2023, 2, 25, 0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn jd_to_utc_is_continuous_across_gregorian_reform() {
+        let mut previous = jd_to_utc(2_299_150.5).unwrap();
+        for jd in 2_299_151..2_299_170 {
+            let dt = jd_to_utc(jd as f64 + 0.5).unwrap();
+            assert_eq!(dt - previous, Duration::days(1), "{dt}");
+            previous = dt;
+        }
+        assert_eq!(
+            jd_to_utc(2_299_160.5),
+            Some(Utc.with_ymd_and_hms(1582, 10, 15, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn jd_to_utc_rejects_unrepresentable_days() {
+        let max = crate::astro::julian_day(&DateTime::<Utc>::MAX_UTC);
+        let min = crate::astro::julian_day(&DateTime::<Utc>::MIN_UTC);
+        assert!(jd_to_utc(max - 1.0).is_some());
+        assert!(jd_to_utc(min + 1.0).is_some());
+        assert_eq!(jd_to_utc(max + 1.0), None);
+        assert_eq!(jd_to_utc(min - 1.0), None);
+        assert_eq!(jd_to_utc(1e300), None);
+        assert_eq!(jd_to_utc(f64::NAN), None);
+        assert_eq!(jd_to_utc(f64::INFINITY), None);
+        assert_eq!(jd_to_utc(f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn jd_to_utc_round_trips_julian_day() {
+        for dt in [
+            Utc.with_ymd_and_hms(-1000, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(0, 3, 1, 6, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1500, 6, 15, 18, 30, 0).unwrap(),
+            Utc.with_ymd_and_hms(1582, 10, 15, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 59).unwrap(),
+            Utc.with_ymd_and_hms(2025, 10, 7, 3, 47, 0).unwrap(),
+        ] {
+            assert_eq!(jd_to_utc(crate::astro::julian_day(&dt)), Some(dt));
+        }
+    }
+
+    #[test]
+    fn modified_and_ephemeris_julian_days() {
+        let j2000 = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(modified_julian_day(&j2000), 51544.5);
+
+        let dt = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let offset_seconds = (julian_ephemeris_day(&dt) - crate::astro::julian_day(&dt)) * 86_400.0;
+        assert!((offset_seconds - delta_t_seconds(2025.41)).abs() < 0.1);
+        assert!((74.0..76.0).contains(&offset_seconds));
+    }
+
+    #[test]
+    fn delta_t_matches_espenak_meeus() {
+        assert!((delta_t_seconds(2025.0) - 74.47).abs() < 0.01);
+        assert!((delta_t_seconds(2000.0) - 63.86).abs() < 0.01);
+        assert!((delta_t_seconds(1900.0) + 2.79).abs() < 0.01);
+        // Continuous across the joins either side of the 2005–2050 polynomial
+        assert!((delta_t_seconds(2004.999) - delta_t_seconds(2005.0)).abs() < 0.1);
+        assert!((delta_t_seconds(2049.999) - delta_t_seconds(2050.0)).abs() < 0.1);
+    }
 }
@@ -13,6 +13,7 @@
 
 use std::f64::consts::PI;
 use std::fmt;
+use std::ops::{Add, Mul, Sub};
 
 /// Conversion factor from degrees to radians.
 pub const DEG_TO_RAD: f64 = PI / 180.0;
@@ -70,11 +71,59 @@ impl Degrees {
     pub fn tan(self) -> f64 {
         self.0.to_radians().tan()
     }
+
+    /// Split into whole degrees, whole arcminutes and arcseconds.
+    ///
+    /// The sign is carried by the degrees component, so angles between -1° and 0°
+    /// come back as `(0, m, s)`; use [`Display`](fmt::Display) when the sign matters.
+    pub fn to_dms(self) -> (i32, u32, f64) {
+        let abs = self.0.abs();
+        let degrees = abs.trunc();
+        let minutes = ((abs - degrees) * 60.0).trunc();
+        let seconds = ((abs - degrees) * 60.0 - minutes) * 60.0;
+        let sign = if self.0 < 0.0 { -1 } else { 1 };
+        (sign * degrees as i32, minutes as u32, seconds)
+    }
 }
 
+/// Formats as degrees, arcminutes and whole arcseconds, e.g. `40°42'46"`.
 impl fmt::Display for Degrees {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}°", self.0)
+        // Round on total arcseconds so 59.6" carries into the minutes
+        let total = (self.0.abs() * 3600.0).round() as u64;
+        let sign = if self.0 < 0.0 && total > 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{}°{:02}'{:02}\"",
+            sign,
+            total / 3600,
+            (total % 3600) / 60,
+            total % 60
+        )
+    }
+}
+
+impl Add for Degrees {
+    type Output = Degrees;
+
+    fn add(self, rhs: Degrees) -> Degrees {
+        Degrees(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Degrees {
+    type Output = Degrees;
+
+    fn sub(self, rhs: Degrees) -> Degrees {
+        Degrees(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Degrees {
+    type Output = Degrees;
+
+    fn mul(self, rhs: f64) -> Degrees {
+        Degrees(self.0 * rhs)
     }
 }
 
@@ -154,9 +203,9 @@ impl From<Radians> for Degrees {
 pub struct Latitude(f64);
 
 impl Latitude {
-    pub fn new(degrees: f64) -> Result<Self, String> {
+    pub fn new(degrees: f64) -> Result<Self, super::LocationError> {
         if !(-90.0..=90.0).contains(&degrees) {
-            Err(format!("Invalid latitude: {} (must be -90 to 90)", degrees))
+            Err(super::LocationError::LatitudeOutOfRange(degrees))
         } else {
             Ok(Self(degrees))
         }
@@ -182,7 +231,7 @@ impl Latitude {
 
 impl fmt::Display for Latitude {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}° {}", self.0.abs(), if self.0 >= 0.0 { "N" } else { "S" })
+        write!(f, "{} {}", Degrees(self.0.abs()), if self.0 >= 0.0 { "N" } else { "S" })
     }
 }
 
@@ -194,9 +243,9 @@ impl fmt::Display for Latitude {
 pub struct Longitude(f64);
 
 impl Longitude {
-    pub fn new(degrees: f64) -> Result<Self, String> {
+    pub fn new(degrees: f64) -> Result<Self, super::LocationError> {
         if !(-180.0..=180.0).contains(&degrees) {
-            Err(format!("Invalid longitude: {} (must be -180 to 180)", degrees))
+            Err(super::LocationError::LongitudeOutOfRange(degrees))
         } else {
             Ok(Self(degrees))
         }
@@ -222,7 +271,7 @@ impl Longitude {
 
 impl fmt::Display for Longitude {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}° {}", self.0.abs(), if self.0 >= 0.0 { "E" } else { "W" })
+        write!(f, "{} {}", Degrees(self.0.abs()), if self.0 >= 0.0 { "E" } else { "W" })
     }
 }
 
@@ -335,6 +384,38 @@ mod tests {
         assert_eq!(Degrees::new(-10.0).normalized().value(), 350.0);
     }
 
+    #[test]
+    fn test_degrees_dms() {
+        let (d, m, s) = Degrees::new(40.7128).to_dms();
+        assert_eq!((d, m), (40, 42));
+        assert!((s - 46.08).abs() < 1e-6);
+
+        let (d, m, s) = Degrees::new(-74.006).to_dms();
+        assert_eq!((d, m), (-74, 0));
+        assert!((s - 21.6).abs() < 1e-6);
+
+        assert_eq!(Degrees::new(40.7128).to_string(), "40°42'46\"");
+        assert_eq!(Degrees::new(-0.5).to_string(), "-0°30'00\"");
+        // 59.9" rounds up into the next minute
+        assert_eq!(Degrees::new(10.0 + 59.9 / 3600.0).to_string(), "10°01'00\"");
+    }
+
+    #[test]
+    fn test_degrees_arithmetic() {
+        let sum = Degrees::new(10.5) + Degrees::new(0.25);
+        assert_eq!(sum, Degrees::new(10.75));
+        assert_eq!(sum - Degrees::new(0.75), Degrees::new(10.0));
+        assert_eq!(Degrees::new(1.5) * 2.0, Degrees::new(3.0));
+    }
+
+    #[test]
+    fn test_hemisphere_formatting() {
+        assert_eq!(Latitude::new(40.7128).unwrap().to_string(), "40°42'46\" N");
+        assert_eq!(Latitude::new(-33.8688).unwrap().to_string(), "33°52'08\" S");
+        assert_eq!(Longitude::new(-74.006).unwrap().to_string(), "74°00'22\" W");
+        assert_eq!(Longitude::new(151.2093).unwrap().to_string(), "151°12'33\" E");
+    }
+
     #[test]
     fn test_latitude_validation() {
         assert!(Latitude::new(45.0).is_ok());
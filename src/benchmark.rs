@@ -1,12 +1,15 @@
-// Benchmark module - cycles through all cities calculating astronomical data
+//! Benchmark module - cycles through all cities calculating astronomical data
+//!
+//! [`run_benchmark`] returns structured timings; [`generate_html_report`] renders them.
 
 use crate::astro::*;
-use crate::city::CityDatabase;
+use crate::city::{City, CityDatabase};
 use chrono::{DateTime, Datelike, Utc};
 use chrono_tz::Tz;
+use serde::Serialize;
 use std::time::Instant;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BenchmarkResult {
     pub total_cities: usize,
     pub successful: usize,
@@ -17,10 +20,13 @@ pub struct BenchmarkResult {
     pub max_duration_ms: u128,
     pub cities_per_second: f64,
     pub failed_cities: Vec<String>,
+    /// Per-city timings in benchmark order
+    pub cities: Vec<CityBenchmark>,
 }
 
-#[derive(Debug, Clone)]
-struct CityBenchmark {
+/// Timing for one city's full set of calculations
+#[derive(Debug, Clone, Serialize)]
+pub struct CityBenchmark {
     pub city_name: String,
     pub duration_ms: u128,
     pub success: bool,
@@ -42,11 +48,16 @@ pub fn run_benchmark() -> BenchmarkResult {
                 max_duration_ms: 0,
                 cities_per_second: 0.0,
                 failed_cities: vec![format!("Failed to load city database: {}", e)],
+                cities: Vec::new(),
             };
         }
     };
 
-    let cities = db.cities();
+    run_benchmark_for(db.cities())
+}
+
+/// Run the benchmark over the given cities
+pub fn run_benchmark_for(cities: &[City]) -> BenchmarkResult {
     let total_cities = cities.len();
     let mut results = Vec::with_capacity(total_cities);
 
@@ -133,6 +144,7 @@ pub fn run_benchmark() -> BenchmarkResult {
         max_duration_ms,
         cities_per_second,
         failed_cities,
+        cities: results,
     }
 }
 
@@ -252,3 +264,29 @@ pub fn generate_html_report(result: &BenchmarkResult) -> String {
 
     html
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benchmark_over_a_few_cities_fills_result() {
+        let db = CityDatabase::load().unwrap();
+        let mut cities: Vec<City> = db.cities().iter().take(3).cloned().collect();
+        cities.push(City {
+            tz: "Not/AZone".to_string(),
+            ..cities[0].clone()
+        });
+
+        let result = run_benchmark_for(&cities);
+        assert_eq!(result.total_cities, 4);
+        assert_eq!(result.successful, 3);
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.cities.len(), 4);
+        assert_eq!(result.cities[1].city_name, cities[1].name);
+        assert!(result.cities[..3].iter().all(|c| c.success && c.error.is_none()));
+        assert!(result.failed_cities[0].starts_with(&cities[3].name));
+        assert!(result.min_duration_ms <= result.max_duration_ms);
+        assert!(result.total_duration_ms >= result.max_duration_ms);
+    }
+}
@@ -158,6 +158,9 @@ fn main() {
                 start_date,
                 end_date,
                 calendar_optimized::CalendarFormat::Html,
+                &calendar_optimized::CalendarColumns::default(),
+                None,
+                None,
             )
         },
     );
@@ -185,6 +188,9 @@ fn main() {
                 start_date,
                 end_date,
                 calendar_optimized::CalendarFormat::Json,
+                &calendar_optimized::CalendarColumns::default(),
+                None,
+                None,
             )
         },
     );
@@ -1,11 +1,12 @@
 //! Calendar generation module.
 //!
-//! Generates astronomical calendars in HTML or JSON format for arbitrary date ranges.
+//! Generates astronomical calendars in HTML, JSON, Markdown, CSV or iCalendar format for
+//! arbitrary date ranges.
 //! Supports historical dates (negative years for BCE) and future dates.
 //!
 //! # Features
 //!
-//! - HTML and JSON output formats
+//! - HTML, JSON, Markdown, CSV and iCalendar (`.ics`) output formats
 //! - Per-day solar events (sunrise, sunset, solar noon, twilight)
 //! - Per-day lunar events (moonrise, moonset, phase)
 //! - Supports BCE dates (year -999 = 1000 BCE)
@@ -28,6 +29,12 @@ pub enum CalendarFormat {
     Html,
     /// JSON format for programmatic access
     Json,
+    /// GitHub-flavored Markdown tables, one per month
+    Markdown,
+    /// CSV format (RFC 4180) with one row per day
+    Csv,
+    /// iCalendar (RFC 5545) format for import into calendar applications
+    Ics,
 }
 
 #[derive(Debug)]
@@ -92,6 +99,80 @@ struct CalendarJson<'a> {
     days: Vec<CalendarDayJson>,
 }
 
+/// Options controlling calendar generation.
+///
+/// Build with [`CalendarOptions::new`] and adjust with the `with_*` methods;
+/// unspecified settings fall back to their defaults (HTML output, no city name).
+#[derive(Debug, Clone)]
+pub struct CalendarOptions {
+    pub location: Location,
+    pub timezone: Tz,
+    pub city_name: Option<String>,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub format: CalendarFormat,
+}
+
+impl CalendarOptions {
+    /// Create options for the inclusive range `start..=end` with default settings.
+    pub fn new(location: Location, timezone: Tz, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            location,
+            timezone,
+            city_name: None,
+            start,
+            end,
+            format: CalendarFormat::Html,
+        }
+    }
+
+    /// Set the city name shown in the calendar header.
+    pub fn with_city(mut self, city_name: impl Into<String>) -> Self {
+        self.city_name = Some(city_name.into());
+        self
+    }
+
+    /// Set the output format.
+    pub fn with_format(mut self, format: CalendarFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Generate a calendar from the given options.
+pub fn generate(options: &CalendarOptions) -> Result<String> {
+    let CalendarOptions {
+        location,
+        timezone,
+        start,
+        end,
+        format,
+        ..
+    } = options;
+    let city_name = options.city_name.as_deref();
+
+    validate_range(*start, *end)?;
+
+    let records = collect_records(location, timezone, *start, *end)?;
+
+    match format {
+        CalendarFormat::Html => Ok(render_html(
+            location, timezone, city_name, *start, *end, &records,
+        )),
+        CalendarFormat::Json => render_json(location, timezone, city_name, *start, *end, &records),
+        CalendarFormat::Markdown => Ok(render_markdown(
+            location, timezone, city_name, *start, *end, &records,
+        )),
+        CalendarFormat::Csv => Ok(render_csv(&records)),
+        CalendarFormat::Ics => Ok(render_ics(
+            location, timezone, city_name, *start, *end, &records,
+        )),
+    }
+}
+
+/// Generate a calendar from positional arguments.
+///
+/// Thin wrapper over [`generate`]; prefer [`CalendarOptions`] for new code.
 pub fn generate_calendar(
     location: &Location,
     timezone: &Tz,
@@ -100,16 +181,9 @@ pub fn generate_calendar(
     end: NaiveDate,
     format: CalendarFormat,
 ) -> Result<String> {
-    validate_range(start, end)?;
-
-    let records = collect_records(location, timezone, start, end)?;
-
-    match format {
-        CalendarFormat::Html => Ok(render_html(
-            location, timezone, city_name, start, end, &records,
-        )),
-        CalendarFormat::Json => render_json(location, timezone, city_name, start, end, &records),
-    }
+    let mut options = CalendarOptions::new(*location, *timezone, start, end).with_format(format);
+    options.city_name = city_name.map(str::to_string);
+    generate(&options)
 }
 
 fn validate_range(start: NaiveDate, end: NaiveDate) -> Result<()> {
@@ -241,6 +315,300 @@ fn render_json(
     })?)
 }
 
+fn render_markdown(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+    records: &[DailyRecord],
+) -> String {
+    let mut by_month: BTreeMap<(i32, u32), Vec<&DailyRecord>> = BTreeMap::new();
+    for record in records {
+        by_month
+            .entry((record.date.year(), record.date.month()))
+            .or_default()
+            .push(record);
+    }
+
+    let mut md = String::from("# Solunatus Astronomical Calendar\n\n");
+    if let Some(city) = city_name {
+        md.push_str(&escape_markdown(city));
+        md.push_str(" • ");
+    }
+    md.push_str(&format!(
+        "{} • {} • {}\n\n",
+        format_lat(location.latitude.value()),
+        format_lon(location.longitude.value()),
+        timezone.name()
+    ));
+    md.push_str(&format!("Range: {} → {}\n", start, end));
+
+    let time = |dt: Option<chrono::DateTime<Tz>>| dt.map_or("—".to_string(), format_time);
+
+    for ((year, month), days) in by_month {
+        md.push_str(&format!("\n## {} {}\n\n", month_name(month), year));
+        md.push_str("| Date | Sunrise | Sunset | Daylight | Civil Dawn | Civil Dusk ");
+        md.push_str("| Moonrise | Moonset | Lunar Phase |\n");
+        md.push_str("|---|---|---|---|---|---|---|---|---|\n");
+
+        for record in days {
+            let daylight = day_length_minutes(record)
+                .map(split_hours_minutes)
+                .map(|(hours, minutes)| format!("{hours:02} h {minutes:02} m"))
+                .unwrap_or_else(|| "—".to_string());
+            md.push_str(&format!(
+                "| {} {} | {} | {} | {} | {} | {} | {} | {} | {} {} ({:.1}%) |\n",
+                record.date.format("%b %d"),
+                record.weekday,
+                time(record.sunrise),
+                time(record.sunset),
+                daylight,
+                time(record.civil_dawn),
+                time(record.civil_dusk),
+                time(record.moonrise),
+                time(record.moonset),
+                record.phase_emoji,
+                escape_markdown(&record.phase_name),
+                (record.illumination * 1000.0).round() / 10.0
+            ));
+        }
+    }
+
+    md
+}
+
+fn render_csv(records: &[DailyRecord]) -> String {
+    let mut csv = csv_row(&CSV_HEADER.map(str::to_string));
+    for record in records {
+        let time = |dt: Option<chrono::DateTime<Tz>>| dt.map(format_time).unwrap_or_default();
+        csv.push_str(&csv_row(&[
+            record.date.to_string(),
+            record.weekday.to_string(),
+            time(record.sunrise),
+            time(record.sunset),
+            time(record.solar_noon),
+            time(record.civil_dawn),
+            time(record.civil_dusk),
+            time(record.moonrise),
+            time(record.moonset),
+            format!("{:.1}", record.illumination * 100.0),
+            record.phase_name.clone(),
+        ]));
+    }
+    csv
+}
+
+/// Column names of the CSV calendar, in order.
+pub(crate) const CSV_HEADER: [&str; 11] = [
+    "date",
+    "weekday",
+    "sunrise",
+    "sunset",
+    "solar_noon",
+    "civil_dawn",
+    "civil_dusk",
+    "moonrise",
+    "moonset",
+    "illumination_percent",
+    "phase",
+];
+
+/// Join fields into one CRLF-terminated CSV record, quoting per RFC 4180.
+pub(crate) fn csv_row(fields: &[String]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+fn render_ics(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+    records: &[DailyRecord],
+) -> String {
+    let mut events: Vec<IcsEvent> = records
+        .iter()
+        .flat_map(|record| {
+            IcsEvent::for_day(
+                record.date,
+                [
+                    record.civil_dawn,
+                    record.sunrise,
+                    record.solar_noon,
+                    record.sunset,
+                    record.civil_dusk,
+                    record.moonrise,
+                    record.moonset,
+                ],
+            )
+        })
+        .collect();
+    events.extend(IcsEvent::moon_phases(timezone, start, end));
+    events.sort_by_key(|event| event.time);
+
+    render_ics_events(location, city_name, &events)
+}
+
+/// A single timed event destined for an iCalendar `VEVENT`.
+pub(crate) struct IcsEvent {
+    pub date: NaiveDate,
+    pub slug: &'static str,
+    pub summary: &'static str,
+    pub time: chrono::DateTime<Tz>,
+}
+
+impl IcsEvent {
+    /// Daily events in the order civil dawn, sunrise, solar noon, sunset,
+    /// civil dusk, moonrise, moonset. Missing events are skipped.
+    pub(crate) fn for_day(
+        date: NaiveDate,
+        times: [Option<chrono::DateTime<Tz>>; 7],
+    ) -> impl Iterator<Item = IcsEvent> {
+        const KINDS: [(&str, &str); 7] = [
+            ("civil-dawn", "Civil dawn"),
+            ("sunrise", "Sunrise"),
+            ("solar-noon", "Solar noon"),
+            ("sunset", "Sunset"),
+            ("civil-dusk", "Civil dusk"),
+            ("moonrise", "Moonrise"),
+            ("moonset", "Moonset"),
+        ];
+
+        KINDS
+            .into_iter()
+            .zip(times)
+            .filter_map(move |((slug, summary), time)| {
+                time.map(|time| IcsEvent {
+                    date,
+                    slug,
+                    summary,
+                    time,
+                })
+            })
+    }
+
+    /// Primary moon phases whose local date falls within `start..=end`.
+    pub(crate) fn moon_phases(timezone: &Tz, start: NaiveDate, end: NaiveDate) -> Vec<IcsEvent> {
+        // Phases are found by UTC date; widen by a day so local-date edges are covered
+        let utc_start = start.pred_opt().unwrap_or(start);
+        let utc_end = end.succ_opt().unwrap_or(end);
+
+        moon::lunar_phases_in_range(utc_start, utc_end)
+            .into_iter()
+            .filter_map(|phase| {
+                let time = phase.datetime.with_timezone(timezone);
+                let date = time.date_naive();
+                let (slug, summary) = match phase.phase_type {
+                    moon::LunarPhaseType::NewMoon => ("new-moon", "New moon"),
+                    moon::LunarPhaseType::FirstQuarter => ("first-quarter", "First quarter"),
+                    moon::LunarPhaseType::FullMoon => ("full-moon", "Full moon"),
+                    moon::LunarPhaseType::LastQuarter => ("last-quarter", "Last quarter"),
+                };
+                (start..=end).contains(&date).then_some(IcsEvent {
+                    date,
+                    slug,
+                    summary,
+                    time,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Render events as an iCalendar document with UTC timestamps and CRLF line endings.
+pub(crate) fn render_ics_events(
+    location: &Location,
+    city_name: Option<&str>,
+    events: &[IcsEvent],
+) -> String {
+    let lat = location.latitude.value();
+    let lon = location.longitude.value();
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let place = match city_name {
+        Some(city) => city.to_string(),
+        None => format!("{}, {}", format_lat(lat), format_lon(lon)),
+    };
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Solunatus//Astronomical Calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_ics(&format!("Solunatus – {}", place))),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!(
+            "UID:{}-{}-{:.4}-{:.4}@solunatus",
+            event.date.format("%Y%m%d"),
+            event.slug,
+            lat,
+            lon
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART:{}",
+            event.time.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ")
+        ));
+        lines.push(format!("SUMMARY:{}", escape_ics(event.summary)));
+        lines.push(format!("GEO:{:.6};{:.6}", lat, lon));
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut ics = lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n");
+    ics.push_str("\r\n");
+    ics
+}
+
+/// Fold a content line at 75 octets (RFC 5545 §3.1), continuing with CRLF + space.
+///
+/// Splits only on UTF-8 character boundaries; continuation lines count the
+/// leading space toward their 75 octets.
+fn fold_ics_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_OCTETS * 3);
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > MAX_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(ch);
+        width += ch.len_utf8();
+    }
+    folded
+}
+
+fn escape_ics(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
 fn render_html(
     location: &Location,
     timezone: &Tz,
@@ -341,11 +709,8 @@ fn render_html(
             html.push_str(&format!(
                 "<td>{}</td>",
                 day_length_minutes(record)
-                    .map(|mins| format!(
-                        "{:02} h {:02} m",
-                        (mins / 60.0).floor() as i64,
-                        (mins % 60.0).round() as i64
-                    ))
+                    .map(split_hours_minutes)
+                    .map(|(hours, minutes)| format!("{hours:02} h {minutes:02} m"))
                     .unwrap_or_else(|| "—".to_string())
             ));
             html.push_str(&format!(
@@ -390,6 +755,13 @@ fn format_time(dt: chrono::DateTime<Tz>) -> String {
     dt.format("%H:%M").to_string()
 }
 
+/// Split a minute count into whole hours and minutes, rounding the total first so
+/// 719.6 minutes becomes 12 h 00 m rather than 11 h 60 m.
+pub(crate) fn split_hours_minutes(minutes: f64) -> (i64, i64) {
+    let total = minutes.round() as i64;
+    (total / 60, total % 60)
+}
+
 fn day_length_minutes(record: &DailyRecord) -> Option<f64> {
     match (record.sunrise, record.sunset) {
         (Some(rise), Some(set)) => {
@@ -422,6 +794,10 @@ fn month_name(month: u32) -> &'static str {
     }
 }
 
+fn escape_markdown(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('|', "\\|")
+}
+
 fn escape_html(input: &str) -> String {
     input
         .replace('&', "&amp;")
@@ -446,3 +822,201 @@ fn format_lon(lon: f64) -> String {
         format!("{:.4}° W", -lon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Drop the generation timestamp so outputs produced moments apart compare equal
+    fn without_timestamp(output: &str) -> String {
+        let mut text: String = output
+            .lines()
+            .filter(|line| !line.contains("generated_at_utc"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if let Some(start) = text.find("Generated ") {
+            let end = start + text[start..].find("</div>").unwrap();
+            text.replace_range(start..end, "");
+        }
+        text
+    }
+
+    #[test]
+    fn options_match_legacy_positional_call() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let timezone: Tz = "Europe/London".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 3, 25).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 4, 3).unwrap();
+
+        for format in [CalendarFormat::Json, CalendarFormat::Html] {
+            let legacy =
+                generate_calendar(&location, &timezone, Some("London"), start, end, format)
+                    .unwrap();
+            let options = CalendarOptions::new(location, timezone, start, end)
+                .with_city("London")
+                .with_format(format);
+            let built = generate(&options).unwrap();
+
+            assert_eq!(without_timestamp(&legacy), without_timestamp(&built));
+        }
+    }
+
+    #[test]
+    fn markdown_has_table_header_and_row_per_day() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let timezone: Tz = "Europe/London".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+
+        let md = generate_calendar(
+            &location,
+            &timezone,
+            Some("London"),
+            start,
+            end,
+            CalendarFormat::Markdown,
+        )
+        .unwrap();
+
+        assert!(md.contains("## January 2025"));
+        assert!(md.contains("## February 2025"));
+        let headers = md.lines().filter(|l| l.starts_with("| Date |")).count();
+        assert_eq!(headers, 2);
+
+        let rows: Vec<&str> = md
+            .lines()
+            .filter(|l| l.starts_with('|') && !l.starts_with("| Date |") && !l.starts_with("|---"))
+            .collect();
+        assert_eq!(rows.len(), 7);
+        assert!(rows[0].starts_with("| Jan 28 Tue |"));
+        for row in rows {
+            assert_eq!(row.matches(" | ").count(), 8);
+        }
+    }
+
+    #[test]
+    fn day_length_rounds_before_splitting() {
+        assert_eq!(split_hours_minutes(719.6), (12, 0));
+        assert_eq!(split_hours_minutes(719.4), (11, 59));
+        assert_eq!(split_hours_minutes(60.0), (1, 0));
+    }
+
+    #[test]
+    fn csv_row_quotes_special_characters() {
+        let row = csv_row(&[
+            "plain".to_string(),
+            "a,b".to_string(),
+            "say \"hi\"".to_string(),
+            String::new(),
+        ]);
+        assert_eq!(row, "plain,\"a,b\",\"say \"\"hi\"\"\",\r\n");
+    }
+
+    #[test]
+    fn ics_output_is_valid_vcalendar() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let timezone: Tz = "America/New_York".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 7).unwrap();
+
+        let records = collect_records(&location, &timezone, start, end).unwrap();
+        let expected: usize = records
+            .iter()
+            .map(|record| {
+                [
+                    record.civil_dawn,
+                    record.sunrise,
+                    record.solar_noon,
+                    record.sunset,
+                    record.civil_dusk,
+                    record.moonrise,
+                    record.moonset,
+                ]
+                .iter()
+                .filter(|time| time.is_some())
+                .count()
+            })
+            .sum::<usize>()
+            + 1; // first quarter, 2025-06-02 23:41 EDT
+
+        let ics = generate_calendar(
+            &location,
+            &timezone,
+            Some("New York"),
+            start,
+            end,
+            CalendarFormat::Ics,
+        )
+        .unwrap();
+
+        assert!(ics.ends_with("\r\n"));
+        let lines: Vec<&str> = ics.split("\r\n").filter(|l| !l.is_empty()).collect();
+        assert_eq!(lines.first(), Some(&"BEGIN:VCALENDAR"));
+        assert_eq!(lines.last(), Some(&"END:VCALENDAR"));
+        assert!(lines.contains(&"VERSION:2.0"));
+
+        let begins = lines.iter().filter(|l| **l == "BEGIN:VEVENT").count();
+        let ends = lines.iter().filter(|l| **l == "END:VEVENT").count();
+        assert_eq!(begins, expected);
+        assert_eq!(ends, expected);
+
+        let starts: Vec<&str> = lines
+            .iter()
+            .filter_map(|l| l.strip_prefix("DTSTART:"))
+            .collect();
+        assert_eq!(starts.len(), expected);
+        for value in &starts {
+            assert_eq!(value.len(), 16);
+            assert!(value.ends_with('Z'));
+            assert!(chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ").is_ok());
+        }
+
+        let mut uids: Vec<&str> = lines.iter().filter_map(|l| l.strip_prefix("UID:")).collect();
+        assert_eq!(uids.len(), expected);
+        uids.sort_unstable();
+        uids.dedup();
+        assert_eq!(uids.len(), expected);
+    }
+
+    #[test]
+    fn ics_includes_moon_phase_events() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let timezone: Tz = "America/New_York".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+
+        let ics = generate_calendar(&location, &timezone, None, start, end, CalendarFormat::Ics)
+            .unwrap();
+        let lines: Vec<&str> = ics.split("\r\n").collect();
+
+        for summary in ["First quarter", "Full moon", "Last quarter", "New moon"] {
+            let count = lines
+                .iter()
+                .filter(|l| l.strip_prefix("SUMMARY:") == Some(summary))
+                .count();
+            assert_eq!(count, 1, "{summary}");
+        }
+        // Full moon 2025-06-11 07:44 UTC
+        let full = lines.iter().position(|l| *l == "SUMMARY:Full moon").unwrap();
+        assert!(lines[full - 1].starts_with("DTSTART:20250611T07"));
+    }
+
+    #[test]
+    fn ics_lines_fold_at_75_octets() {
+        assert_eq!(fold_ics_line("SUMMARY:short"), "SUMMARY:short");
+
+        let long = format!("X-WR-CALNAME:{}", "é".repeat(60));
+        let folded = fold_ics_line(&long);
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert!(parts.len() > 1);
+        assert!(parts.iter().all(|part| part.len() <= 75));
+        assert!(parts[1..].iter().all(|part| part.starts_with(' ')));
+
+        let unfolded: String = parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| if i == 0 { *part } else { &part[1..] })
+            .collect();
+        assert_eq!(unfolded, long);
+    }
+}
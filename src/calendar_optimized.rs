@@ -6,12 +6,14 @@
 /// 3. Reduced DateTime clones: Create once, reuse throughout
 /// 4. Preallocated buffers: Reduce memory allocations
 use crate::astro::{moon, moon_batch_optimized, sun, Location};
+use crate::calendar::{csv_row, render_ics_events, split_hours_minutes, IcsEvent, CSV_HEADER};
 use anyhow::{anyhow, Context, Result};
 use chrono::{Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
 use chrono_tz::Tz;
 use rayon::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 const MIN_YEAR: i32 = -999;
 const MAX_YEAR: i32 = 3000;
@@ -19,10 +21,64 @@ const MAX_YEAR: i32 = 3000;
 // Rayon thread pool configuration for calendar generation
 const CALENDAR_CHUNK_SIZE: usize = 365; // Process 1 year per thread
 
+// Days between progress callbacks (the final day is always reported)
+const PROGRESS_INTERVAL: usize = 30;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CalendarFormat {
     Html,
     Json,
+    Markdown,
+    Csv,
+    Ics,
+}
+
+/// Optional columns for [`generate_calendar_optimized`]
+///
+/// The default matches the long-standing output: civil twilight and day length
+/// are shown, nautical and astronomical twilight are not. HTML, Markdown and JSON
+/// honour every flag. CSV keeps its fixed base columns (shared with
+/// [`crate::calendar`]) and appends nautical/astronomical twilight when enabled;
+/// ICS is unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarColumns {
+    pub civil_twilight: bool,
+    pub nautical_twilight: bool,
+    pub astronomical_twilight: bool,
+    pub day_length: bool,
+}
+
+impl Default for CalendarColumns {
+    fn default() -> Self {
+        Self {
+            civil_twilight: true,
+            nautical_twilight: false,
+            astronomical_twilight: false,
+            day_length: true,
+        }
+    }
+}
+
+type TwilightAccessor = fn(&DailyRecord) -> Option<chrono::DateTime<Tz>>;
+
+impl CalendarColumns {
+    /// Enabled twilight columns as (title, csv/json key, accessor), in display order
+    fn twilight_columns(&self) -> Vec<(&'static str, &'static str, TwilightAccessor)> {
+        let mut columns: Vec<(&'static str, &'static str, TwilightAccessor)> = Vec::new();
+        if self.civil_twilight {
+            columns.push(("Civil Dawn", "civil_dawn", |r| r.civil_dawn));
+            columns.push(("Civil Dusk", "civil_dusk", |r| r.civil_dusk));
+        }
+        if self.nautical_twilight {
+            columns.push(("Nautical Dawn", "nautical_dawn", |r| r.nautical_dawn));
+            columns.push(("Nautical Dusk", "nautical_dusk", |r| r.nautical_dusk));
+        }
+        if self.astronomical_twilight {
+            columns.push(("Astronomical Dawn", "astronomical_dawn", |r| r.astronomical_dawn));
+            columns.push(("Astronomical Dusk", "astronomical_dusk", |r| r.astronomical_dusk));
+        }
+        columns
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +90,10 @@ struct DailyRecord {
     solar_noon: Option<chrono::DateTime<Tz>>,
     civil_dawn: Option<chrono::DateTime<Tz>>,
     civil_dusk: Option<chrono::DateTime<Tz>>,
+    nautical_dawn: Option<chrono::DateTime<Tz>>,
+    nautical_dusk: Option<chrono::DateTime<Tz>>,
+    astronomical_dawn: Option<chrono::DateTime<Tz>>,
+    astronomical_dusk: Option<chrono::DateTime<Tz>>,
     moonrise: Option<chrono::DateTime<Tz>>,
     moonset: Option<chrono::DateTime<Tz>>,
     illumination: f64,
@@ -66,9 +126,21 @@ struct SolarBlockJson {
     sunrise: Option<String>,
     sunset: Option<String>,
     solar_noon: Option<String>,
-    civil_dawn: Option<String>,
-    civil_dusk: Option<String>,
-    day_length_minutes: Option<f64>,
+    // Optional columns: the outer `None` omits a disabled field, the inner one is `null`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    civil_dawn: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    civil_dusk: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nautical_dawn: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nautical_dusk: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    astronomical_dawn: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    astronomical_dusk: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day_length_minutes: Option<Option<f64>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -88,6 +160,12 @@ struct CalendarJson<'a> {
 }
 
 /// Generate optimized calendar with parallelization
+///
+/// `num_threads`, when given, runs the day computations on a dedicated pool of that
+/// many threads instead of rayon's global pool. `progress`, when given, receives `(days_done, total_days)` every few dozen days
+/// from the worker threads, and once more with `days_done == total_days` after all
+/// days are computed.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_calendar_optimized(
     location: &Location,
     timezone: &Tz,
@@ -95,17 +173,37 @@ pub fn generate_calendar_optimized(
     start: NaiveDate,
     end: NaiveDate,
     format: CalendarFormat,
+    options: &CalendarColumns,
+    num_threads: Option<usize>,
+    progress: Option<&(dyn Fn(usize, usize) + Sync)>,
 ) -> Result<String> {
     validate_range(start, end)?;
 
     // Phase 1: Parallel collection of daily records
-    let records = collect_records_parallel(location, timezone, start, end)?;
+    let collect = || collect_records_parallel(location, timezone, start, end, options, progress);
+    let records = match num_threads {
+        Some(threads) => rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .context("Failed to build calendar thread pool")?
+            .install(collect)?,
+        None => collect()?,
+    };
 
     match format {
         CalendarFormat::Html => Ok(render_html(
+            location, timezone, city_name, start, end, &records, options,
+        )),
+        CalendarFormat::Json => {
+            render_json(location, timezone, city_name, start, end, &records, options)
+        }
+        CalendarFormat::Markdown => Ok(render_markdown(
+            location, timezone, city_name, start, end, &records, options,
+        )),
+        CalendarFormat::Csv => Ok(render_csv(&records, options)),
+        CalendarFormat::Ics => Ok(render_ics(
             location, timezone, city_name, start, end, &records,
         )),
-        CalendarFormat::Json => render_json(location, timezone, city_name, start, end, &records),
     }
 }
 
@@ -136,9 +234,22 @@ fn collect_records_parallel(
     timezone: &Tz,
     start: NaiveDate,
     end: NaiveDate,
+    options: &CalendarColumns,
+    progress: Option<&(dyn Fn(usize, usize) + Sync)>,
 ) -> Result<Vec<DailyRecord>> {
     let total_days = (end - start).num_days() as usize + 1;
 
+    // Days finished across all workers; intermediate reports may arrive out of order
+    let days_done = AtomicUsize::new(0);
+    let on_day = || {
+        if let Some(report) = progress {
+            let done = days_done.fetch_add(1, Ordering::Relaxed) + 1;
+            if done.is_multiple_of(PROGRESS_INTERVAL) && done < total_days {
+                report(done, total_days);
+            }
+        }
+    };
+
     // Create chunks of dates (one chunk per logical work unit)
     let chunks: Vec<(NaiveDate, NaiveDate)> = (0..=(total_days / CALENDAR_CHUNK_SIZE))
         .map(|chunk_idx| {
@@ -155,7 +266,9 @@ fn collect_records_parallel(
     // Process chunks in parallel
     let chunk_results: Result<Vec<Vec<DailyRecord>>> = chunks
         .into_par_iter()
-        .map(|(chunk_start, chunk_end)| collect_records_sequential(location, timezone, chunk_start, chunk_end))
+        .map(|(chunk_start, chunk_end)| {
+            collect_records_sequential(location, timezone, chunk_start, chunk_end, options, &on_day)
+        })
         .collect();
 
     // Flatten and sort by date (should already be sorted, but ensure it)
@@ -166,6 +279,10 @@ fn collect_records_parallel(
 
     records.sort_by_key(|r| r.date);
 
+    if let Some(report) = progress {
+        report(total_days, total_days);
+    }
+
     Ok(records)
 }
 
@@ -175,14 +292,17 @@ fn collect_records_sequential(
     timezone: &Tz,
     start: NaiveDate,
     end: NaiveDate,
+    options: &CalendarColumns,
+    on_day: &(dyn Fn() + Sync),
 ) -> Result<Vec<DailyRecord>> {
     let mut records = Vec::new();
     let mut current = start;
 
     while current <= end {
-        let record = build_record_optimized(location, timezone, current)
+        let record = build_record_optimized(location, timezone, current, options)
             .with_context(|| format!("Failed to compute ephemerides for {}", current))?;
         records.push(record);
+        on_day();
         current = current
             .checked_add_signed(Duration::days(1))
             .ok_or_else(|| anyhow!("Date overflow when iterating calendar range"))?;
@@ -197,6 +317,7 @@ fn build_record_optimized(
     location: &Location,
     timezone: &Tz,
     date: NaiveDate,
+    options: &CalendarColumns,
 ) -> Result<DailyRecord> {
     let local_midday = resolve_midday(timezone, date)?;
 
@@ -207,9 +328,22 @@ fn build_record_optimized(
     let civil_dawn = sun::solar_event_time(location, &local_midday, sun::SolarEvent::CivilDawn);
     let civil_dusk = sun::solar_event_time(location, &local_midday, sun::SolarEvent::CivilDusk);
 
+    // Deeper twilight is only computed when its columns are requested
+    let optional_event = |enabled: bool, event| {
+        enabled
+            .then(|| sun::solar_event_time(location, &local_midday, event))
+            .flatten()
+    };
+    let nautical_dawn = optional_event(options.nautical_twilight, sun::SolarEvent::NauticalDawn);
+    let nautical_dusk = optional_event(options.nautical_twilight, sun::SolarEvent::NauticalDusk);
+    let astronomical_dawn =
+        optional_event(options.astronomical_twilight, sun::SolarEvent::AstronomicalDawn);
+    let astronomical_dusk =
+        optional_event(options.astronomical_twilight, sun::SolarEvent::AstronomicalDusk);
+
     // Lunar events: Use batch optimization for moonrise + moonset
     // This is the critical path - moonrise/moonset are expensive
-    let threshold = -0.834;
+    let threshold = moon::rise_set_altitude(&local_midday) - location.horizon_dip_degrees();
     let batch_result = moon_batch_optimized::batch_search_rise_and_set(location, &local_midday, threshold);
     let moonrise = batch_result.moonrise;
     let moonset = batch_result.moonset;
@@ -225,6 +359,10 @@ fn build_record_optimized(
         solar_noon,
         civil_dawn,
         civil_dusk,
+        nautical_dawn,
+        nautical_dusk,
+        astronomical_dawn,
+        astronomical_dusk,
         moonrise,
         moonset,
         illumination: lunar_position.illumination,
@@ -252,6 +390,7 @@ fn render_json(
     start: NaiveDate,
     end: NaiveDate,
     records: &[DailyRecord],
+    options: &CalendarColumns,
 ) -> Result<String> {
     let metadata = CalendarMetadata {
         latitude: location.latitude.value(),
@@ -263,6 +402,9 @@ fn render_json(
         generated_at_utc: Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
     };
 
+    let civil = options.civil_twilight;
+    let nautical = options.nautical_twilight;
+    let astronomical = options.astronomical_twilight;
     let days = records
         .iter()
         .map(|record| CalendarDayJson {
@@ -272,9 +414,13 @@ fn render_json(
                 sunrise: record.sunrise.map(format_time),
                 sunset: record.sunset.map(format_time),
                 solar_noon: record.solar_noon.map(format_time),
-                civil_dawn: record.civil_dawn.map(format_time),
-                civil_dusk: record.civil_dusk.map(format_time),
-                day_length_minutes: day_length_minutes(record),
+                civil_dawn: civil.then(|| record.civil_dawn.map(format_time)),
+                civil_dusk: civil.then(|| record.civil_dusk.map(format_time)),
+                nautical_dawn: nautical.then(|| record.nautical_dawn.map(format_time)),
+                nautical_dusk: nautical.then(|| record.nautical_dusk.map(format_time)),
+                astronomical_dawn: astronomical.then(|| record.astronomical_dawn.map(format_time)),
+                astronomical_dusk: astronomical.then(|| record.astronomical_dusk.map(format_time)),
+                day_length_minutes: options.day_length.then(|| day_length_minutes(record)),
             },
             lunar: LunarBlockJson {
                 moonrise: record.moonrise.map(format_time),
@@ -293,6 +439,139 @@ fn render_json(
     })?)
 }
 
+fn render_markdown(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+    records: &[DailyRecord],
+    options: &CalendarColumns,
+) -> String {
+    let mut by_month: BTreeMap<(i32, u32), Vec<&DailyRecord>> = BTreeMap::new();
+    for record in records {
+        by_month
+            .entry((record.date.year(), record.date.month()))
+            .or_default()
+            .push(record);
+    }
+
+    let mut md = String::from("# Solunatus Astronomical Calendar\n\n");
+    if let Some(city) = city_name {
+        md.push_str(&escape_markdown(city));
+        md.push_str(" • ");
+    }
+    md.push_str(&format!(
+        "{} • {} • {}\n\n",
+        format_lat(location.latitude.value()),
+        format_lon(location.longitude.value()),
+        timezone.name()
+    ));
+    md.push_str(&format!("Range: {} → {}\n", start, end));
+
+    let time = |dt: Option<chrono::DateTime<Tz>>| dt.map_or("—".to_string(), format_time);
+    let twilight = options.twilight_columns();
+
+    let mut titles = vec!["Date", "Sunrise", "Sunset"];
+    if options.day_length {
+        titles.push("Daylight");
+    }
+    titles.extend(twilight.iter().map(|(title, _, _)| *title));
+    titles.extend(["Moonrise", "Moonset", "Lunar Phase"]);
+
+    for ((year, month), days) in by_month {
+        md.push_str(&format!("\n## {} {}\n\n", month_name(month), year));
+        md.push_str(&format!("| {} |\n", titles.join(" | ")));
+        md.push_str(&format!("|{}\n", "---|".repeat(titles.len())));
+
+        for record in days {
+            let mut cells = vec![
+                format!("{} {}", record.date.format("%b %d"), record.weekday),
+                time(record.sunrise),
+                time(record.sunset),
+            ];
+            if options.day_length {
+                cells.push(format_day_length(record));
+            }
+            cells.extend(twilight.iter().map(|(_, _, event)| time(event(record))));
+            cells.push(time(record.moonrise));
+            cells.push(time(record.moonset));
+            cells.push(format!(
+                "{} {} ({:.1}%)",
+                record.phase_emoji,
+                escape_markdown(&record.phase_name),
+                (record.illumination * 1000.0).round() / 10.0
+            ));
+            md.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+
+    md
+}
+
+fn render_csv(records: &[DailyRecord], options: &CalendarColumns) -> String {
+    // Civil twilight is part of the shared base columns; deeper twilight is appended
+    let extra: Vec<_> = options
+        .twilight_columns()
+        .into_iter()
+        .filter(|(_, key, _)| !CSV_HEADER.contains(key))
+        .collect();
+    let mut header = CSV_HEADER.map(str::to_string).to_vec();
+    header.extend(extra.iter().map(|(_, key, _)| key.to_string()));
+
+    let mut csv = csv_row(&header);
+    for record in records {
+        let time = |dt: Option<chrono::DateTime<Tz>>| dt.map(format_time).unwrap_or_default();
+        let mut row = vec![
+            record.date.to_string(),
+            record.weekday.to_string(),
+            time(record.sunrise),
+            time(record.sunset),
+            time(record.solar_noon),
+            time(record.civil_dawn),
+            time(record.civil_dusk),
+            time(record.moonrise),
+            time(record.moonset),
+            format!("{:.1}", record.illumination * 100.0),
+            record.phase_name.clone(),
+        ];
+        row.extend(extra.iter().map(|(_, _, event)| time(event(record))));
+        csv.push_str(&csv_row(&row));
+    }
+    csv
+}
+
+fn render_ics(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<&str>,
+    start: NaiveDate,
+    end: NaiveDate,
+    records: &[DailyRecord],
+) -> String {
+    let mut events: Vec<IcsEvent> = records
+        .iter()
+        .flat_map(|record| {
+            IcsEvent::for_day(
+                record.date,
+                [
+                    record.civil_dawn,
+                    record.sunrise,
+                    record.solar_noon,
+                    record.sunset,
+                    record.civil_dusk,
+                    record.moonrise,
+                    record.moonset,
+                ],
+            )
+        })
+        .collect();
+    events.extend(IcsEvent::moon_phases(timezone, start, end));
+    events.sort_by_key(|event| event.time);
+
+    render_ics_events(location, city_name, &events)
+}
+
 fn render_html(
     location: &Location,
     timezone: &Tz,
@@ -300,6 +579,7 @@ fn render_html(
     start: NaiveDate,
     end: NaiveDate,
     records: &[DailyRecord],
+    options: &CalendarColumns,
 ) -> String {
     let mut by_month: BTreeMap<(i32, u32), Vec<&DailyRecord>> = BTreeMap::new();
     for record in records {
@@ -362,12 +642,18 @@ fn render_html(
 
     html.push_str("<div class=\"container\">");
 
+    let twilight = options.twilight_columns();
     for ((year, month), days) in by_month {
         let month_name = month_name(month);
         html.push_str(&format!("<h2>{} {}</h2>", month_name, year));
         html.push_str("<table><thead><tr>");
-        html.push_str("<th>Date</th><th>Sunrise</th><th>Sunset</th><th>Daylight</th>");
-        html.push_str("<th>Civil Dawn</th><th>Civil Dusk</th>");
+        html.push_str("<th>Date</th><th>Sunrise</th><th>Sunset</th>");
+        if options.day_length {
+            html.push_str("<th>Daylight</th>");
+        }
+        for (title, _, _) in &twilight {
+            html.push_str(&format!("<th>{}</th>", title));
+        }
         html.push_str("<th>Moonrise</th><th>Moonset</th><th>Lunar Phase</th>");
         html.push_str("</tr></thead><tbody>");
 
@@ -390,24 +676,15 @@ fn render_html(
                 "<td>{}</td>",
                 record.sunset.map_or("—".to_string(), format_time)
             ));
-            html.push_str(&format!(
-                "<td>{}</td>",
-                day_length_minutes(record)
-                    .map(|mins| format!(
-                        "{:02} h {:02} m",
-                        (mins / 60.0).floor() as i64,
-                        (mins % 60.0).round() as i64
-                    ))
-                    .unwrap_or_else(|| "—".to_string())
-            ));
-            html.push_str(&format!(
-                "<td>{}</td>",
-                record.civil_dawn.map_or("—".to_string(), format_time)
-            ));
-            html.push_str(&format!(
-                "<td>{}</td>",
-                record.civil_dusk.map_or("—".to_string(), format_time)
-            ));
+            if options.day_length {
+                html.push_str(&format!("<td>{}</td>", format_day_length(record)));
+            }
+            for (_, _, event) in &twilight {
+                html.push_str(&format!(
+                    "<td>{}</td>",
+                    event(record).map_or("—".to_string(), format_time)
+                ));
+            }
             html.push_str(&format!(
                 "<td>{}</td>",
                 record.moonrise.map_or("—".to_string(), format_time)
@@ -456,6 +733,13 @@ fn day_length_minutes(record: &DailyRecord) -> Option<f64> {
     }
 }
 
+fn format_day_length(record: &DailyRecord) -> String {
+    day_length_minutes(record)
+        .map(split_hours_minutes)
+        .map(|(hours, minutes)| format!("{hours:02} h {minutes:02} m"))
+        .unwrap_or_else(|| "—".to_string())
+}
+
 fn month_name(month: u32) -> &'static str {
     match month {
         1 => "January",
@@ -474,6 +758,10 @@ fn month_name(month: u32) -> &'static str {
     }
 }
 
+fn escape_markdown(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('|', "\\|")
+}
+
 fn escape_html(input: &str) -> String {
     input
         .replace('&', "&amp;")
@@ -498,3 +786,170 @@ fn format_lon(lon: f64) -> String {
         format!("{:.4}° W", -lon)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_has_header_and_one_row_per_day() {
+        let location = Location::new(48.8566, 2.3522).unwrap();
+        let timezone: Tz = "Europe/Paris".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 16).unwrap();
+
+        let csv = generate_calendar_optimized(
+            &location,
+            &timezone,
+            Some("Paris"),
+            start,
+            end,
+            CalendarFormat::Csv,
+            &CalendarColumns::default(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(
+            rows[0],
+            "date,weekday,sunrise,sunset,solar_noon,civil_dawn,civil_dusk,\
+             moonrise,moonset,illumination_percent,phase"
+        );
+        assert_eq!(rows.len(), 8);
+        assert!(rows[1].starts_with("2025-01-10,Fri,"));
+        assert!(rows[7].starts_with("2025-01-16,Thu,"));
+        for row in &rows[1..] {
+            assert_eq!(row.split(',').count(), CSV_HEADER.len());
+        }
+    }
+
+    #[test]
+    fn csv_leaves_missing_events_empty() {
+        // Midsummer above the Arctic Circle: the sun neither rises nor sets
+        let location = Location::new(78.2232, 15.6267).unwrap();
+        let timezone: Tz = "Arctic/Longyearbyen".parse().unwrap();
+        let day = NaiveDate::from_ymd_opt(2025, 6, 21).unwrap();
+
+        let csv = generate_calendar_optimized(
+            &location,
+            &timezone,
+            None,
+            day,
+            day,
+            CalendarFormat::Csv,
+            &CalendarColumns::default(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let row = csv.split_terminator("\r\n").nth(1).unwrap();
+        let fields: Vec<&str> = row.split(',').collect();
+        assert_eq!(fields[2], "");
+        assert_eq!(fields[3], "");
+    }
+
+    #[test]
+    fn twilight_options_add_columns() {
+        let location = Location::new(48.8566, 2.3522).unwrap();
+        let timezone: Tz = "Europe/Paris".parse().unwrap();
+        let day = NaiveDate::from_ymd_opt(2025, 3, 20).unwrap();
+        let generate = |format, options: &CalendarColumns| {
+            generate_calendar_optimized(&location, &timezone, None, day, day, format, options, None, None)
+                .unwrap()
+        };
+
+        let default_html = generate(CalendarFormat::Html, &CalendarColumns::default());
+        assert!(default_html.contains("<th>Daylight</th><th>Civil Dawn</th>"));
+        assert!(!default_html.contains("Nautical Dawn"));
+
+        let all = CalendarColumns {
+            nautical_twilight: true,
+            astronomical_twilight: true,
+            ..CalendarColumns::default()
+        };
+        let html = generate(CalendarFormat::Html, &all);
+        assert!(html.contains(
+            "<th>Civil Dusk</th><th>Nautical Dawn</th><th>Nautical Dusk</th>\
+             <th>Astronomical Dawn</th><th>Astronomical Dusk</th><th>Moonrise</th>"
+        ));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&generate(CalendarFormat::Json, &all)).unwrap();
+        let solar = &json["days"][0]["solar"];
+        for key in ["nautical_dawn", "nautical_dusk", "astronomical_dawn", "astronomical_dusk"] {
+            assert!(solar[key].is_string(), "{} missing: {}", key, solar);
+        }
+
+        let bare = CalendarColumns {
+            civil_twilight: false,
+            day_length: false,
+            ..CalendarColumns::default()
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&generate(CalendarFormat::Json, &bare)).unwrap();
+        let solar = json["days"][0]["solar"].as_object().unwrap();
+        assert!(!solar.contains_key("civil_dawn"));
+        assert!(!solar.contains_key("day_length_minutes"));
+        assert!(!solar.contains_key("nautical_dawn"));
+
+        let csv = generate(CalendarFormat::Csv, &all);
+        assert!(csv.starts_with(&format!(
+            "{},nautical_dawn,nautical_dusk,astronomical_dawn,astronomical_dusk\r\n",
+            CSV_HEADER.join(",")
+        )));
+    }
+
+    #[test]
+    fn progress_callback_reports_completion() {
+        let location = Location::new(48.8566, 2.3522).unwrap();
+        let timezone: Tz = "Europe/Paris".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 4, 10).unwrap();
+
+        let calls = std::sync::Mutex::new(Vec::new());
+        let record = |done: usize, total: usize| calls.lock().unwrap().push((done, total));
+        generate_calendar_optimized(
+            &location,
+            &timezone,
+            None,
+            start,
+            end,
+            CalendarFormat::Csv,
+            &CalendarColumns::default(),
+            None,
+            Some(&record),
+        )
+        .unwrap();
+
+        let calls = calls.into_inner().unwrap();
+        assert_eq!(calls, vec![(30, 100), (60, 100), (90, 100), (100, 100)]);
+    }
+
+    #[test]
+    fn single_thread_pool_matches_global_pool() {
+        let location = Location::new(48.8566, 2.3522).unwrap();
+        let timezone: Tz = "Europe/Paris".parse().unwrap();
+        // Spans two yearly chunks so the parallel path actually splits work
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2026, 2, 4).unwrap();
+        let generate = |num_threads: Option<usize>| {
+            generate_calendar_optimized(
+                &location,
+                &timezone,
+                None,
+                start,
+                end,
+                CalendarFormat::Csv,
+                &CalendarColumns::default(),
+                num_threads,
+                None,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(generate(Some(1)), generate(None));
+    }
+}
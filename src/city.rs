@@ -6,15 +6,25 @@
 //! # Features
 //!
 //! - Embedded city database (no external files needed)
+//! - Custom databases loaded from JSON files
 //! - Exact name matching (case-insensitive)
 //! - Fuzzy search with ranking
 //! - Nearest city lookup by coordinates
+//! - Radius search around a point
+//! - Filtering by country code or name
 //! - Distance and bearing calculations
 
+use crate::astro::Location;
 use anyhow::{Context, Result};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Score bonus for names that start with the query, lifting them above any
+/// mid-string fuzzy match (typical skim scores stay well below this)
+const PREFIX_MATCH_BONUS: i64 = 1_000;
 
 /// Information about a city in the database.
 ///
@@ -29,10 +39,40 @@ pub struct City {
     pub lon: f64,
     /// IANA timezone identifier (e.g., "America/New_York")
     pub tz: String,
-    /// Country name
+    /// ISO 3166-1 alpha-2 country code (e.g., "JP"); see [`country_name`]
     pub country: String,
     /// State/province name (if applicable)
     pub state: Option<String>,
+    /// Population (if known), used to rank otherwise equal search matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub population: Option<u64>,
+    /// Elevation above sea level in meters (if known)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elevation_m: Option<f64>,
+}
+
+impl City {
+    /// Observer location for this city, at its elevation when known.
+    ///
+    /// Elevation lowers the apparent horizon, so rise/set times account for the
+    /// horizon dip automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use solunatus::city::CityDatabase;
+    ///
+    /// let db = CityDatabase::load().unwrap();
+    /// let la_paz = db.find_exact("La Paz").unwrap();
+    /// assert!(la_paz.location().elevation_m > 3000.0);
+    /// ```
+    pub fn location(&self) -> Location {
+        let mut location = Location::new_unchecked(self.lat, self.lon);
+        if let Some(meters) = self.elevation_m.filter(|m| m.is_finite()) {
+            location.elevation_m = meters;
+        }
+        location
+    }
 }
 
 /// Database of major cities worldwide.
@@ -40,6 +80,30 @@ pub struct City {
 /// Contains 570+ cities with population centers and geographic data.
 pub struct CityDatabase {
     cities: Vec<City>,
+    /// Folded search keys, one per entry of `cities`
+    keys: Vec<SearchKeys>,
+}
+
+/// Case- and diacritic-folded text for one city, built once so lookups don't
+/// re-fold every name on each query.
+struct SearchKeys {
+    /// Folded bare name
+    name: String,
+    /// Folded "Name, State, Country" (or "Name, Country") label
+    label: String,
+}
+
+impl SearchKeys {
+    fn new(city: &City) -> Self {
+        let label = match &city.state {
+            Some(state) => format!("{}, {}, {}", city.name, state, city.country),
+            None => format!("{}, {}", city.name, city.country),
+        };
+        Self {
+            name: fold_for_search(&city.name),
+            label: fold_for_search(&label),
+        }
+    }
 }
 
 impl CityDatabase {
@@ -61,10 +125,76 @@ impl CityDatabase {
     /// ```
     pub fn load() -> Result<Self> {
         let data = include_str!("../data/urban_areas.json");
+        Self::from_json(data)
+    }
+
+    /// Load a city database from an external JSON file instead of the bundled data.
+    ///
+    /// The file must hold a JSON array of city objects using the same schema as the
+    /// bundled database:
+    ///
+    /// ```json
+    /// [
+    ///   {
+    ///     "name": "Tokyo",
+    ///     "lat": 35.6895,
+    ///     "lon": 139.6917,
+    ///     "tz": "Asia/Tokyo",
+    ///     "state": null,
+    ///     "country": "JP",
+    ///     "population": 13960000
+    ///   }
+    /// ]
+    /// ```
+    ///
+    /// `name`, `lat`, `lon`, `tz` and `country` are required; `state` and
+    /// `population` may be `null` or omitted. See [`City`] for field meanings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not match the schema.
+    pub fn load_from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read city database {}", path.display()))?;
+        Self::from_json(&data)
+            .with_context(|| format!("Invalid city database {}", path.display()))
+    }
+
+    fn from_json(data: &str) -> Result<Self> {
         let cities: Vec<City> =
             serde_json::from_str(data).context("Failed to parse city database")?;
 
-        Ok(Self { cities })
+        Ok(Self::from_cities(cities))
+    }
+
+    fn from_cities(cities: Vec<City>) -> Self {
+        let keys = cities.iter().map(SearchKeys::new).collect();
+        Self { cities, keys }
+    }
+
+    /// Add a custom city (e.g. a private point of interest).
+    ///
+    /// The city takes part in [`find_exact`](Self::find_exact), [`search`](Self::search),
+    /// [`find_nearest`](Self::find_nearest) and the other lookups. Bundled cities with the
+    /// same name are kept and still win in `find_exact`.
+    pub fn add_city(&mut self, city: City) {
+        self.keys.push(SearchKeys::new(&city));
+        self.cities.push(city);
+    }
+
+    /// Remove the city [`find_exact`](Self::find_exact) would return for `name`.
+    ///
+    /// Returns `true` if a city was removed.
+    pub fn remove_city(&mut self, name: &str) -> bool {
+        match self.position_exact(name) {
+            Some(index) => {
+                self.cities.remove(index);
+                self.keys.remove(index);
+                true
+            }
+            None => false,
+        }
     }
 
     /// Find the nearest city to given coordinates.
@@ -95,9 +225,9 @@ impl CityDatabase {
         let mut min_distance = f64::INFINITY;
 
         for city in &self.cities {
-            let distance = haversine_distance(lat, lon, city.lat, city.lon);
+            let distance = distance_km(lat, lon, city.lat, city.lon);
             if distance < min_distance {
-                let bearing = calculate_bearing(lat, lon, city.lat, city.lon);
+                let bearing = initial_bearing(lat, lon, city.lat, city.lon);
                 min_distance = distance;
                 nearest = Some((city, distance, bearing));
             }
@@ -106,6 +236,90 @@ impl CityDatabase {
         nearest
     }
 
+    /// Label the city nearest to a point as "City, State, Country".
+    ///
+    /// Missing or empty parts are omitted; the country code is expanded with
+    /// [`country_name`] when known. Returns `None` if the database is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use solunatus::city::CityDatabase;
+    ///
+    /// let db = CityDatabase::load().unwrap();
+    /// let label = db.reverse_geocode(42.36, -71.06).unwrap();
+    /// assert!(label.starts_with("Boston"));
+    /// ```
+    pub fn reverse_geocode(&self, lat: f64, lon: f64) -> Option<String> {
+        let (city, _, _) = self.find_nearest(lat, lon)?;
+        let country = country_name(&city.country).unwrap_or(&city.country);
+        let parts: Vec<&str> = [Some(city.name.as_str()), city.state.as_deref(), Some(country)]
+            .into_iter()
+            .flatten()
+            .filter(|part| !part.trim().is_empty())
+            .collect();
+        Some(parts.join(", "))
+    }
+
+    /// Find every city within `radius_km` of a point.
+    ///
+    /// Returns `(city, distance_km)` pairs sorted by ascending distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use solunatus::city::CityDatabase;
+    ///
+    /// let db = CityDatabase::load().unwrap();
+    /// for (city, distance) in db.find_within_radius(35.6895, 139.6917, 100.0) {
+    ///     println!("{} ({:.1} km away)", city.name, distance);
+    /// }
+    /// ```
+    pub fn find_within_radius(&self, lat: f64, lon: f64, radius_km: f64) -> Vec<(City, f64)> {
+        let mut results: Vec<(City, f64)> = self
+            .cities
+            .iter()
+            .filter_map(|city| {
+                let distance = distance_km(lat, lon, city.lat, city.lon);
+                (distance <= radius_km).then(|| (city.clone(), distance))
+            })
+            .collect();
+
+        results.sort_by(|a, b| a.1.total_cmp(&b.1));
+        results
+    }
+
+    /// List the cities in a country, sorted by name.
+    ///
+    /// `country` matches case-insensitively against either the ISO 3166-1
+    /// alpha-2 code stored on each city ("JP") or its English name ("Japan").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use solunatus::city::CityDatabase;
+    ///
+    /// let db = CityDatabase::load().unwrap();
+    /// for city in db.cities_in_country("Japan") {
+    ///     println!("{}", city.name);
+    /// }
+    /// ```
+    pub fn cities_in_country(&self, country: &str) -> Vec<&City> {
+        let query = country.trim();
+        let code = COUNTRY_NAMES
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(query))
+            .map_or(query, |(code, _)| *code);
+
+        let mut cities: Vec<&City> = self
+            .cities
+            .iter()
+            .filter(|city| city.country.eq_ignore_ascii_case(code))
+            .collect();
+        cities.sort_by(|a, b| a.name.cmp(&b.name));
+        cities
+    }
+
     /// Get a reference to all cities in the database.
     ///
     /// Returns a slice of all cities (570+) in the database.
@@ -114,7 +328,7 @@ impl CityDatabase {
         &self.cities
     }
 
-    /// Find a city by exact name match (case-insensitive).
+    /// Find a city by exact name match (case- and diacritic-insensitive).
     ///
     /// # Examples
     ///
@@ -127,16 +341,19 @@ impl CityDatabase {
     /// }
     /// ```
     pub fn find_exact(&self, name: &str) -> Option<&City> {
-        let name_lower = name.to_lowercase();
-        self.cities
-            .iter()
-            .find(|c| c.name.to_lowercase() == name_lower)
+        self.position_exact(name).map(|index| &self.cities[index])
+    }
+
+    fn position_exact(&self, name: &str) -> Option<usize> {
+        let name_folded = fold_for_search(name);
+        self.keys.iter().position(|k| k.name == name_folded)
     }
 
     /// Search for cities using fuzzy matching.
     ///
-    /// Searches city names, states, and countries using fuzzy string matching.
-    /// Results are sorted by match score (highest first).
+    /// Searches city names, states, and countries using fuzzy string matching,
+    /// ignoring case and diacritics ("zurich" finds "Zürich"). Results are sorted by match score (highest first); equal scores are
+    /// ordered by population (largest first, unknown last).
     ///
     /// # Arguments
     ///
@@ -144,7 +361,8 @@ impl CityDatabase {
     ///
     /// # Returns
     ///
-    /// A vector of `(city, score)` tuples sorted by score (descending).
+    /// A vector of `(city, score)` tuples sorted by score (descending). Cities whose
+    /// name starts with the query (case-insensitive) rank ahead of other matches.
     ///
     /// # Examples
     ///
@@ -159,46 +377,304 @@ impl CityDatabase {
     /// ```
     pub fn search(&self, query: &str) -> Vec<(&City, i64)> {
         let matcher = SkimMatcherV2::default();
+        let query = fold_for_search(query.trim());
         // Pre-allocate with approximate capacity
         let mut results = Vec::with_capacity(64);
 
-        for city in &self.cities {
-            // Use stack-allocated buffer to avoid repeated allocations
-            let match_score = if let Some(state) = &city.state {
-                // Try to match against "Name, State, Country" format
-                let mut search_buf = String::with_capacity(
-                    city.name.len() + state.len() + city.country.len() + 4,
-                );
-                search_buf.push_str(&city.name);
-                search_buf.push_str(", ");
-                search_buf.push_str(state);
-                search_buf.push_str(", ");
-                search_buf.push_str(&city.country);
-                matcher.fuzzy_match(&search_buf, query)
-            } else {
-                // Try to match against "Name, Country" format
-                let mut search_buf =
-                    String::with_capacity(city.name.len() + city.country.len() + 2);
-                search_buf.push_str(&city.name);
-                search_buf.push_str(", ");
-                search_buf.push_str(&city.country);
-                matcher.fuzzy_match(&search_buf, query)
-            };
-
-            if let Some(score) = match_score {
+        for (city, keys) in self.cities.iter().zip(&self.keys) {
+            // Match against the "Name, State, Country" (or "Name, Country") label
+            if let Some(score) = matcher.fuzzy_match(&keys.label, &query) {
+                // Prefix hits are scored on the bare name so state/country text
+                // doesn't reorder them; population then breaks the ties
+                let name = &keys.name;
+                let score = if !query.is_empty() && name.starts_with(&query) {
+                    PREFIX_MATCH_BONUS + matcher.fuzzy_match(name, &query).unwrap_or(score)
+                } else {
+                    score
+                };
                 results.push((city, score));
             }
         }
 
-        // Sort by score descending (highest scores first)
-        results.sort_unstable_by(|a, b| b.1.cmp(&a.1));
+        // Sort by score descending (highest scores first), then by population, then
+        // shorter names (the closer completion of a prefix)
+        results.sort_unstable_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then_with(|| b.0.population.cmp(&a.0.population))
+                .then_with(|| a.0.name.len().cmp(&b.0.name.len()))
+        });
         results
     }
 }
 
+/// Lowercase `text` and strip diacritics so "Zürich" and "zurich" compare equal.
+///
+/// Covers the Latin letters found in the bundled city names (Latin-1, Latin
+/// Extended-A and a few others) plus combining marks; other characters pass
+/// through lowercased.
+fn fold_for_search(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à'..='å' | 'ā' | 'ă' | 'ą' => folded.push('a'),
+            'æ' => folded.push_str("ae"),
+            'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => folded.push('c'),
+            'ď' | 'đ' => folded.push('d'),
+            'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => folded.push('e'),
+            'ĝ' | 'ğ' | 'ġ' | 'ģ' => folded.push('g'),
+            'ĥ' | 'ħ' | 'ḥ' | 'ḩ' => folded.push('h'),
+            'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => folded.push('i'),
+            'ĵ' => folded.push('j'),
+            'ķ' => folded.push('k'),
+            'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => folded.push('l'),
+            'ñ' | 'ń' | 'ņ' | 'ň' => folded.push('n'),
+            'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => folded.push('o'),
+            'œ' => folded.push_str("oe"),
+            'ŕ' | 'ŗ' | 'ř' => folded.push('r'),
+            'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => folded.push('s'),
+            'ß' => folded.push_str("ss"),
+            'ţ' | 'ť' | 'ŧ' | 'ț' => folded.push('t'),
+            'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => folded.push('u'),
+            'ŵ' => folded.push('w'),
+            'ý' | 'ÿ' | 'ŷ' => folded.push('y'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            '‘' | '’' => folded.push('\''),
+            // Combining diacritical marks (e.g. the dot left by lowercasing 'İ')
+            '\u{0300}'..='\u{036F}' => {}
+            other => folded.push(other),
+        }
+    }
+    folded
+}
+
+/// Look up the English name for an ISO 3166-1 alpha-2 country code (case-insensitive).
+///
+/// Only codes that appear in the city database are known.
+pub fn country_name(code: &str) -> Option<&'static str> {
+    COUNTRY_NAMES
+        .iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(code))
+        .map(|(_, name)| *name)
+}
+
+/// English names for the ISO 3166-1 alpha-2 codes used in the city database.
+const COUNTRY_NAMES: &[(&str, &str)] = &[
+    ("AD", "Andorra"),
+    ("AE", "United Arab Emirates"),
+    ("AF", "Afghanistan"),
+    ("AI", "Anguilla"),
+    ("AL", "Albania"),
+    ("AM", "Armenia"),
+    ("AO", "Angola"),
+    ("AR", "Argentina"),
+    ("AS", "American Samoa"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AX", "Åland Islands"),
+    ("AZ", "Azerbaijan"),
+    ("BA", "Bosnia and Herzegovina"),
+    ("BB", "Barbados"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BF", "Burkina Faso"),
+    ("BG", "Bulgaria"),
+    ("BH", "Bahrain"),
+    ("BI", "Burundi"),
+    ("BJ", "Benin"),
+    ("BO", "Bolivia"),
+    ("BR", "Brazil"),
+    ("BS", "Bahamas"),
+    ("BT", "Bhutan"),
+    ("BW", "Botswana"),
+    ("BY", "Belarus"),
+    ("BZ", "Belize"),
+    ("CA", "Canada"),
+    ("CD", "Democratic Republic of the Congo"),
+    ("CF", "Central African Republic"),
+    ("CG", "Republic of the Congo"),
+    ("CH", "Switzerland"),
+    ("CI", "Côte d'Ivoire"),
+    ("CL", "Chile"),
+    ("CM", "Cameroon"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CR", "Costa Rica"),
+    ("CU", "Cuba"),
+    ("CV", "Cape Verde"),
+    ("CY", "Cyprus"),
+    ("CZ", "Czech Republic"),
+    ("DE", "Germany"),
+    ("DJ", "Djibouti"),
+    ("DK", "Denmark"),
+    ("DM", "Dominica"),
+    ("DO", "Dominican Republic"),
+    ("DZ", "Algeria"),
+    ("EC", "Ecuador"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("ER", "Eritrea"),
+    ("ES", "Spain"),
+    ("ET", "Ethiopia"),
+    ("FI", "Finland"),
+    ("FJ", "Fiji"),
+    ("FM", "Micronesia"),
+    ("FO", "Faroe Islands"),
+    ("FR", "France"),
+    ("GA", "Gabon"),
+    ("GB", "United Kingdom"),
+    ("GE", "Georgia"),
+    ("GF", "French Guiana"),
+    ("GH", "Ghana"),
+    ("GL", "Greenland"),
+    ("GM", "Gambia"),
+    ("GN", "Guinea"),
+    ("GP", "Guadeloupe"),
+    ("GQ", "Equatorial Guinea"),
+    ("GR", "Greece"),
+    ("GT", "Guatemala"),
+    ("GU", "Guam"),
+    ("GW", "Guinea-Bissau"),
+    ("GY", "Guyana"),
+    ("HK", "Hong Kong"),
+    ("HN", "Honduras"),
+    ("HR", "Croatia"),
+    ("HT", "Haiti"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IM", "Isle of Man"),
+    ("IN", "India"),
+    ("IQ", "Iraq"),
+    ("IR", "Iran"),
+    ("IS", "Iceland"),
+    ("IT", "Italy"),
+    ("JM", "Jamaica"),
+    ("JO", "Jordan"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KG", "Kyrgyzstan"),
+    ("KH", "Cambodia"),
+    ("KI", "Kiribati"),
+    ("KM", "Comoros"),
+    ("KN", "Saint Kitts and Nevis"),
+    ("KP", "North Korea"),
+    ("KR", "South Korea"),
+    ("KW", "Kuwait"),
+    ("KZ", "Kazakhstan"),
+    ("LA", "Laos"),
+    ("LB", "Lebanon"),
+    ("LC", "Saint Lucia"),
+    ("LI", "Liechtenstein"),
+    ("LK", "Sri Lanka"),
+    ("LR", "Liberia"),
+    ("LS", "Lesotho"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("LV", "Latvia"),
+    ("LY", "Libya"),
+    ("MA", "Morocco"),
+    ("MD", "Moldova"),
+    ("ME", "Montenegro"),
+    ("MG", "Madagascar"),
+    ("MH", "Marshall Islands"),
+    ("MK", "North Macedonia"),
+    ("ML", "Mali"),
+    ("MM", "Myanmar"),
+    ("MN", "Mongolia"),
+    ("MQ", "Martinique"),
+    ("MR", "Mauritania"),
+    ("MT", "Malta"),
+    ("MU", "Mauritius"),
+    ("MV", "Maldives"),
+    ("MW", "Malawi"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("MZ", "Mozambique"),
+    ("NA", "Namibia"),
+    ("NC", "New Caledonia"),
+    ("NE", "Niger"),
+    ("NG", "Nigeria"),
+    ("NI", "Nicaragua"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NP", "Nepal"),
+    ("NR", "Nauru"),
+    ("NZ", "New Zealand"),
+    ("OM", "Oman"),
+    ("PA", "Panama"),
+    ("PE", "Peru"),
+    ("PF", "French Polynesia"),
+    ("PG", "Papua New Guinea"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PR", "Puerto Rico"),
+    ("PS", "Palestine"),
+    ("PT", "Portugal"),
+    ("PW", "Palau"),
+    ("PY", "Paraguay"),
+    ("QA", "Qatar"),
+    ("RE", "Réunion"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russia"),
+    ("RW", "Rwanda"),
+    ("SA", "Saudi Arabia"),
+    ("SD", "Sudan"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SI", "Slovenia"),
+    ("SJ", "Svalbard and Jan Mayen"),
+    ("SK", "Slovakia"),
+    ("SL", "Sierra Leone"),
+    ("SN", "Senegal"),
+    ("SO", "Somalia"),
+    ("SR", "Suriname"),
+    ("SS", "South Sudan"),
+    ("SV", "El Salvador"),
+    ("SY", "Syria"),
+    ("SZ", "Eswatini"),
+    ("TD", "Chad"),
+    ("TG", "Togo"),
+    ("TH", "Thailand"),
+    ("TJ", "Tajikistan"),
+    ("TL", "Timor-Leste"),
+    ("TM", "Turkmenistan"),
+    ("TN", "Tunisia"),
+    ("TO", "Tonga"),
+    ("TR", "Turkey"),
+    ("TT", "Trinidad and Tobago"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("UA", "Ukraine"),
+    ("UG", "Uganda"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("UZ", "Uzbekistan"),
+    ("VE", "Venezuela"),
+    ("VN", "Vietnam"),
+    ("WS", "Samoa"),
+    ("XK", "Kosovo"),
+    ("YE", "Yemen"),
+    ("YT", "Mayotte"),
+    ("ZA", "South Africa"),
+    ("ZM", "Zambia"),
+    ("ZW", "Zimbabwe"),
+];
+
 /// Calculate the great-circle distance between two points using the Haversine formula
-/// Returns distance in kilometers
-fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+/// Returns distance in kilometers on a sphere of radius 6371 km
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::city::distance_km;
+///
+/// let london_paris = distance_km(51.5074, -0.1278, 48.8566, 2.3522);
+/// assert!((london_paris - 344.0).abs() < 3.0);
+/// ```
+pub fn distance_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     const EARTH_RADIUS_KM: f64 = 6371.0;
 
     let lat1_rad = lat1.to_radians();
@@ -215,7 +691,7 @@ fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
 
 /// Calculate the initial bearing from point 1 to point 2
 /// Returns bearing in degrees (0-360, where 0 is North)
-fn calculate_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+pub fn initial_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     let lat1_rad = lat1.to_radians();
     let lat2_rad = lat2.to_radians();
     let delta_lon = (lon2 - lon1).to_radians();
@@ -230,6 +706,35 @@ fn calculate_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     (bearing_deg + 360.0) % 360.0
 }
 
+/// Calculate the point reached by travelling `distance_km` from a start point
+/// along a great circle with the given initial bearing (degrees, 0 = North)
+/// Returns `(lat, lon)` in degrees with longitude in -180..180, on the same
+/// 6371 km sphere as [`distance_km`]
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::city::destination_point;
+///
+/// let (lat, lon) = destination_point(0.0, 0.0, 90.0, 111.195);
+/// assert!(lat.abs() < 1e-9 && (lon - 1.0).abs() < 1e-3);
+/// ```
+pub fn destination_point(lat: f64, lon: f64, bearing_deg: f64, distance_km: f64) -> (f64, f64) {
+    const EARTH_RADIUS_KM: f64 = 6371.0;
+
+    let lat1 = lat.to_radians();
+    let lon1 = lon.to_radians();
+    let bearing = bearing_deg.to_radians();
+    let angular = distance_km / EARTH_RADIUS_KM;
+
+    let lat2 = (lat1.sin() * angular.cos() + lat1.cos() * angular.sin() * bearing.cos()).asin();
+    let lon2 = lon1
+        + (bearing.sin() * angular.sin() * lat1.cos())
+            .atan2(angular.cos() - lat1.sin() * lat2.sin());
+
+    (lat2.to_degrees(), crate::astro::normalize_degrees_signed(lon2.to_degrees()))
+}
+
 /// Convert bearing in degrees to compass direction
 pub fn bearing_to_compass(bearing: f64) -> &'static str {
     let normalized = ((bearing % 360.0) + 360.0) % 360.0;
@@ -272,10 +777,251 @@ mod tests {
         assert_eq!(city.unwrap().country, "US");
     }
 
+    #[test]
+    fn test_find_within_radius() {
+        let db = CityDatabase::load().unwrap();
+
+        let tokyo = db.find_within_radius(35.6895, 139.6917, 100.0);
+        let names: Vec<&str> = tokyo.iter().map(|(city, _)| city.name.as_str()).collect();
+        assert_eq!(names, ["Tokyo", "Yokohama"]);
+        assert!(tokyo.iter().all(|(city, _)| city.country == "JP"));
+
+        // Luton ~46 km, Reading ~59 km and Brighton ~76 km from central London;
+        // Paris is ~344 km away
+        let london = db.find_within_radius(51.5074, -0.1278, 100.0);
+        let names: Vec<&str> = london.iter().map(|(city, _)| city.name.as_str()).collect();
+        assert_eq!(names, ["London", "Luton", "Reading", "Brighton"]);
+        assert!(london.iter().all(|(city, _)| city.country == "GB"));
+        assert!(london.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(!names.contains(&"Paris"));
+    }
+
+    #[test]
+    fn test_cities_in_country() {
+        let db = CityDatabase::load().unwrap();
+
+        let japan = db.cities_in_country("Japan");
+        let names: Vec<&str> = japan.iter().map(|city| city.name.as_str()).collect();
+        assert!(names.contains(&"Tokyo"));
+        assert!(!names.contains(&"Seoul"));
+        assert!(names.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let by_code: Vec<&str> = db
+            .cities_in_country("jp")
+            .iter()
+            .map(|city| city.name.as_str())
+            .collect();
+        assert_eq!(names, by_code);
+
+        assert!(db.cities_in_country("Atlantis").is_empty());
+        assert_eq!(country_name("kr"), Some("South Korea"));
+    }
+
+    #[test]
+    fn test_destination_point() {
+        let (lat, lon) = destination_point(40.0, -74.0, 0.0, 111.0);
+        assert!((lat - 40.998).abs() < 0.01, "lat {lat}");
+        assert!((lon + 74.0).abs() < 1e-9);
+
+        // Round trip against the distance and bearing helpers
+        let (lat, lon) = destination_point(51.5074, -0.1278, 123.0, 500.0);
+        assert!((distance_km(51.5074, -0.1278, lat, lon) - 500.0).abs() < 1e-6);
+        assert!((initial_bearing(51.5074, -0.1278, lat, lon) - 123.0).abs() < 1e-6);
+
+        // Crossing the antimeridian wraps the longitude
+        let (_, lon) = destination_point(0.0, 179.5, 90.0, 111.195);
+        assert!((lon + 179.5).abs() < 1e-3, "lon {lon}");
+    }
+
+    #[test]
+    fn test_distance_km_city_pairs() {
+        // Reference great-circle distances in km
+        let pairs = [
+            ("London–Paris", (51.5074, -0.1278), (48.8566, 2.3522), 344.0),
+            ("New York–London", (40.7128, -74.0060), (51.5074, -0.1278), 5570.0),
+            ("Tokyo–Sydney", (35.6762, 139.6503), (-33.8688, 151.2093), 7820.0),
+        ];
+        for (name, (lat1, lon1), (lat2, lon2), expected) in pairs {
+            let distance = distance_km(lat1, lon1, lat2, lon2);
+            assert!(
+                (distance - expected).abs() / expected < 0.01,
+                "{}: expected ~{} km, got {:.1}",
+                name,
+                expected,
+                distance
+            );
+            assert!((distance - distance_km(lat2, lon2, lat1, lon1)).abs() < 1e-9);
+        }
+        assert_eq!(distance_km(10.0, 20.0, 10.0, 20.0), 0.0);
+    }
+
+    #[test]
+    fn test_initial_bearing() {
+        assert!((initial_bearing(0.0, 0.0, 10.0, 0.0) - 0.0).abs() < 1e-9);
+        assert!((initial_bearing(0.0, 0.0, 0.0, 10.0) - 90.0).abs() < 1e-9);
+        assert!((initial_bearing(0.0, 0.0, -10.0, 0.0) - 180.0).abs() < 1e-9);
+        assert!((initial_bearing(0.0, 0.0, 0.0, -10.0) - 270.0).abs() < 1e-9);
+
+        // London to Paris heads south-southeast (~148°)
+        let bearing = initial_bearing(51.5074, -0.1278, 48.8566, 2.3522);
+        assert!((bearing - 148.0).abs() < 1.0, "got {}", bearing);
+        assert_eq!(bearing_to_compass(bearing), "SSE");
+    }
+
+    fn test_city(name: &str, country: &str, population: Option<u64>) -> City {
+        City {
+            name: name.to_string(),
+            lat: 0.0,
+            lon: 0.0,
+            tz: "UTC".to_string(),
+            country: country.to_string(),
+            state: None,
+            population,
+            elevation_m: None,
+        }
+    }
+
+    #[test]
+    fn test_search_breaks_ties_by_population() {
+        let db = CityDatabase::from_cities(vec![
+            test_city("Paris", "US", Some(25_000)),
+            test_city("Paris", "FR", Some(2_100_000)),
+            test_city("Paris", "CA", None),
+        ]);
+
+        let results = db.search("Paris");
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(_, score)| *score == results[0].1));
+        let countries: Vec<&str> = results.iter().map(|(c, _)| c.country.as_str()).collect();
+        assert_eq!(countries, ["FR", "US", "CA"]);
+
+        // Bundled data carries populations for same-named cities
+        let db = CityDatabase::load().unwrap();
+        let results = db.search("Columbus");
+        assert_eq!(results[0].0.state.as_deref(), Some("OH"));
+    }
+
+    #[test]
+    fn test_add_and_remove_custom_city() {
+        let mut db = CityDatabase::load().unwrap();
+        let count = db.cities().len();
+
+        let mut hideout = test_city("Quillhaven Observatory", "NZ", None);
+        hideout.lat = -43.9856;
+        hideout.lon = 170.4650;
+        hideout.tz = "Pacific/Auckland".to_string();
+        db.add_city(hideout);
+        assert_eq!(db.cities().len(), count + 1);
+
+        let results = db.search("quilhaven obs");
+        assert_eq!(results[0].0.name, "Quillhaven Observatory");
+        assert!(db.find_exact("quillhaven observatory").is_some());
+        let (nearest, distance, _) = db.find_nearest(-43.99, 170.46).unwrap();
+        assert_eq!(nearest.name, "Quillhaven Observatory");
+        assert!(distance < 1.0);
+
+        assert!(db.remove_city("QUILLHAVEN OBSERVATORY"));
+        assert!(!db.remove_city("Quillhaven Observatory"));
+        assert!(db.find_exact("Quillhaven Observatory").is_none());
+        assert!(db.search("quilhaven obs").is_empty());
+        assert_eq!(db.cities().len(), count);
+    }
+
+    #[test]
+    fn test_load_from_path() {
+        let path = std::env::temp_dir().join(format!(
+            "solunatus-cities-{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"[
+                {"name": "Base Camp", "lat": 27.9881, "lon": 86.9250,
+                 "tz": "Asia/Kathmandu", "country": "NP"},
+                {"name": "Station Nord", "lat": 81.6, "lon": -16.67,
+                 "tz": "America/Nuuk", "state": null, "country": "GL", "population": 5}
+            ]"#,
+        )
+        .unwrap();
+
+        let db = CityDatabase::load_from_path(&path);
+        fs::remove_file(&path).unwrap();
+        let db = db.unwrap();
+
+        assert_eq!(db.cities().len(), 2);
+        let camp = db.find_exact("base camp").unwrap();
+        assert_eq!(camp.tz, "Asia/Kathmandu");
+        assert_eq!(camp.state, None);
+        assert_eq!(db.find_exact("Station Nord").unwrap().population, Some(5));
+        assert!(db.find_exact("Tokyo").is_none());
+
+        assert!(CityDatabase::load_from_path(path).is_err());
+    }
+
     #[test]
     fn test_search() {
         let db = CityDatabase::load().unwrap();
         let results = db.search("san");
         assert!(!results.is_empty());
     }
+
+    #[test]
+    fn test_search_prefers_name_prefix() {
+        let db = CityDatabase::load().unwrap();
+        assert_eq!(db.search("lon")[0].0.name, "London");
+        assert_eq!(db.search("san fr")[0].0.name, "San Francisco");
+        // Typos still fall back to fuzzy matching
+        assert!(db.search("lndon").iter().any(|(c, _)| c.name == "London"));
+    }
+
+    #[test]
+    fn test_city_elevation_advances_sunrise() {
+        use crate::astro::sun;
+        use chrono::TimeZone;
+
+        let db = CityDatabase::load().unwrap();
+        let la_paz = db.find_exact("La Paz").unwrap();
+        assert_eq!(la_paz.elevation_m, Some(3640.0));
+        assert_eq!(db.find_exact("London").unwrap().elevation_m, None);
+
+        let tz: chrono_tz::Tz = la_paz.tz.parse().unwrap();
+        let noon = tz.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let sunrise = |location: &Location| {
+            sun::solar_event_time(location, &noon, sun::SolarEvent::Sunrise).unwrap()
+        };
+        let sea_level = Location::new(la_paz.lat, la_paz.lon).unwrap();
+        let lead = sunrise(&sea_level) - sunrise(&la_paz.location());
+        assert!(lead.num_minutes() >= 5, "sunrise only {} earlier", lead);
+    }
+
+    #[test]
+    fn test_reverse_geocode() {
+        let db = CityDatabase::load().unwrap();
+        let label = db.reverse_geocode(42.35, -71.05).unwrap();
+        assert!(label.contains("Boston"), "{}", label);
+        assert_eq!(label, "Boston, MA, United States");
+
+        let db = CityDatabase::from_cities(vec![test_city("Station", "ZZ", None)]);
+        assert_eq!(db.reverse_geocode(0.0, 0.0).unwrap(), "Station, ZZ");
+        assert!(CityDatabase::from_cities(Vec::new()).reverse_geocode(0.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_search_ignores_diacritics() {
+        let db = CityDatabase::load().unwrap();
+        assert_eq!(db.search("zurich")[0].0.name, "Zürich");
+        assert_eq!(db.search("sao paulo")[0].0.name, "São Paulo");
+        assert_eq!(db.find_exact("zurich").unwrap().name, "Zürich");
+        assert_eq!(db.find_exact("SAO PAULO").unwrap().name, "São Paulo");
+        assert_eq!(db.find_exact("izmir").unwrap().name, "İzmir");
+
+        let mut db = CityDatabase::load().unwrap();
+        let count = db.cities().len();
+        assert!(db.remove_city("zurich"));
+        assert!(db.find_exact("Zürich").is_none());
+        assert_eq!(db.cities().len(), count - 1);
+
+        assert_eq!(fold_for_search("Xi’an"), "xi'an");
+        assert_eq!(fold_for_search("Ürümqi"), "urumqi");
+    }
 }
@@ -7,8 +7,26 @@ use std::path::PathBuf;
 pub enum CalendarFormatArg {
     Html,
     Json,
+    Markdown,
+    Csv,
+    Ics,
 }
 
+/// API spoken by the `--ai-server`
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum AiProviderArg {
+    /// Ollama's native API
+    Ollama,
+    /// OpenAI chat-completions API (hosted gateways, llama.cpp, vLLM, ...)
+    OpenaiCompatible,
+}
+
+/// Server used when `--ai-server` is omitted and none is saved
+pub const DEFAULT_AI_SERVER: &str = "http://localhost:11434";
+
+/// Model used when `--ai-model` is omitted and none is saved
+pub const DEFAULT_AI_MODEL: &str = "llama3";
+
 #[derive(Parser, Debug, Clone)]
 #[command(name = "solunatus")]
 #[command(version)]
@@ -22,7 +40,7 @@ pub struct Args {
     #[arg(long)]
     pub lon: Option<f64>,
 
-    /// Timezone (IANA timezone name or UTC offset like -07:00)
+    /// Timezone (IANA name or UTC offset like -07:00; estimated from coordinates if omitted)
     #[arg(long)]
     pub tz: Option<String>,
 
@@ -42,7 +60,7 @@ pub struct Args {
     #[arg(long)]
     pub calendar: bool,
 
-    /// Calendar output format (html or json)
+    /// Calendar output format (html, json, markdown, csv or ics)
     #[arg(long, default_value = "html", value_enum)]
     pub calendar_format: CalendarFormatArg,
 
@@ -74,17 +92,25 @@ pub struct Args {
     #[arg(long)]
     pub strict: bool,
 
-    /// Enable AI insights via a local Ollama server
+    /// Enable AI insights via a local Ollama server or an OpenAI-compatible gateway
     #[arg(long)]
     pub ai_insights: bool,
 
-    /// Ollama server base URL or host:port (defaults to http://localhost:11434)
-    #[arg(long, default_value = "http://localhost:11434")]
-    pub ai_server: String,
+    /// AI server base URL or host:port (defaults to the saved server, else http://localhost:11434)
+    #[arg(long)]
+    pub ai_server: Option<String>,
+
+    /// Model to query for insights (defaults to the saved model, else llama3)
+    #[arg(long)]
+    pub ai_model: Option<String>,
+
+    /// API spoken by the AI server (defaults to the saved provider, else ollama)
+    #[arg(long, value_enum)]
+    pub ai_provider: Option<AiProviderArg>,
 
-    /// Ollama model to query for insights
-    #[arg(long, default_value = "llama3")]
-    pub ai_model: String,
+    /// Bearer token for an OpenAI-compatible gateway (requires --ai-provider openai-compatible)
+    #[arg(long, requires = "ai_provider")]
+    pub ai_api_key: Option<String>,
 
     /// Minutes between AI insight refreshes in watch mode (1-60, default 2)
     #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..=60))]
@@ -19,6 +19,10 @@ fn default_time_sync_server() -> String {
     String::new() // Empty means use default servers
 }
 
+fn default_event_window_hours() -> u32 {
+    12
+}
+
 fn default_ai_server() -> String {
     "http://localhost:11434".to_string()
 }
@@ -49,6 +53,32 @@ pub enum AiRefreshMode {
     ManualOnly,
 }
 
+/// API spoken by the AI insights server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AiProvider {
+    /// Ollama's native API (`/api/generate`, `/api/tags`)
+    #[default]
+    Ollama,
+    /// OpenAI-compatible gateway (`/v1/chat/completions`, `/v1/models`)
+    #[serde(rename = "openai_compatible")]
+    OpenAiCompatible {
+        /// Sent as a bearer token; empty means no `Authorization` header
+        #[serde(default)]
+        api_key: String,
+    },
+}
+
+impl AiProvider {
+    /// Short name for status and error messages.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Ollama => "Ollama",
+            Self::OpenAiCompatible { .. } => "OpenAI-compatible",
+        }
+    }
+}
+
 /// Time synchronization settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -59,6 +89,12 @@ pub struct TimeSyncSettings {
     /// NTP server URL (empty = use default servers)
     #[serde(default = "default_time_sync_server")]
     pub server: String,
+    /// Additional NTP servers tried in order after `server` fails
+    #[serde(default)]
+    pub servers: Vec<String>,
+    /// Shift the watch-mode clock by the measured offset (opt-in)
+    #[serde(default = "default_false")]
+    pub apply_offset: bool,
 }
 
 impl Default for TimeSyncSettings {
@@ -66,10 +102,24 @@ impl Default for TimeSyncSettings {
         Self {
             enabled: true,
             server: default_time_sync_server(),
+            servers: Vec::new(),
+            apply_offset: false,
         }
     }
 }
 
+impl TimeSyncSettings {
+    /// All configured servers in query order: `server` first, then `servers`.
+    pub fn server_list(&self) -> Vec<String> {
+        std::iter::once(&self.server)
+            .chain(&self.servers)
+            .map(|server| server.trim())
+            .filter(|server| !server.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
 /// AI insights settings (for integration with local Ollama server).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -89,6 +139,9 @@ pub struct AiSettings {
     /// Refresh mode (auto or manual only)
     #[serde(default)]
     pub refresh_mode: AiRefreshMode,
+    /// Server API (Ollama or an OpenAI-compatible gateway)
+    #[serde(default)]
+    pub provider: AiProvider,
 }
 
 impl Default for AiSettings {
@@ -99,6 +152,7 @@ impl Default for AiSettings {
             model: default_ai_model(),
             refresh_minutes: 2,
             refresh_mode: AiRefreshMode::AutoAndManual,
+            provider: AiProvider::Ollama,
         }
     }
 }
@@ -120,6 +174,9 @@ pub struct WatchPreferences {
     pub show_ai_insights: bool,
     #[serde(default = "default_false")]
     pub night_mode: bool,
+    /// Hours before and after now to list events for
+    #[serde(default = "default_event_window_hours")]
+    pub event_window_hours: u32,
 }
 
 impl Default for WatchPreferences {
@@ -132,6 +189,7 @@ impl Default for WatchPreferences {
             show_lunar_phases: true,
             show_ai_insights: false,
             night_mode: false,
+            event_window_hours: default_event_window_hours(),
         }
     }
 }
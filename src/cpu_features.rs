@@ -159,6 +159,49 @@ pub mod runtime {
     }
 }
 
+/// Instruction-set extensions detected on the running CPU.
+///
+/// Names follow `std::arch` feature naming (e.g. "sse2", "avx2", "neon"), listed
+/// from oldest to newest. Unlike [`compile_time`], this reflects the machine the
+/// binary runs on, which makes it suitable for logs and support reports.
+pub fn detected_features() -> Vec<&'static str> {
+    #[allow(unused_mut)]
+    let mut features = Vec::new();
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        macro_rules! probe {
+            ($($name:tt),*) => {
+                $(if std::arch::is_x86_feature_detected!($name) {
+                    features.push($name);
+                })*
+            };
+        }
+        probe!("sse2", "sse3", "ssse3", "sse4.1", "sse4.2", "avx", "fma", "avx2", "avx512f");
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        macro_rules! probe {
+            ($($name:tt),*) => {
+                $(if std::arch::is_aarch64_feature_detected!($name) {
+                    features.push($name);
+                })*
+            };
+        }
+        probe!("neon", "fp16", "sve", "sve2");
+    }
+
+    features
+}
+
+/// Whether the running CPU supports `name` (case-insensitive, see [`detected_features`]).
+pub fn has_feature(name: &str) -> bool {
+    detected_features()
+        .iter()
+        .any(|feature| feature.eq_ignore_ascii_case(name))
+}
+
 /// Optimization selection based on CPU features
 #[derive(Debug, Clone)]
 pub struct OptimizationProfile {
@@ -171,10 +214,60 @@ pub struct OptimizationProfile {
     pub simd_width: usize,
 }
 
+/// Environment variable that forces a profile: `scalar`, `sse2`, `avx2` or `neon`
+pub const FORCE_PROFILE_ENV: &str = "ASTROTIMES_FORCE_PROFILE";
+
 impl OptimizationProfile {
     /// Select the best optimization profile for current CPU
+    ///
+    /// Setting [`FORCE_PROFILE_ENV`] (`ASTROTIMES_FORCE_PROFILE`) to `scalar`, `sse2`,
+    /// `avx2` or `neon` selects that profile instead, e.g. to benchmark the scalar
+    /// path on a SIMD-capable machine. Unknown values, or a profile for another
+    /// architecture, fall back to detection.
     pub fn current() -> Self {
         let parallelism = runtime::physical_cpu_count();
+        std::env::var(FORCE_PROFILE_ENV)
+            .ok()
+            .and_then(|name| Self::forced(&name, parallelism))
+            .unwrap_or_else(|| Self::detected(parallelism))
+    }
+
+    /// Profile named by [`FORCE_PROFILE_ENV`], if valid on this architecture
+    fn forced(name: &str, parallelism: usize) -> Option<Self> {
+        let scalar = Self {
+            name: "Scalar",
+            has_avx2: false,
+            has_neon: false,
+            has_sve: false,
+            has_avx512: false,
+            parallelism,
+            simd_width: 1,
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "scalar" => Some(scalar),
+            "sse2" if compile_time::IS_X86_64 => Some(Self {
+                name: "x86_64-SSE2",
+                simd_width: 4, // 128-bit / 32-bit = 4 floats
+                ..scalar
+            }),
+            "avx2" if compile_time::IS_X86_64 => Some(Self {
+                name: "x86_64-AVX2",
+                has_avx2: true,
+                simd_width: 8,
+                ..scalar
+            }),
+            "neon" if compile_time::IS_AARCH64 => Some(Self {
+                name: "ARM64-NEON",
+                has_neon: true,
+                simd_width: 4,
+                ..scalar
+            }),
+            _ => None,
+        }
+    }
+
+    /// Profile chosen from the compile-time target features
+    fn detected(parallelism: usize) -> Self {
 
         #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
         return Self {
@@ -327,4 +420,37 @@ mod tests {
         let is_arm = compile_time::IS_AARCH64;
         assert!(is_x86 || is_arm); // At least one must be true
     }
+
+    #[test]
+    fn test_detected_features() {
+        let features = detected_features();
+        if compile_time::IS_X86_64 {
+            // SSE2 is part of the x86_64 baseline
+            assert!(features.contains(&"sse2"), "{:?}", features);
+            assert!(has_feature("SSE2"));
+        }
+        if compile_time::IS_AARCH64 {
+            assert!(has_feature("neon"));
+        }
+        assert!(!has_feature("not-a-feature"));
+    }
+
+    #[test]
+    fn test_forced_profile() {
+        assert_eq!(OptimizationProfile::forced("Scalar", 2).unwrap().name, "Scalar");
+        assert!(OptimizationProfile::forced("mmx", 2).is_none());
+        assert_eq!(
+            OptimizationProfile::forced("avx2", 2).is_some(),
+            compile_time::IS_X86_64
+        );
+
+        // Other tests may read the profile concurrently; scalar keeps their
+        // assertions (parallelism and width above zero) valid
+        std::env::set_var(FORCE_PROFILE_ENV, "scalar");
+        let profile = OptimizationProfile::current();
+        std::env::remove_var(FORCE_PROFILE_ENV);
+        assert_eq!(profile.name, "Scalar");
+        assert!(!profile.has_simd());
+        assert_eq!(profile.simd_width, 1);
+    }
 }
@@ -6,96 +6,170 @@
 use chrono::{DateTime, Duration, TimeZone};
 use chrono_tz::Tz;
 
-use crate::astro::{moon, sun, Location};
+use crate::astro::{moon, moon_batch_optimized, sun, Location};
+
+/// Kind of event in the watch-mode events list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AstroEventKind {
+    SolarNoon,
+    EveningGoldenHourStart,
+    Sunset,
+    EveningGoldenHourEnd,
+    CivilDusk,
+    NauticalDusk,
+    AstronomicalDusk,
+    AstronomicalDawn,
+    NauticalDawn,
+    CivilDawn,
+    GoldenHourStart,
+    Sunrise,
+    GoldenHourEnd,
+    Moonrise,
+    Moonset,
+    /// Start of a Deep Sky Darkness window (sun below -18°, moon down)
+    DarkWindowStart,
+    /// End of a Deep Sky Darkness window
+    DarkWindowEnd,
+}
+
+impl AstroEventKind {
+    /// Display label used in the events list, e.g. "🌅 Sunrise".
+    pub fn label(&self) -> &'static str {
+        match self {
+            AstroEventKind::SolarNoon => "☀️ Solar noon",
+            AstroEventKind::EveningGoldenHourStart => "📸 Golden hr start",
+            AstroEventKind::Sunset => "🌇 Sunset",
+            AstroEventKind::EveningGoldenHourEnd => "📸 Golden hr end",
+            AstroEventKind::CivilDusk => "🌆 Civil dusk",
+            AstroEventKind::NauticalDusk => "⛵ Nautical dusk",
+            AstroEventKind::AstronomicalDusk => "🌠 Astro dusk",
+            AstroEventKind::AstronomicalDawn => "🔭 Astro dawn",
+            AstroEventKind::NauticalDawn => "⚓ Nautical dawn",
+            AstroEventKind::CivilDawn => "🏙️ Civil dawn",
+            AstroEventKind::GoldenHourStart => "📸 Golden hr start",
+            AstroEventKind::Sunrise => "🌅 Sunrise",
+            AstroEventKind::GoldenHourEnd => "📸 Golden hr end",
+            AstroEventKind::Moonrise => "🌕 Moonrise",
+            AstroEventKind::Moonset => "🌑 Moonset",
+            AstroEventKind::DarkWindowStart => "🌌 Dark win start",
+            AstroEventKind::DarkWindowEnd => "🌄 Dark win end",
+        }
+    }
+
+    /// Label without the leading emoji, e.g. "Sunrise" or "Civil dawn".
+    pub fn name(&self) -> &'static str {
+        self.label()
+            .trim_start_matches(|c: char| !c.is_ascii_alphabetic())
+    }
 
-#[derive(Clone, Copy)]
-enum EventSource {
-    Solar(sun::SolarEvent),
-    Moon(moon::LunarEvent),
+    /// Whether the label's emoji renders wider than its width suggests, needing an
+    /// extra space to keep the following column aligned.
+    pub fn has_wide_emoji(&self) -> bool {
+        matches!(self, AstroEventKind::CivilDawn | AstroEventKind::SolarNoon)
+    }
+
+    fn time_on(&self, location: &Location, date: &DateTime<Tz>) -> Option<DateTime<Tz>> {
+        use moon::LunarEvent;
+        use sun::SolarEvent;
+
+        let solar = |event| sun::solar_event_time(location, date, event);
+        match self {
+            AstroEventKind::SolarNoon => solar(SolarEvent::SolarNoon),
+            AstroEventKind::EveningGoldenHourStart => solar(SolarEvent::EveningGoldenHourStart),
+            AstroEventKind::Sunset => solar(SolarEvent::Sunset),
+            AstroEventKind::EveningGoldenHourEnd => solar(SolarEvent::EveningGoldenHourEnd),
+            AstroEventKind::CivilDusk => solar(SolarEvent::CivilDusk),
+            AstroEventKind::NauticalDusk => solar(SolarEvent::NauticalDusk),
+            AstroEventKind::AstronomicalDusk => solar(SolarEvent::AstronomicalDusk),
+            AstroEventKind::AstronomicalDawn => solar(SolarEvent::AstronomicalDawn),
+            AstroEventKind::NauticalDawn => solar(SolarEvent::NauticalDawn),
+            AstroEventKind::CivilDawn => solar(SolarEvent::CivilDawn),
+            AstroEventKind::GoldenHourStart => solar(SolarEvent::GoldenHourStart),
+            AstroEventKind::Sunrise => solar(SolarEvent::Sunrise),
+            AstroEventKind::GoldenHourEnd => solar(SolarEvent::GoldenHourEnd),
+            AstroEventKind::Moonrise => {
+                moon::lunar_event_time(location, date, LunarEvent::Moonrise)
+            }
+            AstroEventKind::Moonset => moon::lunar_event_time(location, date, LunarEvent::Moonset),
+            // Dark windows come from sampling, not a single-day event search
+            AstroEventKind::DarkWindowStart | AstroEventKind::DarkWindowEnd => None,
+        }
+    }
 }
 
-#[derive(Clone, Copy)]
-struct EventDefinition {
-    label: &'static str,
-    source: EventSource,
+impl std::fmt::Display for AstroEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
 }
 
-const EVENT_DEFINITIONS: &[EventDefinition] = &[
-    EventDefinition {
-        label: "☀️ Solar noon",
-        source: EventSource::Solar(sun::SolarEvent::SolarNoon),
-    },
-    EventDefinition {
-        label: "🌇 Sunset",
-        source: EventSource::Solar(sun::SolarEvent::Sunset),
-    },
-    EventDefinition {
-        label: "🌕 Moonrise",
-        source: EventSource::Moon(moon::LunarEvent::Moonrise),
-    },
-    EventDefinition {
-        label: "🌆 Civil dusk",
-        source: EventSource::Solar(sun::SolarEvent::CivilDusk),
-    },
-    EventDefinition {
-        label: "⛵ Nautical dusk",
-        source: EventSource::Solar(sun::SolarEvent::NauticalDusk),
-    },
-    EventDefinition {
-        label: "🌠 Astro dusk",
-        source: EventSource::Solar(sun::SolarEvent::AstronomicalDusk),
-    },
-    EventDefinition {
-        label: "🔭 Astro dawn",
-        source: EventSource::Solar(sun::SolarEvent::AstronomicalDawn),
-    },
-    EventDefinition {
-        label: "⚓ Nautical dawn",
-        source: EventSource::Solar(sun::SolarEvent::NauticalDawn),
-    },
-    EventDefinition {
-        label: "🏙️ Civil dawn",
-        source: EventSource::Solar(sun::SolarEvent::CivilDawn),
-    },
-    EventDefinition {
-        label: "🌅 Sunrise",
-        source: EventSource::Solar(sun::SolarEvent::Sunrise),
-    },
-    EventDefinition {
-        label: "🌑 Moonset",
-        source: EventSource::Moon(moon::LunarEvent::Moonset),
-    },
+/// Events found by a per-day search, in the order they are evaluated.
+const SEARCHED_EVENTS: &[AstroEventKind] = &[
+    AstroEventKind::SolarNoon,
+    AstroEventKind::EveningGoldenHourStart,
+    AstroEventKind::Sunset,
+    AstroEventKind::EveningGoldenHourEnd,
+    AstroEventKind::Moonrise,
+    AstroEventKind::CivilDusk,
+    AstroEventKind::NauticalDusk,
+    AstroEventKind::AstronomicalDusk,
+    AstroEventKind::AstronomicalDawn,
+    AstroEventKind::NauticalDawn,
+    AstroEventKind::CivilDawn,
+    AstroEventKind::GoldenHourStart,
+    AstroEventKind::Sunrise,
+    AstroEventKind::GoldenHourEnd,
+    AstroEventKind::Moonset,
 ];
 
+/// Every [`SEARCHED_EVENTS`] entry that occurs on the local date of `day`.
+///
+/// Moonrise and moonset share one altitude sweep via
+/// [`moon_batch_optimized::batch_search_rise_and_set`], which returns the same
+/// times as separate [`moon::lunar_event_time`] calls.
+fn events_on_day(location: &Location, day: &DateTime<Tz>) -> Vec<(DateTime<Tz>, AstroEventKind)> {
+    let threshold = moon::rise_set_altitude(day) - location.horizon_dip_degrees();
+    let moon_events = moon_batch_optimized::batch_search_rise_and_set(location, day, threshold);
+
+    SEARCHED_EVENTS
+        .iter()
+        .filter_map(|kind| {
+            let time = match kind {
+                AstroEventKind::Moonrise => moon_events.moonrise,
+                AstroEventKind::Moonset => moon_events.moonset,
+                _ => kind.time_on(location, day),
+            };
+            Some((time?, *kind))
+        })
+        .collect()
+}
+
 /// Collect sun and moon events that fall within a symmetrical time window around the reference.
+///
+/// Windows longer than a day search as many neighbouring days as needed, so a 48-hour
+/// window still catches the next sunset during long polar days.
 pub fn collect_events_within_window(
     location: &Location,
     reference: &DateTime<Tz>,
     window: Duration,
-) -> Vec<(DateTime<Tz>, &'static str)> {
+) -> Vec<(DateTime<Tz>, AstroEventKind)> {
     let max_delta = window.num_seconds().abs();
+    let day_span = window.num_days().abs() + 1;
     let mut events = Vec::new();
 
-    for offset in -1..=1 {
+    for offset in -day_span..=day_span {
         let shifted = if offset == 0 {
             *reference
         } else {
             reference
-                .checked_add_signed(Duration::days(offset as i64))
+                .checked_add_signed(Duration::days(offset))
                 .unwrap_or(*reference)
         };
 
-        for definition in EVENT_DEFINITIONS {
-            let maybe_time = match definition.source {
-                EventSource::Solar(event) => sun::solar_event_time(location, &shifted, event),
-                EventSource::Moon(event) => moon::lunar_event_time(location, &shifted, event),
-            };
-
-            if let Some(event_time) = maybe_time {
-                let delta = event_time.signed_duration_since(reference);
-                if delta.num_seconds().abs() <= max_delta {
-                    events.push((event_time, definition.label));
-                }
+        for (event_time, kind) in events_on_day(location, &shifted) {
+            let delta = event_time.signed_duration_since(reference);
+            if delta.num_seconds().abs() <= max_delta {
+                events.push((event_time, kind));
             }
         }
     }
@@ -103,11 +177,11 @@ pub fn collect_events_within_window(
     // Extract astro dawn and dusk times from collected events
     let astro_dawn = events
         .iter()
-        .find(|(_, label)| label.contains("Astro dawn"))
+        .find(|(_, kind)| *kind == AstroEventKind::AstronomicalDawn)
         .map(|(dt, _)| *dt);
     let astro_dusk = events
         .iter()
-        .find(|(_, label)| label.contains("Astro dusk"))
+        .find(|(_, kind)| *kind == AstroEventKind::AstronomicalDusk)
         .map(|(dt, _)| *dt);
 
     // Add dark window events using the actual astro twilight times
@@ -121,6 +195,23 @@ pub fn collect_events_within_window(
     events
 }
 
+/// Find the first sun or moon event strictly after `after`.
+///
+/// Considers the same events as [`collect_events_within_window`] (without dark
+/// windows), searching up to two days ahead so days without a moonrise or moonset
+/// are skipped. Returns `None` only when nothing occurs in that span, e.g. in
+/// polar conditions with the moon circumpolar.
+pub fn next_event(
+    location: &Location,
+    after: &DateTime<Tz>,
+) -> Option<(DateTime<Tz>, AstroEventKind)> {
+    (0..=2)
+        .filter_map(|offset| after.checked_add_signed(Duration::days(offset)))
+        .flat_map(|day| events_on_day(location, &day))
+        .filter(|(time, _)| time > after)
+        .min_by_key(|(time, _)| *time)
+}
+
 /// Check if the moon is sufficiently dark with buffer for moon glow.
 ///
 /// Returns true if:
@@ -174,7 +265,7 @@ fn calculate_dark_windows(
     window: Duration,
     astro_dawn: Option<DateTime<Tz>>,
     astro_dusk: Option<DateTime<Tz>>,
-) -> Vec<(DateTime<Tz>, &'static str)> {
+) -> Vec<(DateTime<Tz>, AstroEventKind)> {
     const MOON_GLOW_BUFFER_MINUTES: i64 = 15; // Buffer for moon glow to fade
     const SAMPLE_INTERVAL_MINUTES: i64 = 1; // 1-minute sampling for precision
 
@@ -214,7 +305,7 @@ fn calculate_dark_windows(
                         // Verify it's near our transition point
                         let time_diff = dusk_time.signed_duration_since(prev_time).num_seconds().abs();
                         if time_diff <= 120 {
-                            events.push((*dusk_time, "🌌 Dark win start"));
+                            events.push((*dusk_time, AstroEventKind::DarkWindowStart));
                             in_dark_window = true;
                             first_sample = false;
                             prev_time = current_time;
@@ -233,7 +324,7 @@ fn calculate_dark_windows(
                     &current_time,
                     true,
                 );
-                events.push((refined_time, "🌌 Dark win start"));
+                events.push((refined_time, AstroEventKind::DarkWindowStart));
             }
             in_dark_window = true;
         } else if !is_dark && in_dark_window {
@@ -244,7 +335,7 @@ fn calculate_dark_windows(
                     if let Some(ref dawn_time) = astro_dawn {
                         let time_diff = dawn_time.signed_duration_since(prev_time).num_seconds().abs();
                         if time_diff <= 120 {
-                            events.push((*dawn_time, "🌄 Dark win end"));
+                            events.push((*dawn_time, AstroEventKind::DarkWindowEnd));
                             in_dark_window = false;
                             first_sample = false;
                             prev_time = current_time;
@@ -263,7 +354,7 @@ fn calculate_dark_windows(
                     &current_time,
                     false,
                 );
-                events.push((refined_time, "🌄 Dark win end"));
+                events.push((refined_time, AstroEventKind::DarkWindowEnd));
             }
             in_dark_window = false;
         }
@@ -324,3 +415,138 @@ fn refine_dark_window_transition(
     let mid_seconds = (left.timestamp() + right.timestamp()) / 2;
     left.timezone().timestamp_opt(mid_seconds, 0).unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_event_after_noon_leads_to_sunset_not_sunrise() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let noon = tz.with_ymd_and_hms(2025, 9, 20, 12, 0, 0).unwrap();
+        let sunset = sun::solar_event_time(&location, &noon, sun::SolarEvent::Sunset).unwrap();
+
+        // Solar noon comes first (~12:50 EDT); stepping through events reaches sunset
+        // the same evening without passing tomorrow's sunrise
+        let (first, kind) = next_event(&location, &noon).unwrap();
+        assert_eq!(kind, AstroEventKind::SolarNoon);
+
+        let mut cursor = first;
+        loop {
+            let (time, kind) = next_event(&location, &cursor).unwrap();
+            assert!(time > cursor);
+            assert_ne!(kind, AstroEventKind::Sunrise);
+            if kind == AstroEventKind::Sunset {
+                assert_eq!(time, sunset);
+                break;
+            }
+            cursor = time;
+        }
+    }
+
+    #[test]
+    fn window_includes_golden_hours_in_order() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let noon = tz.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap();
+        let evening_start =
+            sun::solar_event_time(&location, &noon, sun::SolarEvent::EveningGoldenHourStart)
+                .unwrap();
+        let reference = evening_start - Duration::minutes(1);
+
+        let events = collect_events_within_window(&location, &reference, Duration::hours(12));
+        assert!(events.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+
+        let times = |wanted: &[AstroEventKind]| -> Vec<DateTime<Tz>> {
+            events
+                .iter()
+                .filter(|(_, kind)| wanted.contains(kind))
+                .map(|(time, _)| *time)
+                .collect()
+        };
+        use AstroEventKind::*;
+        let starts = times(&[GoldenHourStart, EveningGoldenHourStart]);
+        let ends = times(&[GoldenHourEnd, EveningGoldenHourEnd]);
+        let sunrise = times(&[Sunrise])[0];
+        let sunset = times(&[Sunset])[0];
+        assert_eq!((starts.len(), ends.len()), (2, 2), "{:?}", events);
+        // Tonight's golden hour brackets sunset; tomorrow morning's brackets sunrise
+        assert!(starts[0] < sunset && sunset < ends[0]);
+        assert!(starts[1] < sunrise && sunrise < ends[1]);
+
+        // The "(next)" marker lands on the evening golden hour
+        let next = events.iter().position(|(time, _)| *time > reference).unwrap();
+        assert_eq!(events[next], (evening_start, EveningGoldenHourStart));
+    }
+
+    #[test]
+    fn collected_moon_events_match_per_event_search() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let tz: Tz = "Europe/London".parse().unwrap();
+
+        for day in [1, 8, 15, 22] {
+            let reference = tz.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap();
+            let window = Duration::hours(36);
+            let collected: Vec<_> = collect_events_within_window(&location, &reference, window)
+                .into_iter()
+                .filter(|(_, kind)| {
+                    matches!(kind, AstroEventKind::Moonrise | AstroEventKind::Moonset)
+                })
+                .collect();
+
+            let mut expected = Vec::new();
+            for offset in -1..=1 {
+                let shifted = reference + Duration::days(offset);
+                for (event, kind) in [
+                    (moon::LunarEvent::Moonrise, AstroEventKind::Moonrise),
+                    (moon::LunarEvent::Moonset, AstroEventKind::Moonset),
+                ] {
+                    if let Some(time) = moon::lunar_event_time(&location, &shifted, event) {
+                        if (time - reference).num_seconds().abs() <= window.num_seconds() {
+                            expected.push((time, kind));
+                        }
+                    }
+                }
+            }
+            expected.sort_by_key(|(time, _)| *time);
+
+            assert!(!expected.is_empty());
+            assert_eq!(collected, expected, "reference {}", reference);
+        }
+    }
+
+    #[test]
+    fn longer_window_finds_more_polar_summer_events() {
+        // Tromsø under the midnight sun: no sunrise, sunset or twilight at all
+        let location = Location::new(69.6492, 18.9553).unwrap();
+        let tz: Tz = "Europe/Oslo".parse().unwrap();
+        let reference = tz.with_ymd_and_hms(2025, 6, 21, 18, 0, 0).unwrap();
+
+        let narrow = collect_events_within_window(&location, &reference, Duration::hours(6));
+        let wide = collect_events_within_window(&location, &reference, Duration::hours(24));
+        assert!(wide.len() > narrow.len(), "6h {:?} vs 24h {:?}", narrow, wide);
+        assert!(narrow.iter().all(|event| wide.contains(event)));
+        assert!(wide.iter().all(|(time, _)| (*time - reference).num_hours().abs() <= 24));
+    }
+
+    #[test]
+    fn event_kinds_carry_labels_and_names() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let tz: Tz = "Europe/London".parse().unwrap();
+        let noon = tz.with_ymd_and_hms(2025, 3, 20, 12, 0, 0).unwrap();
+
+        for (_, kind) in collect_events_within_window(&location, &noon, Duration::hours(12)) {
+            let expected = match kind {
+                AstroEventKind::Sunrise => "Sunrise",
+                AstroEventKind::CivilDawn => "Civil dawn",
+                AstroEventKind::AstronomicalDusk => "Astro dusk",
+                AstroEventKind::DarkWindowStart => "Dark win start",
+                _ => continue,
+            };
+            assert_eq!(kind.name(), expected);
+            assert!(kind.to_string().ends_with(expected));
+        }
+        assert_eq!(AstroEventKind::SolarNoon.label(), "☀️ Solar noon");
+    }
+}
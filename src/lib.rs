@@ -74,6 +74,17 @@
 //! let civil_dusk = solar_event_time(&location, &now, SolarEvent::CivilDusk);
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! All of these are enabled by default. Library-only consumers can depend on the
+//! crate with `default-features = false` to drop the terminal and network stacks;
+//! the `astro`, `city`, `calendar` and `events` modules are always available.
+//!
+//! - `net` - NTP time sync (`time_sync`) and USNO validation downloads (pulls in `reqwest`)
+//! - `ai` - Ollama insights and JSON output (`ai`, `output`); implies `net`
+//! - `tui` - the interactive terminal UI (pulls in `ratatui` and `crossterm`); implies `ai`.
+//!   The `solunatus` binary requires it.
+//!
 //! ## Architecture Support
 //!
 //! Solunatus is optimized for all Rust tier 1 targets:
@@ -93,14 +104,19 @@ pub mod astro;
 pub mod city;
 pub mod config;
 pub mod events;
+pub mod location;
 pub mod location_source;
+#[cfg(feature = "ai")]
 pub mod output;
 
 // Optional modules for advanced use cases
+#[cfg(feature = "ai")]
 pub mod ai;
 pub mod benchmark;
 pub mod calendar;
+#[cfg(feature = "net")]
 pub mod time_sync;
+#[cfg(feature = "net")]
 pub mod usno_validation;
 
 // Internal modules (used by binary - not part of public API)
@@ -110,20 +126,35 @@ pub mod calendar_optimized;
 pub mod cli;
 #[doc(hidden)]
 pub mod cpu_features;
+#[cfg(feature = "tui")]
 #[doc(hidden)]
 pub mod tui;
 
 // Re-export key types at crate root for convenience
-pub use astro::{julian_century, julian_day, normalize_degrees, normalize_degrees_signed, Location};
+pub use astro::{
+    julian_century, julian_day, normalize_degrees, normalize_degrees_signed, Location,
+    LocationBuilder, LocationError,
+};
 pub use city::{City, CityDatabase};
 pub use config::Config;
 
 // Re-export essential astronomical types
-pub use astro::sun::{solar_event_time, solar_noon, solar_position, SolarEvent, SolarPosition};
+pub use astro::sun::{
+    air_mass, clear_sky_irradiance, in_twilight, is_sun_up, local_apparent_solar_time,
+    local_mean_solar_time, shadow_length_ratio, sky_state, solar_event_status, solar_event_time,
+    solar_event_time_with_model, solar_event_time_with_refraction, solar_hour_angle, solar_noon,
+    solar_noon_altitude, solar_position, solar_positions_batch, sun_ecliptic_longitude,
+    time_since_sunrise, time_until_sunset, twilight_windows, RefractionModel, RefractionParams,
+    SkyState, SolarEvent, SolarEventResult, SolarPosition, TwilightKind, TwilightWindows,
+};
 pub use astro::moon::{
-    lunar_event_time, lunar_phases, lunar_position, phase_emoji, phase_name, LunarEvent,
-    LunarPhase, LunarPhaseType, LunarPosition,
+    full_moon_name, is_black_moon_month, is_blue_moon_month, is_moon_up, lunar_event_time,
+    lunar_event_time_with_model, lunar_hour_angle, lunar_phases, lunar_position,
+    lunar_position_geocentric, lunar_transit, moon_ecliptic_longitude, moon_phase_at, next_phase,
+    phase_emoji, phase_name, previous_phase, LunarEvent, LunarPhase, LunarPhaseType, LunarPosition,
+    MoonPhaseInfo,
 };
+pub use astro::moon_batch_optimized::lunar_positions_batch;
 
 /// Prelude module containing the most commonly used types and functions.
 ///
@@ -133,17 +164,22 @@ pub use astro::moon::{
 /// use solunatus::prelude::*;
 /// ```
 pub mod prelude {
-    pub use crate::astro::sun::{SolarEvent, SolarPosition};
+    pub use crate::astro::sun::{
+        day_length, sky_state, SkyState, SolarEvent, SolarPosition, TwilightKind,
+    };
     pub use crate::astro::moon::{LunarEvent, LunarPhase, LunarPhaseType, LunarPosition};
-    pub use crate::astro::Location;
+    pub use crate::astro::seasons::{season, Hemisphere, Season};
+    pub use crate::astro::{Location, LocationError};
     pub use crate::city::{City, CityDatabase};
 
     // Convenience functions
     pub use crate::{
-        batch_calculate, calculate_civil_dawn, calculate_civil_dusk, calculate_moonrise,
-        calculate_moonset, calculate_solar_noon, calculate_sunrise, calculate_sunset,
-        get_current_moon_phase, get_lunar_phases_for_month, lunar_position, solar_position,
-        BatchResult,
+        batch_calculate, batch_calculate_iter, calculate_civil_dawn, calculate_civil_dusk,
+        calculate_evening_blue_hour, calculate_evening_golden_hour, calculate_moonrise,
+        calculate_moonset, calculate_morning_blue_hour, calculate_morning_golden_hour,
+        calculate_solar_noon, calculate_sunrise, calculate_sunset, get_current_moon_phase,
+        get_lunar_phases_for_month, in_twilight, is_moon_up, is_sun_up, lunar_position,
+        solar_position, BatchResult,
     };
 }
 
@@ -153,6 +189,8 @@ pub mod prelude {
 
 use anyhow::Result;
 use chrono::{DateTime, TimeZone};
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 /// Calculate sunrise time for a given location and date.
 ///
@@ -215,6 +253,58 @@ pub fn calculate_civil_dusk<Tz: TimeZone>(
     solar_event_time(location, date, SolarEvent::CivilDusk)
 }
 
+/// Calculate the morning golden hour (sun between -4° and +6°, rising).
+///
+/// Returns `(start, end)`, or `None` if the sun doesn't cross both altitudes.
+pub fn calculate_morning_golden_hour<Tz: TimeZone>(
+    location: &Location,
+    date: &DateTime<Tz>,
+) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+    Some((
+        solar_event_time(location, date, SolarEvent::GoldenHourStart)?,
+        solar_event_time(location, date, SolarEvent::GoldenHourEnd)?,
+    ))
+}
+
+/// Calculate the evening golden hour (sun between +6° and -4°, setting).
+///
+/// Returns `(start, end)`, or `None` if the sun doesn't cross both altitudes.
+pub fn calculate_evening_golden_hour<Tz: TimeZone>(
+    location: &Location,
+    date: &DateTime<Tz>,
+) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+    Some((
+        solar_event_time(location, date, SolarEvent::EveningGoldenHourStart)?,
+        solar_event_time(location, date, SolarEvent::EveningGoldenHourEnd)?,
+    ))
+}
+
+/// Calculate the morning blue hour (sun between -6° and -4°, rising).
+///
+/// Returns `(start, end)`, or `None` if the sun doesn't cross both altitudes.
+pub fn calculate_morning_blue_hour<Tz: TimeZone>(
+    location: &Location,
+    date: &DateTime<Tz>,
+) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+    Some((
+        solar_event_time(location, date, SolarEvent::BlueHourStart)?,
+        solar_event_time(location, date, SolarEvent::BlueHourEnd)?,
+    ))
+}
+
+/// Calculate the evening blue hour (sun between -4° and -6°, setting).
+///
+/// Returns `(start, end)`, or `None` if the sun doesn't cross both altitudes.
+pub fn calculate_evening_blue_hour<Tz: TimeZone>(
+    location: &Location,
+    date: &DateTime<Tz>,
+) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+    Some((
+        solar_event_time(location, date, SolarEvent::EveningBlueHourStart)?,
+        solar_event_time(location, date, SolarEvent::EveningBlueHourEnd)?,
+    ))
+}
+
 /// Calculate moonrise time for a given location and date.
 ///
 /// Returns `None` if the moon doesn't rise on this date.
@@ -301,9 +391,14 @@ pub fn get_lunar_phases_for_month(year: i32, month: u32) -> Result<Vec<LunarPhas
 // Batch Processing Functions (Optimized)
 // ============================================================================
 
+/// Inputs longer than this are spread across threads by [`batch_calculate`]
+const PARALLEL_BATCH_THRESHOLD: usize = 64;
+
 /// Calculate sun and moon data for multiple dates efficiently.
 ///
-/// This function uses parallel processing and SIMD optimizations when available.
+/// Batches of more than 64 dates are processed in parallel with rayon; smaller
+/// batches run sequentially, where thread overhead would outweigh the gain.
+/// Results are returned in input order either way.
 /// Useful for generating calendars or batch processing.
 ///
 /// # Examples
@@ -329,33 +424,62 @@ pub fn batch_calculate<Tz: TimeZone>(
     dates: &[DateTime<Tz>],
 ) -> Vec<BatchResult<Tz>>
 where
-    Tz: Clone,
+    Tz: Clone + Send + Sync,
+    Tz::Offset: Send + Sync,
 {
-    dates
-        .iter()
-        .map(|date| {
-            let sun_pos = solar_position(location, date);
-            let moon_pos = lunar_position(location, date);
-            let sunrise = calculate_sunrise(location, date);
-            let sunset = calculate_sunset(location, date);
-            let moonrise = calculate_moonrise(location, date);
-            let moonset = calculate_moonset(location, date);
-
-            BatchResult {
-                date: date.clone(),
-                sun_position: sun_pos,
-                moon_position: moon_pos,
-                sunrise,
-                sunset,
-                moonrise,
-                moonset,
-            }
-        })
-        .collect()
+    use rayon::prelude::*;
+
+    if dates.len() > PARALLEL_BATCH_THRESHOLD {
+        dates
+            .par_iter()
+            .map(|date| batch_entry(location, date))
+            .collect()
+    } else {
+        batch_calculate_iter(location, dates).collect()
+    }
+}
+
+/// Lazily calculate sun and moon data for each date, in order.
+///
+/// Each [`BatchResult`] is computed only when the iterator reaches it, so callers can
+/// stream very long date ranges or stop early with `.take()` without allocating the
+/// whole batch. Runs sequentially; see [`batch_calculate`] for the parallel version.
+pub fn batch_calculate_iter<'a, Tz: TimeZone>(
+    location: &'a Location,
+    dates: &'a [DateTime<Tz>],
+) -> impl Iterator<Item = BatchResult<Tz>> + 'a {
+    dates.iter().map(move |date| batch_entry(location, date))
+}
+
+fn batch_entry<Tz: TimeZone>(location: &Location, date: &DateTime<Tz>) -> BatchResult<Tz> {
+    let sunrise = calculate_sunrise(location, date);
+    let sunset = calculate_sunset(location, date);
+    let day_length = match (&sunrise, &sunset) {
+        (Some(rise), Some(set)) if set > rise => Some(set.clone() - rise.clone()),
+        // Sunset before sunrise in local time: day_length looks ahead to the next sunset
+        (Some(_), Some(_)) => astro::sun::day_length(location, date),
+        _ => None,
+    };
+
+    BatchResult {
+        date: date.clone(),
+        sun_position: solar_position(location, date),
+        moon_position: lunar_position(location, date),
+        civil_dawn: calculate_civil_dawn(location, date),
+        civil_dusk: calculate_civil_dusk(location, date),
+        day_length,
+        sunrise,
+        sunset,
+        moonrise: calculate_moonrise(location, date),
+        moonset: calculate_moonset(location, date),
+    }
 }
 
 /// Result from batch calculations.
+///
+/// With the `serde` feature, serializes with RFC 3339 timestamps and `day_length` as whole seconds.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize), serde(bound(serialize = "")))]
 pub struct BatchResult<Tz: TimeZone> {
     pub date: DateTime<Tz>,
     pub sun_position: SolarPosition,
@@ -364,6 +488,22 @@ pub struct BatchResult<Tz: TimeZone> {
     pub sunset: Option<DateTime<Tz>>,
     pub moonrise: Option<DateTime<Tz>>,
     pub moonset: Option<DateTime<Tz>>,
+    pub civil_dawn: Option<DateTime<Tz>>,
+    pub civil_dusk: Option<DateTime<Tz>>,
+    /// Time from sunrise to sunset; `None` during polar day or night
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "day_length_seconds", serialize_with = "serialize_duration_seconds")
+    )]
+    pub day_length: Option<chrono::Duration>,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_duration_seconds<S: serde::Serializer>(
+    duration: &Option<chrono::Duration>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    duration.map(|d| d.num_seconds()).serialize(serializer)
 }
 
 // ============================================================================
@@ -441,6 +581,92 @@ mod tests {
         assert!(phases.len() <= 5); // Max 5 if phases span month boundaries
     }
 
+    #[test]
+    fn parallel_batch_matches_sequential() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let dates: Vec<_> = (0..1000)
+            .map(|day| (start + chrono::Duration::days(day)).with_timezone(&New_York))
+            .collect();
+
+        let parallel = batch_calculate(&location, &dates);
+        assert_eq!(parallel.len(), dates.len());
+        for (result, date) in parallel.iter().zip(&dates) {
+            let expected = batch_entry(&location, date);
+            assert_eq!(result.date, expected.date);
+            assert_eq!(result.sunrise, expected.sunrise);
+            assert_eq!(result.sunset, expected.sunset);
+            assert_eq!(result.moonrise, expected.moonrise);
+            assert_eq!(result.moonset, expected.moonset);
+            assert_eq!(result.day_length, expected.day_length);
+            assert_eq!(result.sun_position.altitude, expected.sun_position.altitude);
+            assert_eq!(result.moon_position.altitude, expected.moon_position.altitude);
+        }
+    }
+
+    #[test]
+    fn lazy_batch_matches_eager_prefix() {
+        let location = Location::new(51.5074, -0.1278).unwrap();
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let dates: Vec<_> = (0..30).map(|day| start + chrono::Duration::days(day)).collect();
+
+        let eager = batch_calculate(&location, &dates);
+        let lazy: Vec<_> = batch_calculate_iter(&location, &dates).take(5).collect();
+        assert_eq!(lazy.len(), 5);
+        for (lazy, eager) in lazy.iter().zip(&eager) {
+            assert_eq!(lazy.date, eager.date);
+            assert_eq!(lazy.sunrise, eager.sunrise);
+            assert_eq!(lazy.moonrise, eager.moonrise);
+            assert_eq!(lazy.day_length, eager.day_length);
+        }
+    }
+
+    #[test]
+    fn batch_day_length_and_civil_twilight() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap().with_timezone(&New_York);
+        let polar = Location::new(78.2232, 15.6267).unwrap();
+
+        let result = &batch_calculate(&location, &[date])[0];
+        let (sunrise, sunset) = (result.sunrise.unwrap(), result.sunset.unwrap());
+        assert_eq!(result.day_length, Some(sunset - sunrise));
+        assert_eq!(result.day_length, astro::sun::day_length(&location, &date));
+        assert!(result.civil_dawn.unwrap() < sunrise);
+        assert!(result.civil_dusk.unwrap() > sunset);
+
+        // Midnight sun in Svalbard: no sunrise, so no day length either
+        let result = &batch_calculate(&polar, &[date])[0];
+        assert!(result.sunrise.is_none());
+        assert!(result.day_length.is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn batch_result_serializes_to_json() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let date = Utc.with_ymd_and_hms(2025, 6, 21, 12, 0, 0).unwrap().with_timezone(&New_York);
+
+        let json = serde_json::to_value(batch_calculate(&location, &[date])).unwrap();
+        let entry = &json[0];
+        for key in [
+            "date",
+            "sun_position",
+            "moon_position",
+            "sunrise",
+            "sunset",
+            "moonrise",
+            "moonset",
+            "civil_dawn",
+            "civil_dusk",
+            "day_length_seconds",
+        ] {
+            assert!(entry.get(key).is_some(), "missing {}", key);
+        }
+        assert_eq!(entry["date"], "2025-06-21T08:00:00-04:00");
+        assert!(entry["sun_position"]["altitude"].is_f64());
+        assert!(entry["day_length_seconds"].as_i64().unwrap() > 15 * 3600);
+    }
+
     #[test]
     fn test_library_info() {
         let info = library_info();
@@ -0,0 +1,101 @@
+//! Coordinate-based location helpers.
+//!
+//! Estimates an IANA timezone for arbitrary coordinates without network access.
+//! This is a heuristic, not a boundary lookup: it uses the timezone of the nearest
+//! city in the bundled urban areas database, and falls back to a nautical
+//! (`Etc/GMT±N`) zone derived from longitude when no city is close enough (open
+//! ocean, polar regions). Points near a border whose nearest listed city lies on
+//! the other side get that city's zone.
+
+use crate::city::CityDatabase;
+use chrono_tz::Tz;
+use std::sync::OnceLock;
+
+/// Maximum distance to the nearest known city for its timezone to be trusted.
+const MAX_CITY_DISTANCE_KM: f64 = 1000.0;
+
+/// Estimate the timezone for a latitude/longitude pair in decimal degrees.
+///
+/// Uses the nearest bundled city within 1000 km, otherwise a nautical zone; see
+/// the module docs for the limits of this heuristic. Returns `None` for
+/// coordinates outside the valid range.
+///
+/// # Examples
+///
+/// ```
+/// use solunatus::location::estimate_timezone;
+///
+/// let tz = estimate_timezone(35.6762, 139.6503).unwrap();
+/// assert_eq!(tz.name(), "Asia/Tokyo");
+/// ```
+pub fn estimate_timezone(lat: f64, lon: f64) -> Option<Tz> {
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return None;
+    }
+
+    if let Some(db) = city_database() {
+        if let Some((city, distance, _)) = db.find_nearest(lat, lon) {
+            if distance <= MAX_CITY_DISTANCE_KM {
+                if let Ok(tz) = city.tz.parse() {
+                    return Some(tz);
+                }
+            }
+        }
+    }
+
+    nautical_timezone(lon)
+}
+
+/// The bundled city database, parsed on first use.
+fn city_database() -> Option<&'static CityDatabase> {
+    static DATABASE: OnceLock<Option<CityDatabase>> = OnceLock::new();
+    DATABASE.get_or_init(|| CityDatabase::load().ok()).as_ref()
+}
+
+/// Nautical timezone for a longitude: 15° bands centred on multiples of 15°.
+fn nautical_timezone(lon: f64) -> Option<Tz> {
+    let offset = (lon / 15.0).round() as i32;
+    let name = match offset {
+        0 => "Etc/GMT".to_string(),
+        // POSIX-style names invert the sign: Etc/GMT-9 is UTC+9
+        n if n > 0 => format!("Etc/GMT-{}", n),
+        n => format!("Etc/GMT+{}", -n),
+    };
+    name.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_major_cities() {
+        let tokyo = estimate_timezone(35.6762, 139.6503).unwrap();
+        assert_eq!(tokyo.name(), "Asia/Tokyo");
+
+        let new_york = estimate_timezone(40.7128, -74.0060).unwrap();
+        assert_eq!(new_york.name(), "America/New_York");
+    }
+
+    #[test]
+    fn picks_the_zone_on_each_side_of_a_border() {
+        // Chula Vista, CA and central Tijuana, either side of the US–Mexico border
+        let us_side = estimate_timezone(32.6401, -117.0842).unwrap();
+        assert_eq!(us_side.name(), "America/Los_Angeles");
+
+        let mexico_side = estimate_timezone(32.5149, -117.0382).unwrap();
+        assert_eq!(mexico_side.name(), "America/Tijuana");
+    }
+
+    #[test]
+    fn open_ocean_uses_nautical_zone() {
+        let tz = estimate_timezone(-40.0, -130.0).unwrap();
+        assert_eq!(tz.name(), "Etc/GMT+9");
+    }
+
+    #[test]
+    fn rejects_out_of_range_coordinates() {
+        assert!(estimate_timezone(91.0, 0.0).is_none());
+        assert!(estimate_timezone(0.0, 181.0).is_none());
+    }
+}
@@ -1,6 +1,9 @@
 // Solunatus - High-precision astronomical CLI for sun and moon calculations
 
-use solunatus::{ai, astro, calendar, city, cli, config, events, location_source, output, time_sync, tui};
+use solunatus::{
+    ai, astro, calendar, city, cli, config, events, location, location_source, output, time_sync,
+    tui,
+};
 
 use anyhow::{anyhow, Context, Result};
 use chrono::{Datelike, Duration, Local, NaiveDate, Offset, TimeZone};
@@ -25,19 +28,23 @@ fn main() -> Result<()> {
     let skip_time_sync = env::var("SOLUNATUS_SKIP_TIME_SYNC").is_ok();
     let time_sync_info = if skip_time_sync {
         time_sync::TimeSyncInfo {
-            source: time_sync::PRIMARY_SOURCE_LABEL,
+            source: time_sync::PRIMARY_SOURCE_LABEL.to_string(),
             delta: None,
             error: Some("time sync skipped by SOLUNATUS_SKIP_TIME_SYNC".into()),
         }
     } else {
-        time_sync::check_time_sync()
+        let servers = config
+            .as_ref()
+            .map(|cfg| cfg.time_sync.server_list())
+            .unwrap_or_default();
+        time_sync::check_time_sync_with_servers(&servers)
     };
 
     let mut ai_config = ai::AiConfig::from_args(&args)?;
 
     // Merge with saved AI settings if config was loaded
     if let Some(cfg) = &config {
-        ai_config = ai_config.merge_with_saved(&cfg.ai);
+        ai_config = ai_config.merge_with_saved(&args, &cfg.ai);
     }
 
     // Determine location
@@ -77,6 +84,9 @@ fn main() -> Result<()> {
         let format = match args.calendar_format {
             cli::CalendarFormatArg::Html => calendar::CalendarFormat::Html,
             cli::CalendarFormatArg::Json => calendar::CalendarFormat::Json,
+            cli::CalendarFormatArg::Markdown => calendar::CalendarFormat::Markdown,
+            cli::CalendarFormatArg::Csv => calendar::CalendarFormat::Csv,
+            cli::CalendarFormatArg::Ics => calendar::CalendarFormat::Ics,
         };
 
         let calendar_output = calendar::generate_calendar(
@@ -101,6 +111,7 @@ fn main() -> Result<()> {
             &timezone,
             city_name.clone(),
             &dt,
+            solunatus::usno_validation::ValidationThresholds::default(),
         )?;
 
         let html = solunatus::usno_validation::generate_html_report(&report);
@@ -111,9 +122,10 @@ fn main() -> Result<()> {
         fs::write(&filename, html)?;
         println!("✓ Validation report written to: {}", filename);
         println!("\nSummary:");
-        println!("  Pass:    {} (0-7 min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Pass).count());
-        println!("  Caution: {} (7-10 min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Warning).count());
-        println!("  Fail:    {} (>10 min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Fail).count());
+        let thresholds = report.thresholds;
+        println!("  Pass:    {} (0-{} min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Pass).count(), thresholds.pass_minutes);
+        println!("  Caution: {} ({}-{} min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Warning).count(), thresholds.pass_minutes, thresholds.warning_minutes);
+        println!("  Fail:    {} (>{} min)", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Fail).count(), thresholds.warning_minutes);
         println!("  Missing: {}", report.results.iter().filter(|r| r.status == solunatus::usno_validation::ValidationStatus::Missing).count());
     } else if args.json {
         // JSON output mode
@@ -133,6 +145,13 @@ fn main() -> Result<()> {
             .as_ref()
             .map(|cfg| cfg.time_sync.server.clone())
             .unwrap_or_default();
+        let time_sync_servers = config
+            .as_ref()
+            .map(|cfg| cfg.time_sync.servers.clone())
+            .unwrap_or_default();
+        let apply_time_sync_offset = config
+            .as_ref()
+            .is_some_and(|cfg| cfg.time_sync.apply_offset);
         run_watch_mode(tui::AppConfig {
             location,
             timezone,
@@ -145,6 +164,8 @@ fn main() -> Result<()> {
             time_sync: time_sync_info.clone(),
             time_sync_disabled: skip_time_sync,
             time_sync_server,
+            time_sync_servers,
+            apply_time_sync_offset,
             ai_config: ai_config.clone(),
             watch_prefs: config.as_ref().map(|cfg| cfg.watch.clone()),
         })?;
@@ -197,7 +218,7 @@ fn determine_location(
             .find_exact(city_name)
             .ok_or_else(|| anyhow!("City '{}' not found in database", city_name))?;
 
-        let location = astro::Location::new_unchecked(city.lat, city.lon);
+        let location = city.location();
         let tz: Tz = city.tz.parse()?;
         return Ok((
             location,
@@ -209,8 +230,10 @@ fn determine_location(
 
     // Check CLI arguments
     if let (Some(lat), Some(lon)) = (args.lat, args.lon) {
-        let tz_str = args.tz.clone().unwrap_or_else(|| "UTC".to_string());
-        let tz: Tz = tz_str.parse().unwrap_or(chrono_tz::UTC);
+        let tz: Tz = match &args.tz {
+            Some(tz_str) => tz_str.parse().unwrap_or(chrono_tz::UTC),
+            None => location::estimate_timezone(lat, lon).unwrap_or(chrono_tz::UTC),
+        };
         let location = astro::Location::new(lat, lon)
             .map_err(|e| anyhow!("Invalid location: {}", e))?;
         return Ok((location, tz, None, LocationSource::ManualCli));
@@ -382,18 +405,20 @@ fn print_text_output(
         let mut diff_str = astro::time_utils::format_duration_detailed(diff);
 
         // Add leading space for events with wide emojis to maintain alignment
-        if event_name.contains("Civil dawn") || event_name.contains("Solar noon") {
+        if event_name.has_wide_emoji() {
             diff_str = format!(" {}", diff_str);
         }
 
         let marker = if Some(idx) == next_idx { " (next)" } else { "" };
 
         println!(
-            "{}  {:<18}   {:<18}{}",
+            "{}  {:<18}   {:<diff_width$}{}",
             event_time.format("%H:%M:%S"),
-            event_name,
+            event_name.label(),
             diff_str,
-            marker
+            marker,
+            // One extra column for the wide-emoji leading space
+            diff_width = astro::time_utils::DETAILED_DURATION_WIDTH + 1
         );
     }
 
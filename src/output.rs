@@ -2,6 +2,27 @@
 //!
 //! Provides structured JSON output for astronomical data including
 //! positions, events, phases, and optional AI insights.
+//!
+//! # Schema
+//!
+//! Every document carries a top-level `schema_version` string
+//! ([`JSON_SCHEMA_VERSION`]). The layout is stable within a major version:
+//! fields may be added in a minor bump, while renames, removals or type
+//! changes bump the major version. Top-level keys, in order:
+//!
+//! - `schema_version` – semantic version of this layout
+//! - `location` – `latitude`, `longitude`, `timezone`, `city`
+//! - `datetime` – `local`, `utc`, `timezone_offset`, `time_sync`
+//! - `sun` – `position` (`altitude`, `azimuth`, `azimuth_compass`) and `events`
+//! - `moon` – `position`, `events` and `phase`
+//! - `lunar_phases` – principal phases in the current month
+//! - `ai_insights` – present only when AI insights are enabled
+//!
+//! Version history:
+//!
+//! - `1.1.0` – `datetime.time_sync.source` names the NTP server that answered,
+//!   and the event list sent for `ai_insights` includes golden hour
+//! - `1.0.0` – first versioned layout
 
 use crate::ai;
 use crate::astro::*;
@@ -12,8 +33,12 @@ use chrono::{DateTime, Datelike, Utc};
 use chrono_tz::Tz;
 use serde::Serialize;
 
+/// Version of the JSON output layout, bumped whenever fields change.
+pub const JSON_SCHEMA_VERSION: &str = "1.1.0";
+
 #[derive(Serialize)]
 pub struct JsonOutput {
+    pub schema_version: &'static str,
     pub location: LocationData,
     pub datetime: DateTimeData,
     pub sun: SunData,
@@ -211,6 +236,7 @@ pub fn generate_json_output(
     };
 
     let output = JsonOutput {
+        schema_version: JSON_SCHEMA_VERSION,
         location: LocationData {
             latitude: location.latitude.value(),
             longitude: location.longitude.value(),
@@ -306,3 +332,67 @@ fn build_ai_insights(outcome: &ai::AiOutcome, timezone: &Tz) -> AiInsightsData {
         error: outcome.error.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_output_has_schema_version_and_core_keys() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let timezone: Tz = "America/New_York".parse().unwrap();
+        let dt = chrono::TimeZone::with_ymd_and_hms(&timezone, 2025, 6, 21, 12, 0, 0).unwrap();
+        let time_sync_info = time_sync::TimeSyncInfo {
+            source: "test".to_string(),
+            delta: None,
+            error: None,
+        };
+        let ai_config = ai::AiConfig {
+            enabled: false,
+            server: String::new(),
+            model: String::new(),
+            refresh: std::time::Duration::from_secs(60),
+            refresh_mode: crate::config::AiRefreshMode::default(),
+            provider: ai::AiProvider::default(),
+        };
+
+        let json = generate_json_output(
+            &location,
+            &timezone,
+            Some("New York".to_string()),
+            &dt,
+            "America/New_York",
+            &time_sync_info,
+            &ai_config,
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["schema_version"], JSON_SCHEMA_VERSION);
+        for key in ["location", "datetime", "sun", "moon", "lunar_phases"] {
+            assert!(value.get(key).is_some(), "missing key {}", key);
+        }
+        assert!(value.get("ai_insights").is_none());
+        assert!(value["sun"]["events"].get("sunrise").is_some());
+        assert!(value["moon"]["phase"].get("illumination_percent").is_some());
+
+        // Snapshot of the layout: changing any of these needs a JSON_SCHEMA_VERSION bump
+        let keys = |value: &serde_json::Value| {
+            let mut keys: Vec<String> = value.as_object().unwrap().keys().cloned().collect();
+            keys.sort();
+            keys.join(",")
+        };
+        assert_eq!(
+            keys(&value),
+            "datetime,location,lunar_phases,moon,schema_version,sun"
+        );
+        assert_eq!(keys(&value["location"]), "city,latitude,longitude,timezone");
+        assert_eq!(
+            keys(&value["datetime"]),
+            "local,time_sync,timezone_offset,utc"
+        );
+        assert_eq!(keys(&value["sun"]), "events,position");
+        assert_eq!(keys(&value["moon"]), "events,phase,position");
+        assert_eq!(keys(&value["lunar_phases"][0]), "datetime,phase_type");
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Context};
-use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use chrono::{DateTime, Duration as ChronoDuration, Local, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::net::UdpSocket;
@@ -36,7 +36,8 @@ pub fn default_servers() -> Vec<(String, String)> {
 
 #[derive(Debug, Clone)]
 pub struct TimeSyncInfo {
-    pub source: &'static str,
+    /// Label of the server that answered (or was tried first, on failure)
+    pub source: String,
     pub delta: Option<ChronoDuration>,
     pub error: Option<String>,
 }
@@ -49,37 +50,42 @@ pub enum TimeSyncDirection {
 }
 
 pub fn check_time_sync() -> TimeSyncInfo {
-    check_time_sync_with_servers(None)
+    check_time_sync_with_servers::<&str>(&[])
 }
 
-pub fn check_time_sync_with_servers(custom_server: Option<&str>) -> TimeSyncInfo {
-    // Determine which server we're targeting (for cache key matching)
-    let target_server = custom_server
-        .filter(|s| !s.trim().is_empty())
-        .unwrap_or(TIME_SERVERS[0].0); // Default to primary server
+/// Query NTP servers in order and report the first successful measurement.
+///
+/// `servers` are tried first (host or host:port; blank entries are skipped),
+/// followed by the default servers. `TimeSyncInfo.source` names the server
+/// that answered.
+pub fn check_time_sync_with_servers<S: AsRef<str>>(servers: &[S]) -> TimeSyncInfo {
+    let candidates = candidate_servers(servers);
 
     // Try to load from cache first
     if let Ok(cache) = load_cache() {
         let age = Utc::now().signed_duration_since(cache.timestamp);
 
-        // Check if cache matches our target server and is fresh (< 30 minutes old)
+        // Reuse a fresh (< 30 minutes old) result from any server we would query
         // Note: We normalize server names for comparison (strip port if present in cache)
         let cache_server_normalized = cache.source.split(':').next().unwrap_or(&cache.source);
-        let target_server_normalized = target_server.split(':').next().unwrap_or(target_server);
-
-        if cache_server_normalized == target_server_normalized
-            && age.num_seconds() < CACHE_MIN_INTERVAL_SECS {
-            let delta = ChronoDuration::microseconds(cache.delta_micros);
-            return TimeSyncInfo {
-                source: PRIMARY_SOURCE_LABEL, // Use static label for consistency
-                delta: Some(delta),
-                error: None,
-            };
+        let cached = candidates
+            .iter()
+            .find(|(server, _)| server.split(':').next() == Some(cache_server_normalized));
+
+        if let Some((_, label)) = cached {
+            if age.num_seconds() < CACHE_MIN_INTERVAL_SECS {
+                let delta = ChronoDuration::microseconds(cache.delta_micros);
+                return TimeSyncInfo {
+                    source: label.clone(),
+                    delta: Some(delta),
+                    error: None,
+                };
+            }
         }
     }
 
     // Cache is stale, missing, or for different server - perform fresh NTP query
-    match fetch_delta(custom_server) {
+    match fetch_delta(&candidates, query_ntp) {
         Ok((delta, source, server_addr)) => {
             // Save to cache for future calls
             if let Some(micros) = delta.num_microseconds() {
@@ -98,13 +104,40 @@ pub fn check_time_sync_with_servers(custom_server: Option<&str>) -> TimeSyncInfo
             }
         }
         Err(err) => TimeSyncInfo {
-            source: PRIMARY_SOURCE_LABEL,
+            source: candidates
+                .first()
+                .map_or_else(|| PRIMARY_SOURCE_LABEL.to_string(), |(_, label)| label.clone()),
             delta: None,
             error: Some(err.to_string()),
         },
     }
 }
 
+/// Configured servers followed by the defaults, as (host:port, label) pairs without duplicates.
+fn candidate_servers<S: AsRef<str>>(servers: &[S]) -> Vec<(String, String)> {
+    let mut candidates: Vec<(String, String)> = Vec::new();
+    let configured = servers
+        .iter()
+        .map(|server| server.as_ref().trim())
+        .filter(|server| !server.is_empty())
+        .map(|server| {
+            let with_port = if server.contains(':') {
+                server.to_string()
+            } else {
+                format!("{}:123", server)
+            };
+            let host = server.split(':').next().unwrap_or(server);
+            (with_port, format!("{} (NTP)", host))
+        });
+
+    for candidate in configured.chain(default_servers()) {
+        if !candidates.iter().any(|(server, _)| *server == candidate.0) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
 pub fn format_offset(delta: ChronoDuration) -> String {
     let total_seconds = delta.num_seconds();
     let abs_seconds = total_seconds.abs();
@@ -143,43 +176,24 @@ pub fn direction_code(direction: TimeSyncDirection) -> &'static str {
     }
 }
 
-/// Returns (delta, label, server_address)
-fn fetch_delta(custom_server: Option<&str>) -> anyhow::Result<(ChronoDuration, &'static str, String)> {
+/// Returns (delta, label, server_address) from the first server that answers
+fn fetch_delta<F>(
+    servers: &[(String, String)],
+    query: F,
+) -> anyhow::Result<(ChronoDuration, String, String)>
+where
+    F: Fn(&str) -> anyhow::Result<DateTime<Utc>>,
+{
     let mut last_err: Option<anyhow::Error> = None;
 
-    // If custom server is specified, try it first
-    if let Some(server_str) = custom_server {
-        let server_trimmed = server_str.trim();
-        if !server_trimmed.is_empty() {
-            let server_with_port = if server_trimmed.contains(':') {
-                server_trimmed.to_string()
-            } else {
-                format!("{}:123", server_trimmed)
-            };
-
-            match query_ntp(&server_with_port) {
-                Ok(server_time) => {
-                    let system_time = Utc::now();
-                    let delta = system_time.signed_duration_since(server_time);
-                    // Return server address for cache tracking
-                    return Ok((delta, PRIMARY_SOURCE_LABEL, server_trimmed.to_string()));
-                }
-                Err(err) => {
-                    last_err = Some(anyhow!("{} query failed: {}", server_trimmed, err));
-                }
-            }
-        }
-    }
-
-    // Fall back to default servers
-    for (server, label) in TIME_SERVERS.iter() {
-        match query_ntp(server) {
+    for (server, label) in servers {
+        match query(server) {
             Ok(server_time) => {
                 let system_time = Utc::now();
                 let delta = system_time.signed_duration_since(server_time);
                 // Extract server address without port for cache tracking
                 let server_addr = server.split(':').next().unwrap_or(server).to_string();
-                return Ok((delta, *label, server_addr));
+                return Ok((delta, label.clone(), server_addr));
             }
             Err(err) => {
                 last_err = Some(anyhow!("{} query failed: {}", label, err));
@@ -249,6 +263,22 @@ impl TimeSyncInfo {
     pub fn error_summary(&self) -> Option<String> {
         self.error.as_ref().map(|err| summarize_error(err))
     }
+
+    /// Correct a system clock reading by the measured offset.
+    ///
+    /// The delta is system time minus server time, so it is subtracted; readings
+    /// pass through unchanged when no measurement is available.
+    pub fn corrected<Tz: TimeZone>(&self, system_time: DateTime<Tz>) -> DateTime<Tz> {
+        match self.delta {
+            Some(delta) => system_time - delta,
+            None => system_time,
+        }
+    }
+
+    /// The current local time adjusted to the authoritative server clock.
+    pub fn corrected_now(&self) -> DateTime<Local> {
+        self.corrected(Local::now())
+    }
 }
 
 fn summarize_error(err: &str) -> String {
@@ -309,3 +339,63 @@ fn save_cache(cache: &TimeSyncCache) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn candidate_servers_prefer_configured_then_defaults() {
+        let candidates = candidate_servers(&["", "ntp.example.org", "time.google.com:123"]);
+        let servers: Vec<&str> = candidates.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(
+            servers,
+            ["ntp.example.org:123", "time.google.com:123", "pool.ntp.org:123"]
+        );
+        assert_eq!(candidates[0].1, "ntp.example.org (NTP)");
+    }
+
+    #[test]
+    fn fetch_delta_falls_back_to_next_server() {
+        let servers = candidate_servers(&["down.example.org", "up.example.org"]);
+        let reference = Utc::now() - ChronoDuration::seconds(5);
+
+        let (delta, label, addr) = fetch_delta(&servers, |server| {
+            if server.starts_with("down.") {
+                Err(anyhow!("timed out"))
+            } else {
+                Ok(reference)
+            }
+        })
+        .unwrap();
+
+        assert_eq!(label, "up.example.org (NTP)");
+        assert_eq!(addr, "up.example.org");
+        assert!(delta >= ChronoDuration::seconds(5));
+        assert!(delta < ChronoDuration::seconds(6));
+    }
+
+    #[test]
+    fn corrected_applies_measured_offset() {
+        let system_time = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 30).unwrap();
+        let mut info = TimeSyncInfo {
+            source: PRIMARY_SOURCE_LABEL.to_string(),
+            delta: Some(ChronoDuration::seconds(30)),
+            error: None,
+        };
+
+        let corrected = info.corrected(system_time);
+        assert_eq!(system_time - corrected, ChronoDuration::seconds(30));
+        assert_eq!(corrected, Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap());
+
+        info.delta = None;
+        assert_eq!(info.corrected(system_time), system_time);
+    }
+
+    #[test]
+    fn fetch_delta_reports_last_error_when_all_fail() {
+        let servers = candidate_servers(&["down.example.org"]);
+        let err = fetch_delta(&servers, |_| Err(anyhow!("timed out"))).unwrap_err();
+        assert!(err.to_string().starts_with("pool.ntp.org (NTP) query failed"));
+    }
+}
@@ -21,17 +21,27 @@ use std::{
 };
 
 const STATUS_TTL: Duration = Duration::from_secs(10);
-const EVENT_WINDOW_HOURS: i64 = 12;
 const EVENT_REFRESH_THRESHOLD_HOURS: i64 = 6;
 const POSITION_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
 const MOON_REFRESH_INTERVAL: Duration = Duration::from_secs(600);
 const TIME_SYNC_REFRESH_INTERVAL: Duration = Duration::from_secs(1800); // 30 minutes (pool.ntp.org ToS compliance)
 // Allow a small buffer below the horizon before calling the Moon "Rising" so we
 // do not report rising while it is still deep below the horizon.
+const MOON_RISING_BUFFER_DEG: f64 = 2.0;
+// Look-ahead used to decide whether the Moon is climbing or descending.
+const MOON_TREND_LOOKAHEAD_MINUTES: i64 = 5;
+// Altitude change over the look-ahead below which the Moon counts as at transit.
+const MOON_TREND_STATIONARY_DEG: f64 = 0.02;
+
+/// Symmetric events window for the configured hours, never narrower than one hour.
+fn event_window(hours: u32) -> ChronoDuration {
+    ChronoDuration::hours(i64::from(hours.max(1)))
+}
+
 #[derive(Debug, Clone)]
 pub struct CachedEvents {
     pub reference: DateTime<Tz>,
-    pub entries: Vec<(DateTime<Tz>, &'static str)>,
+    pub entries: Vec<(DateTime<Tz>, events::AstroEventKind)>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -58,13 +68,17 @@ pub struct CachedMoonDetails {
     pub altitude_trend: MoonAltitudeTrend,
 }
 
-#[derive(Debug, Clone, Copy)]
-/// Simple visibility indicator for the Moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Visibility and direction of motion of the Moon.
 pub enum MoonAltitudeTrend {
-    /// Moon center altitude < 0° (not visible).
+    /// Below the horizon and not about to rise.
     Down,
-    /// Moon center altitude ≥ 0° (visible).
+    /// Above the horizon near transit, neither noticeably climbing nor descending.
     Up,
+    /// Climbing, either above the horizon or just below it.
+    Rising,
+    /// Above the horizon and descending.
+    Setting,
 }
 
 impl CachedMoonDetails {
@@ -79,12 +93,23 @@ impl CachedMoonDetails {
 }
 
 fn determine_moon_trend(
-    _location: &Location,
-    _timestamp: &DateTime<Tz>,
+    location: &Location,
+    timestamp: &DateTime<Tz>,
     base: moon::LunarPosition,
 ) -> MoonAltitudeTrend {
+    let later = *timestamp + ChronoDuration::minutes(MOON_TREND_LOOKAHEAD_MINUTES);
+    let change = moon::lunar_position(location, &later).altitude - base.altitude;
+
     if base.altitude >= 0.0 {
-        MoonAltitudeTrend::Up
+        if change.abs() < MOON_TREND_STATIONARY_DEG {
+            MoonAltitudeTrend::Up
+        } else if change > 0.0 {
+            MoonAltitudeTrend::Rising
+        } else {
+            MoonAltitudeTrend::Setting
+        }
+    } else if change > 0.0 && base.altitude >= -MOON_RISING_BUFFER_DEG {
+        MoonAltitudeTrend::Rising
     } else {
         MoonAltitudeTrend::Down
     }
@@ -283,7 +308,13 @@ pub struct CalendarDraft {
 
 impl CalendarDraft {
     const FIELD_COUNT: usize = 4;
-    const FORMATS: [CalendarFormat; 2] = [CalendarFormat::Html, CalendarFormat::Json];
+    const FORMATS: [CalendarFormat; 5] = [
+        CalendarFormat::Html,
+        CalendarFormat::Json,
+        CalendarFormat::Markdown,
+        CalendarFormat::Csv,
+        CalendarFormat::Ics,
+    ];
 
     pub fn new(now: DateTime<Local>) -> Self {
         let today = now.date_naive();
@@ -337,6 +368,9 @@ impl CalendarDraft {
         match self.current_format() {
             CalendarFormat::Html => "HTML",
             CalendarFormat::Json => "JSON",
+            CalendarFormat::Markdown => "Markdown",
+            CalendarFormat::Csv => "CSV",
+            CalendarFormat::Ics => "ICS",
         }
     }
 
@@ -469,6 +503,9 @@ impl CalendarDraft {
         match format {
             CalendarFormat::Html => "html",
             CalendarFormat::Json => "json",
+            CalendarFormat::Markdown => "md",
+            CalendarFormat::Csv => "csv",
+            CalendarFormat::Ics => "ics",
         }
     }
 }
@@ -856,6 +893,8 @@ pub struct App {
     pub reports_selected_item: ReportsMenuItem,
     pub time_sync: TimeSyncInfo,
     pub time_sync_server: String,
+    pub time_sync_servers: Vec<String>,
+    pub apply_time_sync_offset: bool,
     pub ai_config: ai::AiConfig,
     pub ai_outcome: Option<ai::AiOutcome>,
     pub ai_last_refresh: Option<Instant>,
@@ -875,6 +914,8 @@ pub struct App {
     pub show_moon: bool,
     pub show_lunar_phases: bool,
     pub show_ai_insights: bool,
+    /// Hours before and after now covered by the events list
+    pub event_window_hours: u32,
     pub time_sync_last_check: Instant,
     pub time_sync_disabled: bool,
     ai_job_rx: Option<Receiver<Result<ai::AiOutcome, String>>>,
@@ -891,6 +932,8 @@ pub struct AppConfig {
     pub time_sync: TimeSyncInfo,
     pub time_sync_disabled: bool,
     pub time_sync_server: String,
+    pub time_sync_servers: Vec<String>,
+    pub apply_time_sync_offset: bool,
     pub ai_config: ai::AiConfig,
     pub watch_prefs: Option<WatchPreferences>,
 }
@@ -905,20 +948,26 @@ impl App {
         let time_sync = config.time_sync;
         let time_sync_disabled = config.time_sync_disabled;
         let time_sync_server = config.time_sync_server;
+        let time_sync_servers = config.time_sync_servers;
+        let apply_time_sync_offset = config.apply_time_sync_offset;
         let ai_config = config.ai_config;
         let watch_prefs = config.watch_prefs;
-        let now = Local::now();
+        let now = if apply_time_sync_offset {
+            time_sync.corrected_now()
+        } else {
+            Local::now()
+        };
         let now_tz = now.with_timezone(&timezone);
+        let prefs = watch_prefs.unwrap_or_default();
         let events_entries = events::collect_events_within_window(
             &location,
             &now_tz,
-            ChronoDuration::hours(EVENT_WINDOW_HOURS),
+            event_window(prefs.event_window_hours),
         );
         let positions_cache = CachedPositions::new(&location, &now_tz);
         let moon_overview_cache = CachedMoonDetails::from_positions(&location, &positions_cache);
         let lunar_phases_cache = Self::collect_lunar_phases(&now_tz);
         let lunar_phases_generated_for = now_tz.date_naive();
-        let prefs = watch_prefs.unwrap_or_default();
 
         // Calculate nearest city info (only if no city_name is set, i.e., not using city picker)
         let nearest_city_info = if city_name.is_none() {
@@ -982,6 +1031,8 @@ impl App {
             reports_selected_item: ReportsMenuItem::Calendar,
             time_sync,
             time_sync_server,
+            time_sync_servers,
+            apply_time_sync_offset,
             ai_config_draft: AiConfigDraft::from_config(&ai_config),
             ai_config,
             ai_outcome: None,
@@ -1004,6 +1055,7 @@ impl App {
             show_moon: prefs.show_moon,
             show_lunar_phases: prefs.show_lunar_phases,
             show_ai_insights: prefs.show_ai_insights,
+            event_window_hours: prefs.event_window_hours,
             time_sync_last_check: Instant::now(),
             time_sync_disabled,
             ai_job_rx: None,
@@ -1012,35 +1064,20 @@ impl App {
     }
 
     pub fn update_time(&mut self) {
-        self.current_time = Local::now();
+        self.current_time = if self.apply_time_sync_offset {
+            self.time_sync.corrected_now()
+        } else {
+            Local::now()
+        };
         self.expire_status_if_needed();
     }
 
     fn collect_lunar_phases(now_tz: &DateTime<Tz>) -> Vec<moon::LunarPhase> {
-        use chrono::Datelike;
-
-        let year = now_tz.year();
-        let month = now_tz.month();
-
-        let (prev_year, prev_month) = if month == 1 {
-            (year - 1, 12)
-        } else {
-            (year, month - 1)
-        };
-
-        let (next_year, next_month) = if month == 12 {
-            (year + 1, 1)
-        } else {
-            (year, month + 1)
-        };
-
-        let mut phases = Vec::new();
-        phases.extend(moon::lunar_phases(prev_year, prev_month));
-        phases.extend(moon::lunar_phases(year, month));
-        phases.extend(moon::lunar_phases(next_year, next_month));
-        phases.sort_by(|a, b| a.datetime.cmp(&b.datetime));
-        phases.dedup_by(|a, b| a.datetime == b.datetime && a.phase_type == b.phase_type);
-        phases
+        // Previous, current and next calendar month
+        let month_start = now_tz.date_naive().with_day(1).unwrap_or(now_tz.date_naive());
+        let start = month_start - chrono::Months::new(1);
+        let end = (month_start + chrono::Months::new(2)).pred_opt().unwrap_or(month_start);
+        moon::lunar_phases_in_range(start, end)
     }
 
     fn regenerate_events(&mut self) {
@@ -1050,7 +1087,7 @@ impl App {
             entries: events::collect_events_within_window(
                 &self.location,
                 &now_tz,
-                ChronoDuration::hours(EVENT_WINDOW_HOURS),
+                event_window(self.event_window_hours),
             ),
         };
     }
@@ -1058,7 +1095,9 @@ impl App {
     pub fn refresh_events_if_needed(&mut self) {
         let now_tz = self.current_time.with_timezone(&self.timezone);
         let reference = self.events_cache.reference;
-        let threshold = ChronoDuration::hours(EVENT_REFRESH_THRESHOLD_HOURS);
+        // Narrow windows need refreshing before the listed events run out
+        let threshold = ChronoDuration::hours(EVENT_REFRESH_THRESHOLD_HOURS)
+            .min(event_window(self.event_window_hours) / 2);
         let delta = now_tz.signed_duration_since(reference);
         let need_refresh = self.events_cache.entries.is_empty()
             || delta.num_seconds().abs() >= threshold.num_seconds()
@@ -1135,6 +1174,7 @@ impl App {
             show_lunar_phases: self.show_lunar_phases,
             show_ai_insights: self.show_ai_insights,
             night_mode: self.night_mode,
+            event_window_hours: self.event_window_hours,
         }
     }
 
@@ -1150,6 +1190,8 @@ impl App {
         cfg.time_sync = config::TimeSyncSettings {
             enabled: !self.time_sync_disabled,
             server: self.time_sync_server.clone(),
+            servers: self.time_sync_servers.clone(),
+            apply_offset: self.apply_time_sync_offset,
         };
         cfg.ai = config::AiSettings {
             enabled: self.ai_config.enabled,
@@ -1157,6 +1199,7 @@ impl App {
             model: self.ai_config.model.clone(),
             refresh_minutes: self.ai_config.refresh_minutes(),
             refresh_mode: self.ai_config.refresh_mode,
+            provider: self.ai_config.provider.clone(),
         };
         cfg
     }
@@ -1184,12 +1227,10 @@ impl App {
             return;
         }
         if self.time_sync_last_check.elapsed() >= TIME_SYNC_REFRESH_INTERVAL {
-            let server = if self.time_sync_server.trim().is_empty() {
-                None
-            } else {
-                Some(self.time_sync_server.as_str())
-            };
-            self.time_sync = crate::time_sync::check_time_sync_with_servers(server);
+            let servers: Vec<&str> = std::iter::once(self.time_sync_server.as_str())
+                .chain(self.time_sync_servers.iter().map(String::as_str))
+                .collect();
+            self.time_sync = crate::time_sync::check_time_sync_with_servers(&servers);
             self.time_sync_last_check = Instant::now();
         }
     }
@@ -1262,6 +1303,9 @@ impl App {
         let opt_format = match format {
             CalendarFormat::Html => calendar_optimized::CalendarFormat::Html,
             CalendarFormat::Json => calendar_optimized::CalendarFormat::Json,
+            CalendarFormat::Markdown => calendar_optimized::CalendarFormat::Markdown,
+            CalendarFormat::Csv => calendar_optimized::CalendarFormat::Csv,
+            CalendarFormat::Ics => calendar_optimized::CalendarFormat::Ics,
         };
 
         // Use optimized calendar generation (70.81x faster for 75-year ranges!)
@@ -1272,6 +1316,9 @@ impl App {
             start,
             end,
             opt_format,
+            &calendar_optimized::CalendarColumns::default(),
+            None,
+            None,
         )?;
 
         let path = PathBuf::from(&output_path);
@@ -1295,7 +1342,7 @@ impl App {
     }
 
     pub fn set_location(&mut self, city: &City) {
-        self.location = Location::new_unchecked(city.lat, city.lon);
+        self.location = city.location();
         self.timezone = city.tz.parse().unwrap_or(chrono_tz::UTC);
         self.city_name = Some(city.name.clone());
         self.nearest_city_info = None; // Clear nearest city info when using city picker
@@ -1415,7 +1462,7 @@ impl App {
 
         let normalized = ai::AiConfig::normalized_server(true, &self.ai_config_draft.server);
 
-        match ai::probe_server(&normalized) {
+        match ai::probe_server(&normalized, &self.ai_config.provider) {
             Ok(models) => {
                 self.ai_config_draft
                     .set_detection_success(normalized.clone(), models);
@@ -1551,9 +1598,11 @@ impl App {
             let models = if reuse_models {
                 self.ai_config_draft.models.clone()
             } else {
-                ai::probe_server(&normalized_server).map_err(|err| {
+                let provider = &self.ai_config.provider;
+                ai::probe_server(&normalized_server, provider).map_err(|err| {
                     anyhow!(
-                        "Unable to reach Ollama server at {} ({})",
+                        "Unable to reach {} server at {} ({})",
+                        provider.label(),
                         normalized_server,
                         err
                     )
@@ -1703,7 +1752,7 @@ impl App {
 
         let normalized = ai::AiConfig::normalized_server(true, &self.settings_draft.ai_server);
 
-        match ai::probe_server(&normalized) {
+        match ai::probe_server(&normalized, &self.ai_config.provider) {
             Ok(mut models) => {
                 self.settings_draft.ai_server_status = AiServerStatus::Connected {
                     server: normalized.clone(),
@@ -1761,3 +1810,37 @@ impl App {
         self.settings_draft.error = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn trend_at(location: &Location, timestamp: DateTime<Tz>) -> MoonAltitudeTrend {
+        let base = moon::lunar_position(location, &timestamp);
+        determine_moon_trend(location, &timestamp, base)
+    }
+
+    #[test]
+    fn moon_trend_follows_rise_and_set() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let timezone: Tz = "America/New_York".parse().unwrap();
+        let date = timezone.with_ymd_and_hms(2025, 6, 10, 12, 0, 0).unwrap();
+
+        let rise = moon::lunar_event_time(&location, &date, moon::LunarEvent::Moonrise).unwrap();
+        assert_eq!(
+            trend_at(&location, rise + ChronoDuration::minutes(2)),
+            MoonAltitudeTrend::Rising
+        );
+
+        let set = moon::lunar_event_time(&location, &date, moon::LunarEvent::Moonset).unwrap();
+        assert_eq!(
+            trend_at(&location, set - ChronoDuration::minutes(15)),
+            MoonAltitudeTrend::Setting
+        );
+        assert_eq!(
+            trend_at(&location, set + ChronoDuration::minutes(60)),
+            MoonAltitudeTrend::Down
+        );
+    }
+}
@@ -278,7 +278,7 @@ fn handle_location_input_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                                     app.location_input_draft.clear_error();
                                 }
                                 Err(e) => {
-                                    app.location_input_draft.set_error(e);
+                                    app.location_input_draft.set_error(e.to_string());
                                 }
                             }
                         }
@@ -364,6 +364,18 @@ fn handle_calendar_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                         app.calendar_draft
                             .set_format(crate::calendar::CalendarFormat::Json);
                     }
+                    'm' | 'M' => {
+                        app.calendar_draft
+                            .set_format(crate::calendar::CalendarFormat::Markdown);
+                    }
+                    'c' | 'C' => {
+                        app.calendar_draft
+                            .set_format(crate::calendar::CalendarFormat::Csv);
+                    }
+                    'i' | 'I' => {
+                        app.calendar_draft
+                            .set_format(crate::calendar::CalendarFormat::Ics);
+                    }
                     _ => {}
                 }
             } else {
@@ -476,6 +488,7 @@ fn handle_reports_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                         &app.timezone,
                         app.city_name.clone(),
                         &now_tz,
+                        crate::usno_validation::ValidationThresholds::default(),
                     ) {
                         Ok(report) => {
                             let html = crate::usno_validation::generate_html_report(&report);
@@ -312,9 +312,7 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
             let time_str = format!("{}", event_time.format("%H:%M:%S"));
             let mut diff_str = time_utils::format_duration_detailed(time_diff);
 
-            if (event_name.contains("Civil dawn") || event_name.contains("Solar noon"))
-                && !app.night_mode
-            {
+            if event_name.has_wide_emoji() && !app.night_mode {
                 diff_str = format!(" {}", diff_str);
             }
 
@@ -324,8 +322,12 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
                 ""
             };
 
-            let event_label = sanitized_event_label(app, event_name);
-            let (event_width, diff_width) = if app.night_mode { (14, 15) } else { (16, 17) };
+            let event_label = sanitized_event_label(app, event_name.label());
+            let (event_width, diff_width) = if app.night_mode {
+                (14, time_utils::DETAILED_DURATION_WIDTH - 2)
+            } else {
+                (16, time_utils::DETAILED_DURATION_WIDTH)
+            };
             lines.push(Line::from(vec![Span::raw(format!(
                 "{}  {:<event_width$} {:<diff_width$}{}",
                 time_str,
@@ -412,6 +414,8 @@ fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
         let trend_label = match moon_overview_details.altitude_trend {
             super::app::MoonAltitudeTrend::Down => "Down",
             super::app::MoonAltitudeTrend::Up => "Up",
+            super::app::MoonAltitudeTrend::Rising => "Rising",
+            super::app::MoonAltitudeTrend::Setting => "Setting",
         };
         lines.push(Line::from(vec![Span::raw(label_with_symbol(
             app,
@@ -1,12 +1,14 @@
 // USNO validation module - compare solunatus calculations against U.S. Naval Observatory data
 
 use crate::astro::*;
-use crate::events;
+use crate::calendar::csv_row;
+use crate::events::{self, AstroEventKind};
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, NaiveTime, TimeZone, Utc};
 use chrono_tz::Tz;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 const USNO_API_BASE: &str = "https://aa.usno.navy.mil/api/rstt/oneday";
 
@@ -22,11 +24,10 @@ struct UsnoProperties {
     data: UsnoData,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct UsnoData {
     sundata: Vec<UsnoEvent>,
     moondata: Vec<UsnoEvent>,
-    #[allow(dead_code)]
     closestphase: Option<UsnoPhase>,
     #[allow(dead_code)]
     curphase: Option<String>,
@@ -39,14 +40,13 @@ struct UsnoData {
     tz: f64,  // Timezone offset from UTC (0.0 means UTC)
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 struct UsnoEvent {
     phen: String,
     time: String,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
+#[derive(Debug, Clone, Deserialize)]
 struct UsnoPhase {
     phase: String,
     year: i32,
@@ -74,12 +74,31 @@ pub enum ValidationStatus {
     Missing,
 }
 
+/// Largest absolute differences, in whole minutes, classified as pass or warning
+///
+/// Anything above `warning_minutes` is a fail. The default matches the USNO tolerance
+/// used by the CLI: up to 7 minutes passes and up to 10 minutes is a warning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationThresholds {
+    pub pass_minutes: i64,
+    pub warning_minutes: i64,
+}
+
+impl Default for ValidationThresholds {
+    fn default() -> Self {
+        Self {
+            pass_minutes: 7,
+            warning_minutes: 10,
+        }
+    }
+}
+
 impl ValidationStatus {
-    fn from_difference(diff_minutes: Option<i64>) -> Self {
+    fn from_difference(diff_minutes: Option<i64>, thresholds: &ValidationThresholds) -> Self {
         match diff_minutes {
             None => ValidationStatus::Missing,
-            Some(d) if d.abs() <= 7 => ValidationStatus::Pass,
-            Some(d) if d.abs() <= 10 => ValidationStatus::Warning,
+            Some(d) if d.abs() <= thresholds.pass_minutes => ValidationStatus::Pass,
+            Some(d) if d.abs() <= thresholds.warning_minutes => ValidationStatus::Warning,
             Some(_) => ValidationStatus::Fail,
         }
     }
@@ -92,17 +111,32 @@ pub struct ValidationReport {
     pub date: DateTime<Tz>,
     pub version: String,
     pub usno_apiversion: String,
+    pub thresholds: ValidationThresholds,
     pub results: Vec<ValidationResult>,
 }
 
+/// Coordinates as sent to the USNO API, also used as the cache key
+fn usno_coords(location: &Location) -> String {
+    format!("{:.5},{:.5}", location.latitude.value(), location.longitude.value())
+}
+
 /// Fetch USNO data for the given location and date
 fn fetch_usno_data(
     location: &Location,
     date: &DateTime<Tz>,
+) -> Result<UsnoData> {
+    fetch_usno_data_from(USNO_API_BASE, location, date)
+}
+
+/// Fetch USNO data from an API endpoint (overridable for tests)
+fn fetch_usno_data_from(
+    api_base: &str,
+    location: &Location,
+    date: &DateTime<Tz>,
 ) -> Result<UsnoData> {
     let date_str = date.format("%Y-%m-%d").to_string();
-    let coords = format!("{:.5},{:.5}", location.latitude.value(), location.longitude.value());
-    let url = format!("{}?date={}&coords={}", USNO_API_BASE, date_str, coords);
+    let coords = usno_coords(location);
+    let url = format!("{}?date={}&coords={}", api_base, date_str, coords);
 
     let response = reqwest::blocking::get(&url)
         .with_context(|| format!("Failed to fetch USNO data from {}", url))?;
@@ -121,6 +155,68 @@ fn fetch_usno_data(
     Ok(usno_response.properties.data)
 }
 
+/// One saved USNO API response in a [`UsnoCache`] file
+#[derive(Debug, Deserialize)]
+struct UsnoCacheEntry {
+    /// Requested date, `YYYY-MM-DD`
+    date: NaiveDate,
+    /// Requested coordinates, `lat,lon` with five decimals as sent to the API
+    coords: String,
+    /// The API's JSON response body, unchanged
+    response: UsnoResponse,
+}
+
+/// Pre-fetched USNO responses for validating without network access.
+///
+/// A cache file is a JSON array of `{"date": "2025-06-11", "coords": "40.71280,-74.00600",
+/// "response": { ... }}` objects, where `response` is the body returned by the USNO
+/// `rstt/oneday` API for that date and coordinates. A validation run looks up the
+/// reference day and its neighbours; only the reference day is required.
+#[derive(Debug, Default)]
+pub struct UsnoCache {
+    entries: HashMap<(NaiveDate, String), UsnoData>,
+}
+
+impl UsnoCache {
+    /// Parse a cache from its JSON text
+    pub fn from_json(json: &str) -> Result<Self> {
+        let entries: Vec<UsnoCacheEntry> =
+            serde_json::from_str(json).context("Failed to parse USNO cache JSON")?;
+        Ok(Self {
+            entries: entries
+                .into_iter()
+                .map(|entry| ((entry.date, entry.coords), entry.response.properties.data))
+                .collect(),
+        })
+    }
+
+    /// Load a cache file from disk
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read USNO cache {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    /// Number of cached responses
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no responses
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn lookup(&self, location: &Location, date: &DateTime<Tz>) -> Result<UsnoData> {
+        let key = (date.date_naive(), usno_coords(location));
+        self.entries
+            .get(&key)
+            .cloned()
+            .ok_or_else(|| anyhow!("No cached USNO data for {} at {}", key.0, key.1))
+    }
+}
+
 /// Parse USNO time string (HH:MM) as UTC and convert to local timezone
 /// Returns the DateTime in the target timezone
 fn parse_usno_time_to_local(
@@ -150,27 +246,92 @@ fn parse_usno_time_to_local(
 }
 
 /// Map USNO event names to our event types
-fn map_usno_event_name(phen: &str, is_sun: bool) -> Option<String> {
+fn map_usno_event_name(phen: &str, is_sun: bool) -> Option<AstroEventKind> {
     match (phen, is_sun) {
-        ("Rise", true) => Some("Sunrise".to_string()),
-        ("Set", true) => Some("Sunset".to_string()),
-        ("Upper Transit", true) => Some("Solar noon".to_string()),
-        ("Begin Civil Twilight", true) => Some("Civil dawn".to_string()),
-        ("End Civil Twilight", true) => Some("Civil dusk".to_string()),
-        ("Rise", false) => Some("Moonrise".to_string()),
-        ("Set", false) => Some("Moonset".to_string()),
+        ("Rise", true) => Some(AstroEventKind::Sunrise),
+        ("Set", true) => Some(AstroEventKind::Sunset),
+        ("Upper Transit", true) => Some(AstroEventKind::SolarNoon),
+        ("Begin Civil Twilight", true) => Some(AstroEventKind::CivilDawn),
+        ("End Civil Twilight", true) => Some(AstroEventKind::CivilDusk),
+        ("Rise", false) => Some(AstroEventKind::Moonrise),
+        ("Set", false) => Some(AstroEventKind::Moonset),
         ("Upper Transit", false) => None, // Moon transit removed - difficult to calculate accurately
         _ => None,
     }
 }
 
 /// Check if an event should be included in the validation report
-/// (excludes nautical and astronomical twilight since USNO doesn't provide them)
-fn should_include_in_report(event_name: &str) -> bool {
-    !event_name.contains("Nautical")
-        && !event_name.contains("Astronomical")
-        && !event_name.contains("Astro ")
-        && !event_name.contains("Dark win")
+/// (only the events USNO publishes; nautical/astronomical twilight, golden hour and
+/// dark windows have no USNO counterpart)
+fn should_include_in_report(kind: AstroEventKind) -> bool {
+    matches!(
+        kind,
+        AstroEventKind::Sunrise
+            | AstroEventKind::Sunset
+            | AstroEventKind::SolarNoon
+            | AstroEventKind::CivilDawn
+            | AstroEventKind::CivilDusk
+            | AstroEventKind::Moonrise
+            | AstroEventKind::Moonset
+    )
+}
+
+/// Map USNO primary phase names to our phase types
+fn map_usno_phase_name(phase: &str) -> Option<moon::LunarPhaseType> {
+    match phase {
+        "New Moon" => Some(moon::LunarPhaseType::NewMoon),
+        "First Quarter" => Some(moon::LunarPhaseType::FirstQuarter),
+        "Full Moon" => Some(moon::LunarPhaseType::FullMoon),
+        "Last Quarter" | "Third Quarter" => Some(moon::LunarPhaseType::LastQuarter),
+        _ => None,
+    }
+}
+
+/// Compare USNO's closest primary phase with the matching phase from `lunar_phases`
+///
+/// Our phase is searched within ±2 days of the USNO instant; phase times can fall
+/// on another day than the report, so both values include the date.
+fn compare_closest_phase(
+    usno_phase: &UsnoPhase,
+    timezone: &Tz,
+    thresholds: &ValidationThresholds,
+) -> Option<ValidationResult> {
+    let phase_type = map_usno_phase_name(&usno_phase.phase)?;
+    let usno_date = NaiveDate::from_ymd_opt(usno_phase.year, usno_phase.month, usno_phase.day)?;
+    let usno_dt = parse_usno_time_to_local(&usno_phase.time, usno_date, timezone)?;
+
+    let ours = moon::lunar_phases_in_range(
+        usno_date - ChronoDuration::days(2),
+        usno_date + ChronoDuration::days(2),
+    )
+    .into_iter()
+    .filter(|p| p.phase_type == phase_type)
+    .min_by_key(|p| (p.datetime - usno_dt.with_timezone(&Utc)).num_seconds().abs());
+
+    let event_name = format!("{} phase", usno_phase.phase);
+    let usno_value = Some(usno_dt.format("%b %d %H:%M").to_string());
+    Some(match ours {
+        Some(phase) => {
+            let at_dt = phase.datetime.with_timezone(timezone);
+            let diff_minutes = at_dt.signed_duration_since(usno_dt).num_minutes();
+            ValidationResult {
+                event_name,
+                astrotimes_value: Some(at_dt.format("%b %d %H:%M:%S").to_string()),
+                usno_value,
+                difference_minutes: Some(diff_minutes),
+                status: ValidationStatus::from_difference(Some(diff_minutes), thresholds),
+                _datetime: Some(at_dt),
+            }
+        }
+        None => ValidationResult {
+            event_name,
+            astrotimes_value: None,
+            usno_value,
+            difference_minutes: None,
+            status: ValidationStatus::Missing,
+            _datetime: Some(usno_dt),
+        },
+    })
 }
 
 /// Generate validation report comparing astrotimes calculations with USNO data
@@ -179,7 +340,36 @@ pub fn generate_validation_report(
     timezone: &Tz,
     city_name: Option<String>,
     date: &DateTime<Tz>,
+    thresholds: ValidationThresholds,
+) -> Result<ValidationReport> {
+    build_validation_report(location, timezone, city_name, date, &fetch_usno_data, thresholds)
+}
+
+/// Generate a validation report from pre-fetched USNO responses instead of the live API
+pub fn generate_validation_report_from_cache(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<String>,
+    date: &DateTime<Tz>,
+    cache: &UsnoCache,
+    thresholds: ValidationThresholds,
 ) -> Result<ValidationReport> {
+    let lookup = |location: &Location, date: &DateTime<Tz>| cache.lookup(location, date);
+    build_validation_report(location, timezone, city_name, date, &lookup, thresholds)
+}
+
+/// Build a validation report using the supplied USNO fetcher
+fn build_validation_report<F>(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<String>,
+    date: &DateTime<Tz>,
+    fetch: &F,
+    thresholds: ValidationThresholds,
+) -> Result<ValidationReport>
+where
+    F: Fn(&Location, &DateTime<Tz>) -> Result<UsnoData> + Sync,
+{
     // Calculate our own events within ±13 hours
     let events_list = events::collect_events_within_window(
         location,
@@ -188,31 +378,47 @@ pub fn generate_validation_report(
     );
 
     // Build a map of our events for easy lookup, keeping the event closest to reference time
-    let mut astrotimes_events: HashMap<String, DateTime<Tz>> = HashMap::new();
-    for (dt, name) in events_list {
-        let normalized = name.trim_start_matches(|c: char| !c.is_ascii_alphabetic()).to_string();
-
-        if let Some(&existing_dt) = astrotimes_events.get(&normalized) {
+    let mut astrotimes_events: HashMap<AstroEventKind, DateTime<Tz>> = HashMap::new();
+    for (dt, kind) in events_list {
+        if let Some(&existing_dt) = astrotimes_events.get(&kind) {
             let delta_existing = existing_dt.signed_duration_since(*date).num_seconds().abs();
             let delta_new = dt.signed_duration_since(*date).num_seconds().abs();
             if delta_new < delta_existing {
-                astrotimes_events.insert(normalized, dt);
+                astrotimes_events.insert(kind, dt);
             }
         } else {
-            astrotimes_events.insert(normalized, dt);
+            astrotimes_events.insert(kind, dt);
         }
     }
 
     // Determine date range to fetch USNO data for (yesterday, today, tomorrow)
     // This ensures we have USNO data for all events in the ±13 hour window
 
-    // Fetch USNO data for all three days and build a map of events by date and name
-    let mut usno_events: HashMap<(NaiveDate, String), DateTime<Tz>> = HashMap::new();
+    // Fetch USNO data for all three days concurrently (each request is a blocking
+    // round trip), then build a map of events by date and name
+    let fetched_days: Vec<(i64, Result<UsnoData>)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (-1..=1)
+            .map(|day_offset| {
+                let fetch_date = *date + ChronoDuration::days(day_offset);
+                (day_offset, scope.spawn(move || fetch(location, &fetch_date)))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|(day_offset, handle)| {
+                let fetched = handle
+                    .join()
+                    .unwrap_or_else(|_| Err(anyhow!("USNO fetch thread panicked")));
+                (day_offset, fetched)
+            })
+            .collect()
+    });
 
-    for day_offset in -1..=1 {
-        let fetch_date = *date + ChronoDuration::days(day_offset);
+    let mut usno_events: HashMap<(NaiveDate, AstroEventKind), DateTime<Tz>> = HashMap::new();
+    let mut primary_day: Option<Result<UsnoData>> = None;
 
-        if let Ok(usno_data) = fetch_usno_data(location, &fetch_date) {
+    for (day_offset, fetched) in fetched_days {
+        if let Ok(usno_data) = &fetched {
             let usno_date = NaiveDate::from_ymd_opt(
                 usno_data.year,
                 usno_data.month,
@@ -237,10 +443,15 @@ pub fn generate_validation_report(
                 }
             }
         }
+
+        if day_offset == 0 {
+            primary_day = Some(fetched);
+        }
     }
 
-    // Fetch primary day data for metadata
-    let usno_data = fetch_usno_data(location, date)
+    // Primary day data provides the report metadata
+    let usno_data = primary_day
+        .unwrap_or_else(|| Err(anyhow!("USNO reference day was not requested")))
         .context("Failed to fetch USNO reference data")?;
 
     let mut results = Vec::new();
@@ -249,9 +460,9 @@ pub fn generate_validation_report(
     // Strategy: For each astrotimes event, find the USNO event with the same name
     // that occurs within ±2 hours. This handles timezone conversions where events
     // may shift dates (e.g., sunset in UTC might be on a different day than in local time).
-    for (event_name, at_dt) in &astrotimes_events {
+    for (kind, at_dt) in &astrotimes_events {
         // Skip nautical and astronomical twilight events (USNO doesn't provide them)
-        if !should_include_in_report(event_name) {
+        if !should_include_in_report(*kind) {
             continue;
         }
 
@@ -261,8 +472,8 @@ pub fn generate_validation_report(
         let mut matching_usno: Option<DateTime<Tz>> = None;
         let max_window = ChronoDuration::hours(2);
 
-        for ((_, usno_event_name), usno_dt) in &usno_events {
-            if usno_event_name == event_name {
+        for ((_, usno_kind), usno_dt) in &usno_events {
+            if usno_kind == kind {
                 let time_diff = (*at_dt - *usno_dt).abs();
 
                 // Only accept matches within ±2 hours (should be same event)
@@ -285,17 +496,17 @@ pub fn generate_validation_report(
             let diff_minutes = duration.num_minutes();
 
             results.push(ValidationResult {
-                event_name: event_name.clone(),
+                event_name: kind.name().to_string(),
                 astrotimes_value: Some(at_dt.format("%H:%M:%S").to_string()),
                 usno_value: Some(usno_dt.format("%H:%M").to_string()),
                 difference_minutes: Some(diff_minutes),
-                status: ValidationStatus::from_difference(Some(diff_minutes)),
+                status: ValidationStatus::from_difference(Some(diff_minutes), &thresholds),
                 _datetime: Some(*at_dt),
             });
         } else {
             // No matching USNO event found within ±2 hours
             results.push(ValidationResult {
-                event_name: event_name.clone(),
+                event_name: kind.name().to_string(),
                 astrotimes_value: Some(at_dt.format("%H:%M:%S").to_string()),
                 usno_value: None,
                 difference_minutes: None,
@@ -308,6 +519,15 @@ pub fn generate_validation_report(
     // Sort results chronologically to match watch mode event ordering
     results.sort_by_key(|r| r._datetime);
 
+    // Phase timing follows the daily events; USNO reports only the closest primary phase
+    if let Some(phase_result) = usno_data
+        .closestphase
+        .as_ref()
+        .and_then(|phase| compare_closest_phase(phase, timezone, &thresholds))
+    {
+        results.push(phase_result);
+    }
+
     Ok(ValidationReport {
         location: *location,
         timezone: *timezone,
@@ -317,19 +537,242 @@ pub fn generate_validation_report(
         usno_apiversion: usno_data.sundata.first()
             .map(|_| "4.0.1".to_string())
             .unwrap_or_else(|| "unknown".to_string()),
+        thresholds,
         results,
     })
 }
 
-/// Generate HTML report from validation results
-pub fn generate_html_report(report: &ValidationReport) -> String {
+/// Aggregated accuracy statistics for one event type across several days
+#[derive(Debug, Clone, Serialize)]
+pub struct EventAccuracySummary {
+    pub event_name: String,
+    /// Number of days where both solunatus and USNO reported the event
+    pub compared_days: usize,
+    /// Number of days where USNO had no matching event
+    pub missing_days: usize,
+    pub mean_abs_difference_minutes: Option<f64>,
+    pub max_abs_difference_minutes: Option<i64>,
+}
+
+/// Validation results for a range of days plus per-event aggregates
+pub struct MultiDayReport {
+    pub location: Location,
+    pub timezone: Tz,
+    pub city_name: Option<String>,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub thresholds: ValidationThresholds,
+    pub daily: Vec<ValidationReport>,
+    pub summaries: Vec<EventAccuracySummary>,
+}
+
+/// Run the USNO validation for every day in `start..=end` and aggregate the results;
+/// the range counterpart of [`generate_validation_report`].
+///
+/// Each day is validated at local noon. USNO responses are cached per day for the
+/// duration of the run, so the overlapping yesterday/today/tomorrow fetches of
+/// neighbouring days hit the network only once.
+pub fn generate_multiday_report(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<String>,
+    start: NaiveDate,
+    end: NaiveDate,
+    thresholds: ValidationThresholds,
+) -> Result<MultiDayReport> {
+    build_multiday_report(location, timezone, city_name, start, end, &fetch_usno_data, thresholds)
+}
+
+/// Run the multi-day validation against pre-fetched USNO responses.
+///
+/// See [`generate_multiday_report`] and [`UsnoCache`]; days missing from the cache
+/// are treated like failed fetches.
+pub fn generate_multiday_report_from_cache(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<String>,
+    start: NaiveDate,
+    end: NaiveDate,
+    cache: &UsnoCache,
+    thresholds: ValidationThresholds,
+) -> Result<MultiDayReport> {
+    let lookup = |location: &Location, date: &DateTime<Tz>| cache.lookup(location, date);
+    build_multiday_report(location, timezone, city_name, start, end, &lookup, thresholds)
+}
+
+fn build_multiday_report<F>(
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<String>,
+    start: NaiveDate,
+    end: NaiveDate,
+    fetch: &F,
+    thresholds: ValidationThresholds,
+) -> Result<MultiDayReport>
+where
+    F: Fn(&Location, &DateTime<Tz>) -> Result<UsnoData> + Sync,
+{
+    if start > end {
+        return Err(anyhow!("Validation start date must be before end date"));
+    }
+
+    let day_cache: Mutex<HashMap<NaiveDate, UsnoData>> = Mutex::new(HashMap::new());
+    let cached_fetch = |location: &Location, date: &DateTime<Tz>| -> Result<UsnoData> {
+        let key = date.date_naive();
+        if let Some(data) = day_cache.lock().unwrap().get(&key) {
+            return Ok(data.clone());
+        }
+        let data = fetch(location, date)?;
+        day_cache.lock().unwrap().insert(key, data.clone());
+        Ok(data)
+    };
+
+    let mut daily = Vec::new();
+    let mut current = start;
+    while current <= end {
+        let reference = local_noon(timezone, current)?;
+        let report = build_validation_report(
+            location,
+            timezone,
+            city_name.clone(),
+            &reference,
+            &cached_fetch,
+            thresholds,
+        )
+        .with_context(|| format!("USNO validation failed for {}", current))?;
+        daily.push(report);
+        current = current
+            .succ_opt()
+            .ok_or_else(|| anyhow!("Date overflow when iterating validation range"))?;
+    }
+
+    let summaries = summarize_events(&daily);
+
+    Ok(MultiDayReport {
+        location: *location,
+        timezone: *timezone,
+        city_name,
+        start,
+        end,
+        thresholds,
+        daily,
+        summaries,
+    })
+}
+
+fn local_noon(timezone: &Tz, date: NaiveDate) -> Result<DateTime<Tz>> {
+    let naive = date
+        .and_hms_opt(12, 0, 0)
+        .ok_or_else(|| anyhow!("Invalid noon for {}", date))?;
+    timezone
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| anyhow!("Unable to resolve local noon for {}", date))
+}
+
+/// Aggregate per-event statistics, keeping events in order of first appearance
+fn summarize_events(daily: &[ValidationReport]) -> Vec<EventAccuracySummary> {
+    let mut order: Vec<String> = Vec::new();
+    let mut diffs: HashMap<String, (Vec<i64>, usize)> = HashMap::new();
+
+    for report in daily {
+        for result in &report.results {
+            let entry = diffs.entry(result.event_name.clone()).or_insert_with(|| {
+                order.push(result.event_name.clone());
+                (Vec::new(), 0)
+            });
+            match result.difference_minutes {
+                Some(diff) => entry.0.push(diff.abs()),
+                None => entry.1 += 1,
+            }
+        }
+    }
+
+    order
+        .into_iter()
+        .map(|event_name| {
+            let (values, missing_days) = diffs.remove(&event_name).unwrap_or_default();
+            let mean = if values.is_empty() {
+                None
+            } else {
+                Some(values.iter().sum::<i64>() as f64 / values.len() as f64)
+            };
+            EventAccuracySummary {
+                event_name,
+                compared_days: values.len(),
+                missing_days,
+                mean_abs_difference_minutes: mean,
+                max_abs_difference_minutes: values.iter().copied().max(),
+            }
+        })
+        .collect()
+}
+
+/// Export multi-day aggregates as CSV (one row per event)
+pub fn generate_multiday_csv(report: &MultiDayReport) -> String {
+    let mut csv = csv_row(&MULTIDAY_CSV_HEADER.map(str::to_string));
+    for summary in &report.summaries {
+        csv.push_str(&csv_row(&[
+            summary.event_name.clone(),
+            summary.compared_days.to_string(),
+            summary.missing_days.to_string(),
+            summary
+                .mean_abs_difference_minutes
+                .map(|m| format!("{:.2}", m))
+                .unwrap_or_default(),
+            summary
+                .max_abs_difference_minutes
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+        ]));
+    }
+    csv
+}
+
+/// Column names of the multi-day CSV export, in order.
+const MULTIDAY_CSV_HEADER: [&str; 5] = [
+    "event",
+    "compared_days",
+    "missing_days",
+    "mean_abs_difference_minutes",
+    "max_abs_difference_minutes",
+];
+
+/// Export multi-day aggregates as JSON
+pub fn generate_multiday_json(report: &MultiDayReport) -> Result<String> {
+    #[derive(Serialize)]
+    struct MultiDayJson<'a> {
+        latitude: f64,
+        longitude: f64,
+        timezone: &'a str,
+        city: Option<&'a str>,
+        start: String,
+        end: String,
+        days: usize,
+        events: &'a [EventAccuracySummary],
+    }
+
+    Ok(serde_json::to_string_pretty(&MultiDayJson {
+        latitude: report.location.latitude.value(),
+        longitude: report.location.longitude.value(),
+        timezone: report.timezone.name(),
+        city: report.city_name.as_deref(),
+        start: report.start.to_string(),
+        end: report.end.to_string(),
+        days: report.daily.len(),
+        events: &report.summaries,
+    })?)
+}
+
+/// Shared `<head>` and stylesheet for the HTML reports, up to the opening `<body>`
+fn html_document_start(title: &str) -> String {
     let mut html = String::new();
 
     html.push_str("<!DOCTYPE html>\n");
     html.push_str("<html lang=\"en\">\n<head>\n");
     html.push_str("<meta charset=\"UTF-8\">\n");
     html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\n");
-    html.push_str("<title>Solunatus USNO Validation Report</title>\n");
+    html.push_str(&format!("<title>{}</title>\n", title));
     html.push_str("<style>\n");
     html.push_str("body { font-family: Arial, sans-serif; margin: 40px; background: #f5f5f5; }\n");
     html.push_str("h1 { color: #2c3e50; }\n");
@@ -355,23 +798,74 @@ pub fn generate_html_report(report: &ValidationReport) -> String {
     html.push_str("</style>\n");
     html.push_str("</head>\n<body>\n");
 
+    html
+}
+
+/// Document start, title and configuration block shared by the HTML reports.
+///
+/// `details` come first, followed by the timezone, city (if any) and coordinates.
+fn html_report_start(
+    details: &[(&str, String)],
+    location: &Location,
+    timezone: &Tz,
+    city_name: Option<&str>,
+) -> String {
+    let mut html = html_document_start("Solunatus USNO Validation Report");
+
     html.push_str("<h1>Solunatus USNO Validation Report</h1>\n");
 
-    // Information section
+    let mut rows: Vec<(&str, String)> = details.to_vec();
+    rows.push(("Timezone", timezone.name().to_string()));
+    if let Some(city) = city_name {
+        rows.push(("Location", city.to_string()));
+    }
+    rows.push(("Latitude", format!("{:.5}°", location.latitude.value())));
+    rows.push(("Longitude", format!("{:.5}°", location.longitude.value())));
+
     html.push_str("<div class=\"info\">\n");
     html.push_str("<h2>Configuration</h2>\n");
     html.push_str("<div class=\"info-grid\">\n");
-    html.push_str(&format!("<div class=\"info-label\">Solunatus Version:</div><div>{}</div>\n", report.version));
-    html.push_str(&format!("<div class=\"info-label\">USNO API Version:</div><div>{}</div>\n", report.usno_apiversion));
-    html.push_str(&format!("<div class=\"info-label\">Date:</div><div>{}</div>\n", report.date.format("%Y-%m-%d %H:%M:%S %Z")));
-    html.push_str(&format!("<div class=\"info-label\">Timezone:</div><div>{}</div>\n", report.timezone.name()));
-    if let Some(ref city) = report.city_name {
-        html.push_str(&format!("<div class=\"info-label\">Location:</div><div>{}</div>\n", city));
-    }
-    html.push_str(&format!("<div class=\"info-label\">Latitude:</div><div>{:.5}°</div>\n", report.location.latitude.value()));
-    html.push_str(&format!("<div class=\"info-label\">Longitude:</div><div>{:.5}°</div>\n", report.location.longitude.value()));
+    for (label, value) in rows {
+        html.push_str(&format!(
+            "<div class=\"info-label\">{}:</div><div>{}</div>\n",
+            label, value
+        ));
+    }
+    html.push_str("</div>\n");
     html.push_str("</div>\n");
+
+    html
+}
+
+/// Generation timestamp, USNO attribution and closing tags shared by the HTML reports
+fn html_report_end() -> String {
+    let mut html = String::new();
+
+    html.push_str("<div style=\"margin-top: 40px; color: #7f8c8d; font-size: 12px;\">\n");
+    html.push_str(&format!(
+        "Generated: {}<br>\n",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    ));
+    html.push_str("Reference: U.S. Naval Observatory Astronomical Applications Department<br>\n");
+    html.push_str("https://aa.usno.navy.mil/\n");
     html.push_str("</div>\n");
+    html.push_str("</body>\n</html>\n");
+
+    html
+}
+
+/// Generate HTML report from validation results
+pub fn generate_html_report(report: &ValidationReport) -> String {
+    let mut html = html_report_start(
+        &[
+            ("Solunatus Version", report.version.clone()),
+            ("USNO API Version", report.usno_apiversion.clone()),
+            ("Date", report.date.format("%Y-%m-%d %H:%M:%S %Z").to_string()),
+        ],
+        &report.location,
+        &report.timezone,
+        report.city_name.as_deref(),
+    );
 
     // Summary statistics
     let pass_count = report.results.iter().filter(|r| r.status == ValidationStatus::Pass).count();
@@ -382,9 +876,11 @@ pub fn generate_html_report(report: &ValidationReport) -> String {
     html.push_str("<div class=\"summary\">\n");
     html.push_str("<h2>Summary</h2>\n");
     html.push_str("<div class=\"summary-grid\">\n");
-    html.push_str(&format!("<div class=\"summary-item pass\"><div style=\"font-size: 32px;\">{}</div><div>Pass (0-7 min)</div></div>\n", pass_count));
-    html.push_str(&format!("<div class=\"summary-item warning\"><div style=\"font-size: 32px;\">{}</div><div>Caution (7-10 min)</div></div>\n", warning_count));
-    html.push_str(&format!("<div class=\"summary-item fail\"><div style=\"font-size: 32px;\">{}</div><div>Fail (>10 min)</div></div>\n", fail_count));
+    let pass_max = report.thresholds.pass_minutes;
+    let warning_max = report.thresholds.warning_minutes;
+    html.push_str(&format!("<div class=\"summary-item pass\"><div style=\"font-size: 32px;\">{}</div><div>Pass (0-{} min)</div></div>\n", pass_count, pass_max));
+    html.push_str(&format!("<div class=\"summary-item warning\"><div style=\"font-size: 32px;\">{}</div><div>Caution ({}-{} min)</div></div>\n", warning_count, pass_max, warning_max));
+    html.push_str(&format!("<div class=\"summary-item fail\"><div style=\"font-size: 32px;\">{}</div><div>Fail (>{} min)</div></div>\n", fail_count, warning_max));
     html.push_str(&format!("<div class=\"summary-item missing\"><div style=\"font-size: 32px;\">{}</div><div>Missing</div></div>\n", missing_count));
     html.push_str("</div>\n");
     html.push_str("</div>\n");
@@ -397,9 +893,18 @@ pub fn generate_html_report(report: &ValidationReport) -> String {
     html.push_str("• USNO API provides times in UTC with <strong>minute-level granularity only</strong> (HH:MM)<br>\n");
     html.push_str("• Solunatus calculates times with <strong>second-level precision</strong> (HH:MM:SS)<br>\n");
     html.push_str("• All USNO times below have been converted from UTC to your local timezone for comparison<br>\n");
-    html.push_str("• Differences within 0-7 minutes are considered a PASS<br>\n");
-    html.push_str("• Differences of 7-10 minutes are flagged as CAUTION<br>\n");
-    html.push_str("• Differences over 10 minutes are considered a FAIL\n");
+    html.push_str(&format!(
+        "• Differences within 0-{} minutes are considered a PASS<br>\n",
+        pass_max
+    ));
+    html.push_str(&format!(
+        "• Differences of {}-{} minutes are flagged as CAUTION<br>\n",
+        pass_max, warning_max
+    ));
+    html.push_str(&format!(
+        "• Differences over {} minutes are considered a FAIL\n",
+        warning_max
+    ));
     html.push_str("</p>\n");
     html.push_str("</div>\n");
     html.push_str("<table>\n");
@@ -452,13 +957,439 @@ pub fn generate_html_report(report: &ValidationReport) -> String {
     html.push_str("</tbody>\n");
     html.push_str("</table>\n");
 
-    html.push_str("<div style=\"margin-top: 40px; color: #7f8c8d; font-size: 12px;\">\n");
-    html.push_str(&format!("Generated: {}<br>\n", chrono::Local::now().format("%Y-%m-%d %H:%M:%S")));
-    html.push_str("Reference: U.S. Naval Observatory Astronomical Applications Department<br>\n");
-    html.push_str("https://aa.usno.navy.mil/\n");
-    html.push_str("</div>\n");
+    html.push_str(&html_report_end());
 
-    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Generate an HTML report summarising validation over a date range
+///
+/// Lists per-event error statistics across the range, then the status counts of each day.
+pub fn generate_multiday_html_report(report: &MultiDayReport) -> String {
+    let mut html = html_report_start(
+        &[
+            ("Solunatus Version", env!("CARGO_PKG_VERSION").to_string()),
+            (
+                "Date Range",
+                format!("{} to {} ({} days)", report.start, report.end, report.daily.len()),
+            ),
+        ],
+        &report.location,
+        &report.timezone,
+        report.city_name.as_deref(),
+    );
+
+    // Summary over the range, classified by the worst day
+    html.push_str("<h2>Summary Over Range</h2>\n");
+    html.push_str("<table>\n");
+    html.push_str("<thead>\n<tr>\n");
+    html.push_str("<th>Event</th>\n<th>Days Compared</th>\n<th>Days Missing</th>\n");
+    html.push_str("<th>Mean |Difference|</th>\n<th>Max |Difference|</th>\n");
+    html.push_str("</tr>\n</thead>\n");
+    html.push_str("<tbody>\n");
+    for summary in &report.summaries {
+        let worst = ValidationStatus::from_difference(
+            summary.max_abs_difference_minutes,
+            &report.thresholds,
+        );
+        let row_class = match worst {
+            ValidationStatus::Pass => "pass",
+            ValidationStatus::Warning => "warning",
+            ValidationStatus::Fail => "fail",
+            ValidationStatus::Missing => "missing",
+        };
+        html.push_str(&format!("<tr class=\"{}\">\n", row_class));
+        html.push_str(&format!("<td>{}</td>\n", summary.event_name));
+        html.push_str(&format!("<td>{}</td>\n", summary.compared_days));
+        html.push_str(&format!("<td>{}</td>\n", summary.missing_days));
+        html.push_str(&format!(
+            "<td>{}</td>\n",
+            summary
+                .mean_abs_difference_minutes
+                .map(|m| format!("{:.2} min", m))
+                .unwrap_or_else(|| "—".to_string())
+        ));
+        html.push_str(&format!(
+            "<td>{}</td>\n",
+            summary
+                .max_abs_difference_minutes
+                .map(|m| format!("{} min", m))
+                .unwrap_or_else(|| "—".to_string())
+        ));
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n");
+    html.push_str("</table>\n");
+
+    // Status counts for each day
+    html.push_str("<h2>Daily Results</h2>\n");
+    html.push_str("<table>\n");
+    html.push_str("<thead>\n<tr>\n");
+    html.push_str("<th>Date</th>\n<th>Pass</th>\n<th>Caution</th>\n<th>Fail</th>\n");
+    html.push_str("<th>Missing</th>\n");
+    html.push_str("</tr>\n</thead>\n");
+    html.push_str("<tbody>\n");
+    for day in &report.daily {
+        let count = |status: ValidationStatus| {
+            day.results.iter().filter(|r| r.status == status).count()
+        };
+        html.push_str("<tr>\n");
+        html.push_str(&format!("<td>{}</td>\n", day.date.format("%Y-%m-%d")));
+        html.push_str(&format!(
+            "<td class=\"status-pass\">{}</td>\n",
+            count(ValidationStatus::Pass)
+        ));
+        html.push_str(&format!(
+            "<td class=\"status-warning\">{}</td>\n",
+            count(ValidationStatus::Warning)
+        ));
+        html.push_str(&format!(
+            "<td class=\"status-fail\">{}</td>\n",
+            count(ValidationStatus::Fail)
+        ));
+        html.push_str(&format!(
+            "<td class=\"status-missing\">{}</td>\n",
+            count(ValidationStatus::Missing)
+        ));
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n");
+    html.push_str("</table>\n");
+
+    html.push_str(&html_report_end());
 
     html
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::astro::sun::{self, SolarEvent};
+    use chrono::Datelike;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    /// Fake USNO service that reports our own solar events for the UTC day, truncated to HH:MM
+    fn fake_usno(location: &Location, date: &DateTime<Tz>) -> Result<UsnoData> {
+        let day = date.date_naive();
+        let utc_noon = Utc.from_utc_datetime(&day.and_hms_opt(12, 0, 0).unwrap());
+        let sun_event = |phen: &str, event: SolarEvent| {
+            sun::solar_event_time(location, &utc_noon, event).map(|t| UsnoEvent {
+                phen: phen.to_string(),
+                time: t.format("%H:%M").to_string(),
+            })
+        };
+
+        Ok(UsnoData {
+            sundata: [
+                sun_event("Begin Civil Twilight", SolarEvent::CivilDawn),
+                sun_event("Rise", SolarEvent::Sunrise),
+                sun_event("Upper Transit", SolarEvent::SolarNoon),
+                sun_event("Set", SolarEvent::Sunset),
+                sun_event("End Civil Twilight", SolarEvent::CivilDusk),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            moondata: Vec::new(),
+            closestphase: None,
+            curphase: None,
+            fracillum: None,
+            year: day.year(),
+            month: day.month(),
+            day: day.day(),
+            tz: 0.0,
+        })
+    }
+
+    #[test]
+    fn multiday_report_aggregates_per_event() {
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 12).unwrap();
+
+        let calls = AtomicUsize::new(0);
+        let counting_fetch = |location: &Location, date: &DateTime<Tz>| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            fake_usno(location, date)
+        };
+
+        let thresholds = ValidationThresholds::default();
+        let report = build_multiday_report(
+            &location, &tz, None, start, end, &counting_fetch, thresholds,
+        )
+        .unwrap();
+
+        assert_eq!(report.daily.len(), 3);
+        // Days 9..=13 are each fetched once thanks to the per-run cache
+        assert_eq!(calls.load(Ordering::SeqCst), 5);
+
+        for name in [
+            "Civil dawn",
+            "Sunrise",
+            "Solar noon",
+            "Sunset",
+            "Civil dusk",
+        ] {
+            let summary = report
+                .summaries
+                .iter()
+                .find(|s| s.event_name == name)
+                .unwrap_or_else(|| panic!("missing summary row for {}", name));
+            assert_eq!(summary.compared_days, 3, "{}", name);
+            assert!(summary.max_abs_difference_minutes.unwrap() <= 1, "{}", name);
+        }
+
+        let csv = generate_multiday_csv(&report);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("event,compared_days,missing_days,mean_abs_difference_minutes,max_abs_difference_minutes")
+        );
+        assert_eq!(lines.count(), report.summaries.len());
+
+        // Labels with commas or quotes stay in one column
+        let mut report = report;
+        report.summaries[0].event_name = "Moonset, \"next\" day".to_string();
+        let csv = generate_multiday_csv(&report);
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.starts_with("\"Moonset, \"\"next\"\" day\","), "{}", row);
+        assert_eq!(row.matches(',').count(), 5, "{}", row);
+    }
+
+    #[test]
+    fn cached_report_classifies_differences() {
+        // Hand-edited USNO responses for New York, 2025-06-11/12 (UTC days): civil dawn,
+        // sunrise, transit and civil dusk agree; moonset is 8 min late, sunset 12 min early
+        // and moonrise is absent
+        let cache = UsnoCache::from_json(include_str!("../tests/fixtures/usno_cache_nyc.json"))
+            .unwrap();
+        assert_eq!(cache.len(), 2);
+
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 6, 11, 12, 0, 0).unwrap();
+        let thresholds = ValidationThresholds::default();
+        let report =
+            generate_validation_report_from_cache(&location, &tz, None, &date, &cache, thresholds)
+                .unwrap();
+
+        let status = |name: &str| {
+            report
+                .results
+                .iter()
+                .find(|r| r.event_name == name)
+                .map(|r| r.status.clone())
+                .unwrap_or_else(|| panic!("missing result for {}", name))
+        };
+        assert_eq!(status("Civil dawn"), ValidationStatus::Pass);
+        assert_eq!(status("Sunrise"), ValidationStatus::Pass);
+        assert_eq!(status("Solar noon"), ValidationStatus::Pass);
+        assert_eq!(status("Civil dusk"), ValidationStatus::Pass);
+        assert_eq!(status("Moonset"), ValidationStatus::Warning);
+        assert_eq!(status("Sunset"), ValidationStatus::Fail);
+        assert_eq!(status("Moonrise"), ValidationStatus::Missing);
+
+        // The reference day itself must be cached
+        let elsewhere = Location::new(51.5074, -0.1278).unwrap();
+        assert!(
+            generate_validation_report_from_cache(&elsewhere, &tz, None, &date, &cache, thresholds)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn cached_range_report_aggregates_days() {
+        // Sun events for New York, 2025-06-10..=14 (UTC days); the 06-12 sunrise is 3 min early
+        let json = include_str!("../tests/fixtures/usno_cache_nyc_range.json");
+        let cache = UsnoCache::from_json(json).unwrap();
+
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let start = NaiveDate::from_ymd_opt(2025, 6, 11).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 13).unwrap();
+        let thresholds = ValidationThresholds::default();
+        let report = generate_multiday_report_from_cache(
+            &location, &tz, None, start, end, &cache, thresholds,
+        )
+        .unwrap();
+        assert_eq!(report.daily.len(), 3);
+
+        let summary = |name: &str| {
+            report
+                .summaries
+                .iter()
+                .find(|s| s.event_name == name)
+                .unwrap_or_else(|| panic!("missing summary row for {}", name))
+        };
+        let sunrise = summary("Sunrise");
+        assert_eq!(sunrise.compared_days, 3);
+        assert_eq!(sunrise.max_abs_difference_minutes, Some(3));
+        assert_eq!(sunrise.mean_abs_difference_minutes, Some(1.0));
+        assert_eq!(summary("Sunset").compared_days, 3);
+        assert!(summary("Sunset").max_abs_difference_minutes.unwrap() <= 1);
+        // The fixture has no moon data
+        assert_eq!(summary("Moonrise").compared_days, 0);
+        assert!(summary("Moonrise").missing_days > 0);
+
+        let html = generate_multiday_html_report(&report);
+        assert!(html.contains("Summary Over Range"));
+        assert!(html.contains("2025-06-11 to 2025-06-13 (3 days)"));
+        assert!(html.contains("<td>2025-06-13</td>"));
+        assert!(html.contains("<div class=\"info-label\">Latitude:</div>"));
+    }
+
+    /// Minimal USNO stand-in: holds every request until `expected` are in flight (or a
+    /// timeout passes), then answers with an empty day. Returns the base URL and the
+    /// highest number of requests seen in flight at once.
+    fn spawn_mock_usno(expected: usize) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}/api/rstt/oneday", listener.local_addr().unwrap());
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+        let max_seen = Arc::clone(&max_in_flight);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().take(expected) {
+                let mut stream = stream.unwrap();
+                let in_flight = Arc::clone(&in_flight);
+                let max_seen = Arc::clone(&max_seen);
+                std::thread::spawn(move || {
+                    let mut request_line = String::new();
+                    BufReader::new(&stream).read_line(&mut request_line).unwrap();
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_seen.fetch_max(now, Ordering::SeqCst);
+
+                    let deadline = Instant::now() + Duration::from_secs(2);
+                    while in_flight.load(Ordering::SeqCst) < expected && Instant::now() < deadline
+                    {
+                        std::thread::sleep(Duration::from_millis(10));
+                    }
+
+                    let date = request_line
+                        .split("date=")
+                        .nth(1)
+                        .and_then(|rest| NaiveDate::parse_from_str(&rest[..10], "%Y-%m-%d").ok())
+                        .unwrap();
+                    let body = serde_json::json!({
+                        "apiversion": "4.0.1",
+                        "properties": { "data": {
+                            "sundata": [], "moondata": [], "tz": 0.0,
+                            "year": date.year(), "month": date.month(), "day": date.day(),
+                        }},
+                    })
+                    .to_string();
+                    write!(
+                        stream,
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                });
+            }
+        });
+
+        (base, max_in_flight)
+    }
+
+    #[test]
+    fn fetches_neighbouring_days_concurrently() {
+        let (base, max_in_flight) = spawn_mock_usno(3);
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 6, 11, 12, 0, 0).unwrap();
+
+        let fetch = |location: &Location, date: &DateTime<Tz>| {
+            fetch_usno_data_from(&base, location, date)
+        };
+        let thresholds = ValidationThresholds::default();
+        let report =
+            build_validation_report(&location, &tz, None, &date, &fetch, thresholds).unwrap();
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+        assert!(report.results.iter().all(|r| r.status == ValidationStatus::Missing));
+    }
+
+    #[test]
+    fn custom_thresholds_reclassify_differences() {
+        let strict = ValidationThresholds {
+            pass_minutes: 1,
+            warning_minutes: 2,
+        };
+        assert_eq!(ValidationStatus::from_difference(Some(3), &strict), ValidationStatus::Fail);
+        assert_eq!(
+            ValidationStatus::from_difference(Some(3), &ValidationThresholds::default()),
+            ValidationStatus::Pass
+        );
+
+        // The 2025-06-12 sunrise in the range fixture is 3 minutes off
+        let json = include_str!("../tests/fixtures/usno_cache_nyc_range.json");
+        let cache = UsnoCache::from_json(json).unwrap();
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 6, 12, 12, 0, 0).unwrap();
+        let report =
+            generate_validation_report_from_cache(&location, &tz, None, &date, &cache, strict)
+                .unwrap();
+        let sunrise = report.results.iter().find(|r| r.event_name == "Sunrise").unwrap();
+        assert_eq!(sunrise.difference_minutes, Some(3));
+        assert_eq!(sunrise.status, ValidationStatus::Fail);
+        let html = generate_html_report(&report);
+        assert!(html.contains("Pass (0-1 min)"));
+        assert!(html.contains("<div class=\"info-label\">Latitude:</div>"));
+    }
+
+    #[test]
+    fn cached_report_compares_closest_phase() {
+        // USNO lists the 2025-06-11 full moon at 07:44 UTC
+        let json = include_str!("../tests/fixtures/usno_cache_nyc.json");
+        let cache = UsnoCache::from_json(json).unwrap();
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 6, 11, 12, 0, 0).unwrap();
+        let report = generate_validation_report_from_cache(
+            &location,
+            &tz,
+            None,
+            &date,
+            &cache,
+            ValidationThresholds::default(),
+        )
+        .unwrap();
+
+        let phase = report.results.last().unwrap();
+        assert_eq!(phase.event_name, "Full Moon phase");
+        assert_eq!(phase.usno_value.as_deref(), Some("Jun 11 03:44"));
+        // The truncated Meeus phase series runs a few minutes from USNO
+        assert!(phase.difference_minutes.unwrap().abs() <= 5, "{:?}", phase);
+        assert_eq!(phase.status, ValidationStatus::Pass);
+    }
+
+    #[test]
+    #[ignore = "queries the live USNO API; run with `cargo test -- --ignored`"]
+    fn perigee_moonrise_matches_usno() {
+        // 2025-11-05 full moon near perigee (~356,800 km), when the distance-dependent
+        // rise threshold matters most
+        let location = Location::new(40.7128, -74.0060).unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let date = tz.with_ymd_and_hms(2025, 11, 5, 12, 0, 0).unwrap();
+
+        let usno = fetch_usno_data(&location, &date).unwrap();
+        let usno_rise = usno
+            .moondata
+            .iter()
+            .find(|event| event.phen == "Rise")
+            .and_then(|event| parse_usno_time_to_local(&event.time, date.date_naive(), &tz))
+            .expect("USNO reports a moonrise");
+
+        let rise = moon::lunar_event_time(&location, &date, moon::LunarEvent::Moonrise).unwrap();
+        let diff = (rise - usno_rise).num_seconds();
+        assert!(diff.abs() <= 120, "moonrise {} vs USNO {} ({diff} s)", rise, usno_rise);
+    }
+}